@@ -0,0 +1,74 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::utils::{Deserialize, Serialize};
+
+/// A position in logical pixels, relative to the parents top left corner.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A size in logical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Size {
+    pub width:  f64,
+    pub height: f64,
+}
+
+impl Size {
+    pub const fn new(width: f64, height: f64) -> Self {
+        Self { width, height }
+    }
+}
+
+/// A rectangle in logical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rect {
+    pub origin: Point,
+    pub size:   Size,
+}
+
+impl Rect {
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            origin: Point::new(x, y),
+            size:   Size::new(width, height),
+        }
+    }
+
+    pub fn max_x(&self) -> f64 {
+        self.origin.x + self.size.width
+    }
+
+    pub fn max_y(&self) -> f64 {
+        self.origin.y + self.size.height
+    }
+}
+
+/// The direction children of a widget get arranged in.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
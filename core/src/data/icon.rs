@@ -0,0 +1,23 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::path::PathBuf;
+
+use crate::utils::{Deserialize, Serialize};
+
+/// An image shown by tray icons, notifications and the like.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Icon {
+    /// An icon of the platforms icon theme, e.g. `"mail-unread"`.
+    Named(String),
+    /// An image file.
+    File(PathBuf),
+}
+
+impl Icon {
+    pub fn named(name: &str) -> Self {
+        Self::Named(String::from(name))
+    }
+}
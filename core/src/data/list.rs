@@ -0,0 +1,170 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::utils::{Deserialize, Serialize};
+
+/// A single value displayed in a list, table or tree cell.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CellValue {
+    #[default]
+    Empty,
+    Text(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl fmt::Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellValue::Empty => Ok(()),
+            CellValue::Text(text) => text.fmt(f),
+            CellValue::Integer(value) => value.fmt(f),
+            CellValue::Float(value) => value.fmt(f),
+            CellValue::Bool(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(text: &str) -> Self {
+        CellValue::Text(String::from(text))
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(text: String) -> Self {
+        CellValue::Text(text)
+    }
+}
+
+impl From<i64> for CellValue {
+    fn from(value: i64) -> Self {
+        CellValue::Integer(value)
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        CellValue::Float(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+
+/// Describes one column of a table.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ColumnDefinition {
+    pub title:    String,
+    /// Whether clicking the column header asks the data source to sort.
+    pub sortable: bool,
+}
+
+impl ColumnDefinition {
+    pub fn new<S: Into<String>>(title: S, sortable: bool) -> Self {
+        Self {
+            title: title.into(),
+            sortable,
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    pub fn reversed(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub enum SelectionMode {
+    None,
+    #[default]
+    Single,
+    Multiple,
+}
+
+/// The selected rows of a list or table, ordered by row index.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Selection {
+    rows: BTreeSet<usize>,
+}
+
+impl Selection {
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn single(row: usize) -> Self {
+        let mut rows = BTreeSet::new();
+        rows.insert(row);
+        Self { rows }
+    }
+
+    pub fn contains(&self, row: usize) -> bool {
+        self.rows.contains(&row)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.rows.iter().copied()
+    }
+
+    /// The lowest selected row, if any.
+    pub fn first(&self) -> Option<usize> {
+        self.rows.iter().next().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+impl std::iter::FromIterator<usize> for Selection {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        Self {
+            rows: iter.into_iter().collect(),
+        }
+    }
+}
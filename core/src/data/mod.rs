@@ -3,6 +3,30 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+mod geometry;
+pub use geometry::*;
+
+mod icon;
+pub use icon::*;
+
+mod list;
+pub use list::*;
+
+mod tree;
+pub use tree::*;
+
 pub mod prelude {
-    //todo!
+    pub use super::{
+        CellValue,
+        ColumnDefinition,
+        Icon,
+        Orientation,
+        Point,
+        Rect,
+        Selection,
+        SelectionMode,
+        Size,
+        SortOrder,
+        TreePath,
+    };
 }
@@ -0,0 +1,100 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::fmt;
+
+use crate::utils::{Deserialize, Serialize};
+
+/// Addresses a node in a tree by the child indices leading to it.
+///
+/// The empty path is the invisible root, `[0]` its first child,
+/// `[0, 2]` the third child of the first child and so on.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub struct TreePath {
+    indices: Vec<usize>,
+}
+
+impl TreePath {
+    pub fn root() -> Self {
+        Self {
+            indices: Vec::new(),
+        }
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Number of steps from the root. Top level nodes have a depth of 1.
+    pub fn depth(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Index of this node within its parent, `None` for the root.
+    pub fn index(&self) -> Option<usize> {
+        self.indices.last().copied()
+    }
+
+    pub fn child(&self, index: usize) -> Self {
+        let mut indices = self.indices.clone();
+        indices.push(index);
+        Self { indices }
+    }
+
+    /// The parent of this node, `None` for the root.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, parent) = self.indices.split_last()?;
+        Some(Self {
+            indices: parent.to_vec(),
+        })
+    }
+
+    /// Whether `self` is a (direct or indirect) ancestor of `other`.
+    pub fn is_ancestor_of(&self, other: &TreePath) -> bool {
+        other.indices.len() > self.indices.len() && other.indices.starts_with(&self.indices)
+    }
+}
+
+impl fmt::Display for TreePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "/")?;
+        for (pos, index) in self.indices.iter().enumerate() {
+            if pos > 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{}", index)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Vec<usize>> for TreePath {
+    fn from(indices: Vec<usize>) -> Self {
+        Self { indices }
+    }
+}
+
+impl From<&[usize]> for TreePath {
+    fn from(indices: &[usize]) -> Self {
+        Self {
+            indices: indices.to_vec(),
+        }
+    }
+}
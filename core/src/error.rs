@@ -0,0 +1,54 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+/// Everything that can go wrong when using widgets.
+#[derive(Debug, thiserror::Error)]
+pub enum PlatingError {
+    /// An index points behind the last element, e.g. a row of a list view.
+    #[error("{item} {index} is out of range, there are {len}")]
+    OutOfRange {
+        item:  &'static str,
+        index: usize,
+        len:   usize,
+    },
+
+    /// A value got rejected, e.g. a selection not allowed by the selection
+    /// mode.
+    #[error("invalid {what}: {reason}")]
+    InvalidValue {
+        what:   &'static str,
+        reason: String,
+    },
+
+    /// The widget is in a state that does not allow the call, e.g. clicking
+    /// a hidden tray icon.
+    #[error("{0}")]
+    InvalidState(String),
+
+    /// The widget does not have this feature at all.
+    #[error("{0} is not supported by this widget")]
+    Unsupported(&'static str),
+
+    /// A dialog got closed without an answer.
+    #[error("the dialog was closed without a result")]
+    NoResult,
+
+    /// The native toolkit reported an error.
+    #[error("backend failure: {0}")]
+    Backend(#[from] anyhow::Error),
+}
+
+impl PlatingError {
+    pub fn invalid<S: Into<String>>(what: &'static str, reason: S) -> Self {
+        PlatingError::InvalidValue {
+            what,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn state<S: Into<String>>(reason: S) -> Self {
+        PlatingError::InvalidState(reason.into())
+    }
+}
@@ -0,0 +1,442 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use super::{LayoutChildren, LayoutSetting};
+use crate::data::{Orientation, Rect, Size};
+use crate::utils::{Deserialize, Serialize};
+
+/// How children are distributed along the main axis of a [`FlexLayout`].
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub enum Justify {
+    #[default]
+    Start,
+    End,
+    Center,
+    /// First and last child touch the edges, the rest of the space is put
+    /// between the children.
+    SpaceBetween,
+    /// Every child gets the same space on both of its sides.
+    SpaceAround,
+    /// The space before, between and after the children is the same.
+    SpaceEvenly,
+}
+
+/// How children are positioned along the cross axis of a [`FlexLayout`].
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub enum Align {
+    Start,
+    End,
+    Center,
+    /// Children fill the whole cross axis, limited by their maximum size.
+    #[default]
+    Stretch,
+}
+
+/// Space between the children of a [`FlexLayout`], in logical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Gap(pub f64);
+
+/// Space between the edges of a container and its children, in logical
+/// pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Padding {
+    pub top:    f64,
+    pub right:  f64,
+    pub bottom: f64,
+    pub left:   f64,
+}
+
+impl Padding {
+    pub const fn new(top: f64, right: f64, bottom: f64, left: f64) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    pub const fn all(padding: f64) -> Self {
+        Self::new(padding, padding, padding, padding)
+    }
+}
+
+/// How much of the free space along the main axis a child takes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Grow(pub f64);
+
+/// How much a child gives up when there is not enough space along the main
+/// axis. Weighted by the basis of the child.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Shrink(pub f64);
+
+impl Default for Shrink {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Size along the main axis a child starts out with before growing or
+/// shrinking. Defaults to the preferred size of the child.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Basis(pub f64);
+
+/// Overrides the [`Align`] of the container for a single child.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AlignSelf(pub Align);
+
+/// The size a child would like to have. Also sizes
+/// [`Track::Auto`](super::Track::Auto) grid tracks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PreferredSize(pub Size);
+
+/// The size a child never gets smaller than.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MinSize(pub Size);
+
+/// The size a child never gets bigger than.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MaxSize(pub Size);
+
+impl Default for MaxSize {
+    fn default() -> Self {
+        Self(Size::new(f64::INFINITY, f64::INFINITY))
+    }
+}
+
+/// Layout properties of a container, arranging its children in a single row
+/// or column.
+///
+/// Build it from a settings list:
+/// ```rust
+/// use plating_core::data::Orientation;
+/// use plating_core::layout::{FlexLayout, Gap, Justify};
+/// use plating_core::utils::settings::setting_list;
+///
+/// let layout = FlexLayout::from_settings(&setting_list!(
+///     Orientation::Vertical,
+///     Justify::Center,
+///     Gap(4.0)
+/// ));
+/// assert_eq!(layout.gap, 4.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FlexLayout {
+    pub direction: Orientation,
+    pub justify: Justify,
+    pub align: Align,
+    pub gap: f64,
+    pub padding: Padding,
+}
+
+/// Layout properties of a single child of a [`FlexLayout`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FlexItem {
+    pub grow: f64,
+    pub shrink: f64,
+    pub basis: Option<f64>,
+    pub align_self: Option<Align>,
+    pub preferred: Size,
+    pub min: Size,
+    pub max: Size,
+}
+
+impl Default for FlexItem {
+    fn default() -> Self {
+        Self {
+            grow: Grow::default().0,
+            shrink: Shrink::default().0,
+            basis: None,
+            align_self: None,
+            preferred: Size::default(),
+            min: MinSize::default().0,
+            max: MaxSize::default().0,
+        }
+    }
+}
+
+impl FlexItem {
+    pub fn from_settings<SETTINGS>(settings: &SETTINGS) -> Self
+    where
+        SETTINGS: LayoutSetting<Self>,
+    {
+        let mut item = Self::default();
+        settings.apply(&mut item);
+        item
+    }
+}
+
+/// Splits `size` into its main and cross axis length.
+fn along(size: Size, direction: Orientation) -> (f64, f64) {
+    match direction {
+        Orientation::Horizontal => (size.width, size.height),
+        Orientation::Vertical => (size.height, size.width),
+    }
+}
+
+impl FlexLayout {
+    pub fn from_settings<SETTINGS>(settings: &SETTINGS) -> Self
+    where
+        SETTINGS: LayoutSetting<Self>,
+    {
+        let mut layout = Self::default();
+        settings.apply(&mut layout);
+        layout
+    }
+
+    /// Computes the frames of `items` inside a container of `size`.
+    ///
+    /// Frames are relative to the top left corner of the container and
+    /// returned in the order of `items`. Children that do not fit overflow at
+    /// the end of the main axis.
+    pub fn compute(&self, size: Size, items: &[FlexItem]) -> Vec<Rect> {
+        let inner = Size::new(
+            (size.width - self.padding.left - self.padding.right).max(0.0),
+            (size.height - self.padding.top - self.padding.bottom).max(0.0),
+        );
+        let (main_length, cross_length) = along(inner, self.direction);
+        let gaps = self.gap * items.len().saturating_sub(1) as f64;
+        let main_sizes = self.resolve_main_sizes(items, main_length - gaps);
+
+        let leftover = (main_length - gaps - main_sizes.iter().sum::<f64>()).max(0.0);
+        let count = items.len() as f64;
+        let (mut position, spacing) = match self.justify {
+            Justify::Start => (0.0, 0.0),
+            Justify::End => (leftover, 0.0),
+            Justify::Center => (leftover / 2.0, 0.0),
+            Justify::SpaceBetween if items.len() > 1 => (0.0, leftover / (count - 1.0)),
+            Justify::SpaceBetween => (0.0, 0.0),
+            Justify::SpaceAround => (leftover / count / 2.0, leftover / count),
+            Justify::SpaceEvenly => (leftover / (count + 1.0), leftover / (count + 1.0)),
+        };
+
+        items
+            .iter()
+            .zip(main_sizes)
+            .map(|(item, main)| {
+                let (_, preferred) = along(item.preferred, self.direction);
+                let (_, min) = along(item.min, self.direction);
+                let (_, max) = along(item.max, self.direction);
+                let align = item.align_self.unwrap_or(self.align);
+                let cross = match align {
+                    Align::Stretch => cross_length,
+                    _ => preferred,
+                }
+                .min(max)
+                .max(min);
+                let offset = match align {
+                    Align::Start | Align::Stretch => 0.0,
+                    Align::End => cross_length - cross,
+                    Align::Center => (cross_length - cross) / 2.0,
+                };
+
+                let frame = match self.direction {
+                    Orientation::Horizontal => Rect::new(
+                        self.padding.left + position,
+                        self.padding.top + offset,
+                        main,
+                        cross,
+                    ),
+                    Orientation::Vertical => Rect::new(
+                        self.padding.left + offset,
+                        self.padding.top + position,
+                        cross,
+                        main,
+                    ),
+                };
+                position += main + self.gap + spacing;
+                frame
+            })
+            .collect()
+    }
+
+    /// Computes the frames of `children` inside a container of `size` and
+    /// hands every child its frame.
+    pub fn arrange<CHILDREN>(&self, size: Size, children: &CHILDREN) -> Vec<Rect>
+    where
+        CHILDREN: LayoutChildren,
+    {
+        let mut items = Vec::new();
+        children.collect_items(&mut items);
+        let frames = self.compute(size, &items);
+        children.assign_frames(&mut frames.iter());
+        frames
+    }
+
+    /// Resolves the flexible lengths of `items` along the main axis, so they
+    /// fill `available` as far as their constraints allow.
+    fn resolve_main_sizes(&self, items: &[FlexItem], available: f64) -> Vec<f64> {
+        let bases: Vec<f64> = items
+            .iter()
+            .map(|item| {
+                item.basis
+                    .unwrap_or_else(|| along(item.preferred, self.direction).0)
+            })
+            .collect();
+        let limits: Vec<(f64, f64)> = items
+            .iter()
+            .map(|item| {
+                (
+                    along(item.min, self.direction).0,
+                    along(item.max, self.direction).0,
+                )
+            })
+            .collect();
+        let clamp = |index: usize, length: f64| {
+            let (min, max) = limits[index];
+            length.min(max).max(min)
+        };
+
+        let mut sizes: Vec<f64> = (0..items.len())
+            .map(|index| clamp(index, bases[index]))
+            .collect();
+        let growing = sizes.iter().sum::<f64>() < available;
+        let factor = |index: usize| {
+            if growing {
+                items[index].grow
+            } else {
+                items[index].shrink * bases[index]
+            }
+        };
+        let mut frozen: Vec<bool> = (0..items.len())
+            .map(|index| {
+                factor(index) <= 0.0
+                    || (growing && bases[index] > sizes[index])
+                    || (!growing && bases[index] < sizes[index])
+            })
+            .collect();
+
+        loop {
+            let flexible: Vec<usize> = (0..items.len()).filter(|index| !frozen[*index]).collect();
+            if flexible.is_empty() {
+                break;
+            }
+
+            let used: f64 = (0..items.len())
+                .map(|index| {
+                    if frozen[index] {
+                        sizes[index]
+                    } else {
+                        bases[index]
+                    }
+                })
+                .sum();
+            let factors: f64 = flexible.iter().map(|index| factor(*index)).sum();
+            let mut free = available - used;
+            if growing && factors < 1.0 {
+                free *= factors;
+            }
+
+            let mut violation = 0.0;
+            let mut violations = vec![0.0; items.len()];
+            for &index in &flexible {
+                let target = bases[index] + free * factor(index) / factors;
+                sizes[index] = clamp(index, target);
+                violations[index] = sizes[index] - target;
+                violation += violations[index];
+            }
+
+            for &index in &flexible {
+                frozen[index] = violation == 0.0
+                    || (violation > 0.0 && violations[index] > 0.0)
+                    || (violation < 0.0 && violations[index] < 0.0);
+            }
+        }
+
+        sizes
+    }
+}
+
+impl LayoutSetting<FlexLayout> for Orientation {
+    fn apply(&self, target: &mut FlexLayout) {
+        target.direction = *self;
+    }
+}
+
+impl LayoutSetting<FlexLayout> for Justify {
+    fn apply(&self, target: &mut FlexLayout) {
+        target.justify = *self;
+    }
+}
+
+impl LayoutSetting<FlexLayout> for Align {
+    fn apply(&self, target: &mut FlexLayout) {
+        target.align = *self;
+    }
+}
+
+impl LayoutSetting<FlexLayout> for Gap {
+    fn apply(&self, target: &mut FlexLayout) {
+        target.gap = self.0;
+    }
+}
+
+impl LayoutSetting<FlexLayout> for Padding {
+    fn apply(&self, target: &mut FlexLayout) {
+        target.padding = *self;
+    }
+}
+
+impl LayoutSetting<FlexItem> for Grow {
+    fn apply(&self, target: &mut FlexItem) {
+        target.grow = self.0;
+    }
+}
+
+impl LayoutSetting<FlexItem> for Shrink {
+    fn apply(&self, target: &mut FlexItem) {
+        target.shrink = self.0;
+    }
+}
+
+impl LayoutSetting<FlexItem> for Basis {
+    fn apply(&self, target: &mut FlexItem) {
+        target.basis = Some(self.0);
+    }
+}
+
+impl LayoutSetting<FlexItem> for AlignSelf {
+    fn apply(&self, target: &mut FlexItem) {
+        target.align_self = Some(self.0);
+    }
+}
+
+impl LayoutSetting<FlexItem> for PreferredSize {
+    fn apply(&self, target: &mut FlexItem) {
+        target.preferred = self.0;
+    }
+}
+
+impl LayoutSetting<FlexItem> for MinSize {
+    fn apply(&self, target: &mut FlexItem) {
+        target.min = self.0;
+    }
+}
+
+impl LayoutSetting<FlexItem> for MaxSize {
+    fn apply(&self, target: &mut FlexItem) {
+        target.max = self.0;
+    }
+}
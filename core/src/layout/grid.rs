@@ -0,0 +1,342 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use super::{Gap, LayoutChildren, LayoutSetting, Padding};
+use crate::data::{Rect, Size};
+use crate::utils::{Deserialize, Serialize};
+
+/// How the size of a grid row or column is determined.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Track {
+    /// Always this many logical pixels.
+    Fixed(f64),
+    /// A share of the space left over by all other tracks, relative to the
+    /// sum of all fractions.
+    Fraction(f64),
+    /// Just big enough for the preferred size of the children placed in it.
+    #[default]
+    Auto,
+}
+
+/// The column tracks of a [`GridLayout`].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Columns(pub Vec<Track>);
+
+/// The row tracks of a [`GridLayout`].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rows(pub Vec<Track>);
+
+/// Space between the columns of a [`GridLayout`], in logical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColumnGap(pub f64);
+
+/// Space between the rows of a [`GridLayout`], in logical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RowGap(pub f64);
+
+/// The row a child of a [`GridLayout`] starts in, counting from 0.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub struct GridRow(pub usize);
+
+/// The column a child of a [`GridLayout`] starts in, counting from 0.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub struct GridColumn(pub usize);
+
+/// How many rows a child of a [`GridLayout`] covers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct RowSpan(pub usize);
+
+/// How many columns a child of a [`GridLayout`] covers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ColumnSpan(pub usize);
+
+/// Layout properties of a container, placing its children into the cells of
+/// a grid.
+///
+/// Children placed outside of the defined tracks get additional
+/// [`Track::Auto`] tracks.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GridLayout {
+    pub columns: Vec<Track>,
+    pub rows: Vec<Track>,
+    pub column_gap: f64,
+    pub row_gap: f64,
+    pub padding: Padding,
+}
+
+/// Placement of a single child of a [`GridLayout`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct GridItem {
+    pub row: usize,
+    pub column: usize,
+    pub row_span: usize,
+    pub column_span: usize,
+}
+
+impl Default for GridItem {
+    fn default() -> Self {
+        Self {
+            row: 0,
+            column: 0,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+}
+
+impl GridItem {
+    pub fn from_settings<SETTINGS>(settings: &SETTINGS) -> Self
+    where
+        SETTINGS: LayoutSetting<Self>,
+    {
+        let mut item = Self::default();
+        settings.apply(&mut item);
+        item
+    }
+}
+
+/// A child as seen by the track sizing of a single axis.
+struct Span {
+    start:     usize,
+    length:    usize,
+    preferred: f64,
+}
+
+/// Sizes `tracks` so they fill `available`, growing the list with
+/// [`Track::Auto`] tracks for children placed outside of it.
+fn size_tracks(tracks: &[Track], spans: &[Span], gap: f64, available: f64) -> Vec<f64> {
+    let count = spans
+        .iter()
+        .map(|span| span.start + span.length)
+        .max()
+        .unwrap_or(0)
+        .max(tracks.len());
+    let track = |index: usize| tracks.get(index).copied().unwrap_or_default();
+
+    let mut sizes: Vec<f64> = (0..count)
+        .map(|index| match track(index) {
+            Track::Fixed(size) => size,
+            _ => 0.0,
+        })
+        .collect();
+
+    // Children spanning several tracks only grow auto tracks once every
+    // single track child got its space, and never touch fraction tracks.
+    let mut ordered: Vec<&Span> = spans.iter().collect();
+    ordered.sort_by_key(|span| span.length);
+    for span in ordered {
+        let range = span.start..span.start + span.length;
+        let auto: Vec<usize> = range
+            .clone()
+            .filter(|index| track(*index) == Track::Auto)
+            .collect();
+        let fraction = range
+            .clone()
+            .any(|index| matches!(track(index), Track::Fraction(_)));
+        if auto.is_empty() || fraction {
+            continue;
+        }
+
+        let current: f64 = sizes[range].iter().sum::<f64>() + gap * (span.length - 1) as f64;
+        let missing = span.preferred - current;
+        if missing > 0.0 {
+            for index in &auto {
+                sizes[*index] += missing / auto.len() as f64;
+            }
+        }
+    }
+
+    let fractions: f64 = (0..count)
+        .map(|index| match track(index) {
+            Track::Fraction(fraction) => fraction,
+            _ => 0.0,
+        })
+        .sum();
+    if fractions > 0.0 {
+        let used = sizes.iter().sum::<f64>() + gap * count.saturating_sub(1) as f64;
+        let free = (available - used).max(0.0);
+        for (index, size) in sizes.iter_mut().enumerate() {
+            if let Track::Fraction(fraction) = track(index) {
+                *size = free * fraction / fractions;
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Start of every track, relative to the first one.
+fn offsets(sizes: &[f64], gap: f64) -> Vec<f64> {
+    sizes
+        .iter()
+        .scan(0.0, |position, size| {
+            let start = *position;
+            *position += size + gap;
+            Some(start)
+        })
+        .collect()
+}
+
+/// Length of `length` tracks beginning at `start`, including the gaps between
+/// them.
+fn extent(sizes: &[f64], start: usize, length: usize, gap: f64) -> f64 {
+    sizes[start..start + length].iter().sum::<f64>() + gap * (length - 1) as f64
+}
+
+impl GridLayout {
+    pub fn from_settings<SETTINGS>(settings: &SETTINGS) -> Self
+    where
+        SETTINGS: LayoutSetting<Self>,
+    {
+        let mut layout = Self::default();
+        settings.apply(&mut layout);
+        layout
+    }
+
+    /// Computes the cell frames of `items` inside a container of `size`.
+    ///
+    /// `preferred` holds the preferred size of every item and is used to size
+    /// [`Track::Auto`] tracks. Children fill their whole cell. Frames are
+    /// relative to the top left corner of the container and returned in the
+    /// order of `items`.
+    pub fn compute(&self, size: Size, items: &[GridItem], preferred: &[Size]) -> Vec<Rect> {
+        let inner = Size::new(
+            (size.width - self.padding.left - self.padding.right).max(0.0),
+            (size.height - self.padding.top - self.padding.bottom).max(0.0),
+        );
+        let column_spans: Vec<Span> = items
+            .iter()
+            .zip(preferred)
+            .map(|(item, preferred)| Span {
+                start:     item.column,
+                length:    item.column_span.max(1),
+                preferred: preferred.width,
+            })
+            .collect();
+        let row_spans: Vec<Span> = items
+            .iter()
+            .zip(preferred)
+            .map(|(item, preferred)| Span {
+                start:     item.row,
+                length:    item.row_span.max(1),
+                preferred: preferred.height,
+            })
+            .collect();
+
+        let widths = size_tracks(&self.columns, &column_spans, self.column_gap, inner.width);
+        let heights = size_tracks(&self.rows, &row_spans, self.row_gap, inner.height);
+        let lefts = offsets(&widths, self.column_gap);
+        let tops = offsets(&heights, self.row_gap);
+
+        column_spans
+            .iter()
+            .zip(&row_spans)
+            .map(|(column, row)| {
+                Rect::new(
+                    self.padding.left + lefts[column.start],
+                    self.padding.top + tops[row.start],
+                    extent(&widths, column.start, column.length, self.column_gap),
+                    extent(&heights, row.start, row.length, self.row_gap),
+                )
+            })
+            .collect()
+    }
+
+    /// Computes the cell frames of `children` inside a container of `size`
+    /// and hands every child its frame.
+    pub fn arrange<CHILDREN>(&self, size: Size, children: &CHILDREN) -> Vec<Rect>
+    where
+        CHILDREN: LayoutChildren,
+    {
+        let mut items = Vec::new();
+        let mut preferred = Vec::new();
+        children.collect_grid_items(&mut items, &mut preferred);
+        let frames = self.compute(size, &items, &preferred);
+        children.assign_frames(&mut frames.iter());
+        frames
+    }
+}
+
+impl LayoutSetting<GridLayout> for Columns {
+    fn apply(&self, target: &mut GridLayout) {
+        target.columns = self.0.clone();
+    }
+}
+
+impl LayoutSetting<GridLayout> for Rows {
+    fn apply(&self, target: &mut GridLayout) {
+        target.rows = self.0.clone();
+    }
+}
+
+impl LayoutSetting<GridLayout> for ColumnGap {
+    fn apply(&self, target: &mut GridLayout) {
+        target.column_gap = self.0;
+    }
+}
+
+impl LayoutSetting<GridLayout> for RowGap {
+    fn apply(&self, target: &mut GridLayout) {
+        target.row_gap = self.0;
+    }
+}
+
+/// Sets the column and the row gap.
+impl LayoutSetting<GridLayout> for Gap {
+    fn apply(&self, target: &mut GridLayout) {
+        target.column_gap = self.0;
+        target.row_gap = self.0;
+    }
+}
+
+impl LayoutSetting<GridLayout> for Padding {
+    fn apply(&self, target: &mut GridLayout) {
+        target.padding = *self;
+    }
+}
+
+impl LayoutSetting<GridItem> for GridRow {
+    fn apply(&self, target: &mut GridItem) {
+        target.row = self.0;
+    }
+}
+
+impl LayoutSetting<GridItem> for GridColumn {
+    fn apply(&self, target: &mut GridItem) {
+        target.column = self.0;
+    }
+}
+
+impl LayoutSetting<GridItem> for RowSpan {
+    fn apply(&self, target: &mut GridItem) {
+        target.row_span = self.0;
+    }
+}
+
+impl LayoutSetting<GridItem> for ColumnSpan {
+    fn apply(&self, target: &mut GridItem) {
+        target.column_span = self.0;
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+//! Backend independent layout of the contents of a `ChildrenOutlet`.
+//!
+//! Every System uses the same engine, so native, mock and headless backends
+//! arrive at the same frames for the same widget tree.
+
+use std::slice::Iter;
+
+use crate::data::{Rect, Size};
+use crate::utils::settings::SettingsList;
+
+mod flex;
+pub use flex::*;
+
+mod grid;
+pub use grid::*;
+
+/// A layout property that can be part of a settings list.
+///
+/// Settings lists apply their settings in order, so later settings win.
+pub trait LayoutSetting<TARGET> {
+    fn apply(&self, target: &mut TARGET);
+}
+
+impl<TARGET> LayoutSetting<TARGET> for () {
+    fn apply(&self, _target: &mut TARGET) {}
+}
+
+impl<TARGET, Head, Tail> LayoutSetting<TARGET> for (Head, Tail)
+where
+    Head: LayoutSetting<TARGET>,
+    Tail: LayoutSetting<TARGET> + SettingsList,
+{
+    fn apply(&self, target: &mut TARGET) {
+        self.0.apply(target);
+        self.1.apply(target);
+    }
+}
+
+/// Implemented by widgets that arrange the contents of their
+/// `ChildrenOutlet` with a [`FlexLayout`].
+///
+/// Grid containers keep their [`GridLayout`] themselves, see
+/// [`Grid`](crate::widgets::Grid).
+pub trait LayoutContainer {
+    fn flex_layout(&self) -> FlexLayout;
+    fn set_flex_layout(&self, layout: FlexLayout);
+
+    fn apply_container_settings<SETTINGS>(&self, settings: &SETTINGS)
+    where
+        SETTINGS: LayoutSetting<FlexLayout>,
+    {
+        let mut layout = self.flex_layout();
+        settings.apply(&mut layout);
+        self.set_flex_layout(layout);
+    }
+}
+
+/// Implemented by widgets that can be placed by a [`FlexLayout`] or a
+/// [`GridLayout`].
+pub trait LayoutChild {
+    fn flex_item(&self) -> FlexItem;
+    fn set_flex_item(&self, item: FlexItem);
+    fn grid_item(&self) -> GridItem;
+    fn set_grid_item(&self, item: GridItem);
+
+    /// The frame assigned by the last layout pass, relative to the parent.
+    fn frame(&self) -> Rect;
+    fn set_frame(&self, frame: Rect);
+
+    fn apply_item_settings<SETTINGS>(&self, settings: &SETTINGS)
+    where
+        SETTINGS: LayoutSetting<FlexItem>,
+    {
+        let mut item = self.flex_item();
+        settings.apply(&mut item);
+        self.set_flex_item(item);
+    }
+
+    fn apply_grid_settings<SETTINGS>(&self, settings: &SETTINGS)
+    where
+        SETTINGS: LayoutSetting<GridItem>,
+    {
+        let mut item = self.grid_item();
+        settings.apply(&mut item);
+        self.set_grid_item(item);
+    }
+}
+
+/// A children list whose children all implement [`LayoutChild`].
+pub trait LayoutChildren {
+    fn collect_items(&self, items: &mut Vec<FlexItem>);
+    /// Collects the grid placement and the preferred size of every child.
+    fn collect_grid_items(&self, items: &mut Vec<GridItem>, preferred: &mut Vec<Size>);
+    fn assign_frames(&self, frames: &mut Iter<Rect>);
+}
+
+impl LayoutChildren for () {
+    fn collect_items(&self, _items: &mut Vec<FlexItem>) {}
+
+    fn collect_grid_items(&self, _items: &mut Vec<GridItem>, _preferred: &mut Vec<Size>) {}
+
+    fn assign_frames(&self, _frames: &mut Iter<Rect>) {}
+}
+
+impl<Head, Tail> LayoutChildren for (Head, Tail)
+where
+    Head: LayoutChild,
+    Tail: LayoutChildren,
+{
+    fn collect_items(&self, items: &mut Vec<FlexItem>) {
+        items.push(self.0.flex_item());
+        self.1.collect_items(items);
+    }
+
+    fn collect_grid_items(&self, items: &mut Vec<GridItem>, preferred: &mut Vec<Size>) {
+        items.push(self.0.grid_item());
+        preferred.push(self.0.flex_item().preferred);
+        self.1.collect_grid_items(items, preferred);
+    }
+
+    fn assign_frames(&self, frames: &mut Iter<Rect>) {
+        if let Some(frame) = frames.next() {
+            self.0.set_frame(*frame);
+        }
+        self.1.assign_frames(frames);
+    }
+}
+
+pub mod prelude {
+    pub use super::{LayoutChild, LayoutContainer};
+}
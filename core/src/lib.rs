@@ -32,9 +32,13 @@ pub(crate) struct Private {}
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Default Result type used in plating
-pub type PlatingResult<T> = Result<T, anyhow::Error>;
+pub type PlatingResult<T> = Result<T, PlatingError>;
+
+mod error;
+pub use error::PlatingError;
 
 pub mod data;
+pub mod layout;
 pub mod utils;
 pub mod widgets;
 
@@ -42,6 +46,7 @@ pub mod mock;
 
 pub mod prelude {
     pub use super::data::prelude::*;
+    pub use super::layout::prelude::*;
     pub use super::utils::prelude::*;
     pub use super::widgets::prelude::*;
 }
@@ -60,14 +65,937 @@ mod tests {
 
         <MockWindowWidget<_> as mock::MockWindow<_>>::new(MockWindowOutlet {
             children: children_list!(b),
-            menu:     (),
+            menu: (),
+            toolbar: (),
+            status_bar: (),
         })?;
 
 
         let _a = MockWindowWidget::new(WindowOutlet {
             children: (),
-            menu:     (),
+            menu: (),
+            toolbar: (),
+            status_bar: (),
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn table_view_only_materializes_visible_rows() -> PlatingResult<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use crate::mock::{MockTableView, MockTableViewWidget};
+        use crate::widgets::{SortChanged, TableViewOutlet};
+
+        struct Numbers {
+            rows:    Vec<i64>,
+            queried: Rc<RefCell<Vec<usize>>>,
+        }
+        impl DataSource for Numbers {
+            fn row_count(&self) -> usize {
+                self.rows.len()
+            }
+
+            fn columns(&self) -> Vec<ColumnDefinition> {
+                vec![ColumnDefinition::new("value", true)]
+            }
+
+            fn cell_value(&self, row: usize, _column: usize) -> CellValue {
+                self.queried.borrow_mut().push(row);
+                self.rows[row].into()
+            }
+
+            fn sort(
+                &mut self,
+                _column: usize,
+                order: SortOrder,
+            ) -> PlatingResult<Option<Vec<usize>>> {
+                let mut previous: Vec<usize> = (0..self.rows.len()).collect();
+                previous.sort_by_key(|row| self.rows[*row]);
+                if order == SortOrder::Descending {
+                    previous.reverse();
+                }
+                self.rows = previous.iter().map(|row| self.rows[*row]).collect();
+                Ok(Some(previous))
+            }
+        }
+
+        let queried = Rc::new(RefCell::new(Vec::new()));
+        let source = Numbers {
+            rows:    (0..100_000).collect(),
+            queried: queried.clone(),
+        };
+        let mut table =
+            <MockTableViewWidget<_, _> as MockTableView<_, _>>::new(TableViewOutlet {}, source)?;
+        table.set_visible_row_count(10)?;
+        assert_eq!(table.visible_rows(), 0..10);
+
+        queried.borrow_mut().clear();
+        table.scroll_to_row(50_000)?;
+        assert_eq!(table.visible_rows(), 49_991..50_001);
+        assert_eq!(queried.borrow().len(), 10);
+
+        let sorted = Rc::new(RefCell::new(Vec::new()));
+        let sorted_handle = sorted.clone();
+        table.on(move |event: &SortChanged| sorted_handle.borrow_mut().push(event.order));
+        table.click_column_header(0)?;
+        table.click_column_header(0)?;
+        assert_eq!(
+            *sorted.borrow(),
+            vec![SortOrder::Ascending, SortOrder::Descending]
+        );
+        assert_eq!(
+            table.materialized_rows()[0].cells,
+            vec![CellValue::Integer(99_999 - 49_991)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn table_view_selection_follows_sorted_rows() -> PlatingResult<()> {
+        use crate::mock::{MockTableView, MockTableViewWidget};
+        use crate::widgets::TableViewOutlet;
+
+        struct Names {
+            rows: Vec<&'static str>,
+        }
+        impl DataSource for Names {
+            fn row_count(&self) -> usize {
+                self.rows.len()
+            }
+
+            fn columns(&self) -> Vec<ColumnDefinition> {
+                vec![ColumnDefinition::new("name", true)]
+            }
+
+            fn cell_value(&self, row: usize, _column: usize) -> CellValue {
+                self.rows[row].into()
+            }
+
+            fn sort(
+                &mut self,
+                _column: usize,
+                order: SortOrder,
+            ) -> PlatingResult<Option<Vec<usize>>> {
+                let mut previous: Vec<usize> = (0..self.rows.len()).collect();
+                previous.sort_by_key(|row| self.rows[*row]);
+                if order == SortOrder::Descending {
+                    previous.reverse();
+                }
+                self.rows = previous.iter().map(|row| self.rows[*row]).collect();
+                Ok(Some(previous))
+            }
+        }
+
+        let source = Names {
+            rows: vec!["cherry", "apple", "banana", "date"],
+        };
+        let mut table =
+            <MockTableViewWidget<_, _> as MockTableView<_, _>>::new(TableViewOutlet {}, source)?;
+        table.set_selection_mode(SelectionMode::Multiple)?;
+        // cherry and banana
+        table.set_selection([0, 2].iter().copied().collect())?;
+
+        table.sort_by(0, SortOrder::Ascending)?;
+        assert_eq!(
+            table.source().rows,
+            vec!["apple", "banana", "cherry", "date"]
+        );
+        assert_eq!(table.selection().rows().collect::<Vec<_>>(), vec![1, 2]);
+
+        table.click_column_header(0)?;
+        assert_eq!(
+            table.source().rows,
+            vec!["date", "cherry", "banana", "apple"]
+        );
+        assert_eq!(table.selection().rows().collect::<Vec<_>>(), vec![1, 2]);
+
+        table.set_selection(Selection::single(0))?;
+        table.sort_by(0, SortOrder::Ascending)?;
+        assert_eq!(table.selection().rows().collect::<Vec<_>>(), vec![3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tree_view_loads_children_on_expand() -> PlatingResult<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use crate::mock::{MockTreeView, MockTreeViewWidget};
+        use crate::widgets::{NodeExpanded, TreeViewOutlet};
+
+        /// Every node has three children, down to a depth of three.
+        struct Levels {
+            loads: Rc<RefCell<Vec<TreePath>>>,
+        }
+        impl TreeDataSource for Levels {
+            fn load_children(&mut self, parent: &TreePath) -> PlatingResult<()> {
+                self.loads.borrow_mut().push(parent.clone());
+                Ok(())
+            }
+
+            fn has_children(&self, node: &TreePath) -> bool {
+                node.depth() < 3
+            }
+
+            fn child_count(&self, parent: &TreePath) -> usize {
+                if self.has_children(parent) {
+                    3
+                } else {
+                    0
+                }
+            }
+
+            fn cell_value(&self, node: &TreePath, _column: usize) -> CellValue {
+                node.to_string().into()
+            }
+        }
+
+        let loads = Rc::new(RefCell::new(Vec::new()));
+        let mut tree = <MockTreeViewWidget<_, _> as MockTreeView<_, _>>::new(
+            TreeViewOutlet {},
+            Levels {
+                loads: loads.clone(),
+            },
+        )?;
+        assert_eq!(*loads.borrow(), vec![TreePath::root()]);
+        assert_eq!(tree.visible_nodes().len(), 3);
+
+        let expanded = Rc::new(RefCell::new(Vec::new()));
+        let expanded_handle = expanded.clone();
+        tree.on(move |event: &NodeExpanded| expanded_handle.borrow_mut().push(event.node.clone()));
+
+        let first = TreePath::root().child(0);
+        assert!(tree.expand_node(&first.child(1)).is_err());
+        tree.expand_node(&first)?;
+        tree.expand_node(&first.child(1))?;
+        tree.expand_node(&first.child(1).child(0))?;
+        assert_eq!(*expanded.borrow(), vec![first.clone(), first.child(1)]);
+        assert_eq!(tree.loaded_child_count(&first.child(2)), None);
+        assert_eq!(tree.visible_nodes().len(), 9);
+
+        tree.collapse(&first)?;
+        assert_eq!(tree.visible_nodes().len(), 3);
+        tree.expand(&first)?;
+        assert_eq!(tree.visible_nodes().len(), 9);
+        assert_eq!(loads.borrow().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn closing_selected_tab_selects_neighbour() -> PlatingResult<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use crate::mock::{MockTab, MockTabWidget, MockTabs, MockTabsWidget};
+        use crate::widgets::{TabOutlet, TabSelectionChanged, TabsOutlet};
+
+        let mut closable = <MockTabWidget<_> as MockTab<_>>::new(
+            TabOutlet {
+                children: children_list!(MockButtonWidget::new(MockButtonOutlet {})?),
+            },
+            "Closable",
+        )?;
+        closable.set_closable(true)?;
+        let fixed = <MockTabWidget<_> as MockTab<_>>::new(TabOutlet { children: () }, "Fixed")?;
+
+        let mut tabs = <MockTabsWidget<_> as MockTabs<_>>::new(TabsOutlet {
+            tabs: children_list!(fixed, closable),
+        })?;
+        assert_eq!(tabs.tab_count(), 2);
+        assert_eq!(tabs.tab_label(1), Some(String::from("Closable")));
+        assert_eq!(tabs.selected_tab(), Some(0));
+
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let changes_handle = changes.clone();
+        tabs.on(move |event: &TabSelectionChanged| changes_handle.borrow_mut().push(*event));
+
+        assert!(tabs.close_tab(0).is_err());
+        tabs.click_tab(1)?;
+        tabs.close_tab(1)?;
+        assert!(!tabs.is_tab_open(1));
+        assert_eq!(tabs.selected_tab(), Some(0));
+        assert_eq!(
+            *changes.borrow(),
+            vec![
+                TabSelectionChanged {
+                    selected: Some(1),
+                    previous: Some(0),
+                },
+                TabSelectionChanged {
+                    selected: Some(0),
+                    previous: Some(1),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_containers() -> PlatingResult<()> {
+        use crate::mock::{
+            MockGroupBox,
+            MockGroupBoxWidget,
+            MockOnly,
+            MockOnlyOutlet,
+            MockOnlyWidget,
+            MockScrollView,
+            MockScrollViewWidget,
+            MockSplitView,
+            MockSplitViewWidget,
+        };
+        use crate::widgets::{GroupBoxOutlet, ScrollViewOutlet, SplitViewOutlet};
+
+        let mut split = <MockSplitViewWidget<_> as MockSplitView<_>>::new(
+            SplitViewOutlet {
+                children: children_list!(
+                    MockButtonWidget::new(MockButtonOutlet {})?,
+                    MockOnlyWidget::new(MockOnlyOutlet {})?,
+                    MockOnlyWidget::new(MockOnlyOutlet {})?
+                ),
+            },
+            Orientation::Horizontal,
+        )?;
+        assert_eq!(split.divider_positions().len(), 2);
+        split.drag_divider(0, 1000.0)?;
+        assert_eq!(split.divider_positions()[0], split.divider_positions()[1]);
+
+        let mut scroll = <MockScrollViewWidget<_> as MockScrollView<_>>::new(ScrollViewOutlet {
+            children: children_list!(MockButtonWidget::new(MockButtonOutlet {})?, split),
+        })?;
+        scroll.set_content_size(Size::new(400.0, 1000.0))?;
+        scroll.set_child_frame(1, Rect::new(0.0, 600.0, 400.0, 100.0))?;
+        scroll.scroll_to_child(1)?;
+        assert_eq!(scroll.scroll_offset(), Point::new(0.0, 400.0));
+        assert!(matches!(
+            scroll.scroll_to_child(0),
+            Err(PlatingError::InvalidState(_))
+        ));
+
+        let group = <MockGroupBoxWidget<_> as MockGroupBox<_>>::new(
+            GroupBoxOutlet {
+                children: children_list!(scroll),
+            },
+            "Settings",
+        )?;
+        assert_eq!(group.title(), "Settings");
+
+        let _window = <MockWindowWidget<_> as mock::MockWindow<_>>::new(MockWindowOutlet {
+            children: children_list!(group),
+            menu: (),
+            toolbar: (),
+            status_bar: (),
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn menu_picks_nested_items() -> PlatingResult<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use crate::mock::{
+            MockCheckMenuItem,
+            MockCheckMenuItemWidget,
+            MockMenu,
+            MockMenuEntry,
+            MockMenuItem,
+            MockMenuItemWidget,
+            MockMenuSeparator,
+            MockMenuSeparatorWidget,
+            MockMenuWidget,
+        };
+        use crate::widgets::{
+            CheckMenuItemOutlet,
+            CheckedChanged,
+            MenuItemActivated,
+            MenuItemOutlet,
+            MenuItemsOutlet,
+            MenuSeparatorOutlet,
+        };
+
+        let activated = Rc::new(RefCell::new(Vec::new()));
+        let mut open = <MockMenuItemWidget<_> as MockMenuItem<_>>::new(MenuItemOutlet {}, "Open")?;
+        let recorder = activated.clone();
+        open.on(move |event: &MenuItemActivated| recorder.borrow_mut().push(event.label.clone()));
+        let mut recent =
+            <MockMenuItemWidget<_> as MockMenuItem<_>>::new(MenuItemOutlet {}, "notes.txt")?;
+        let recorder = activated.clone();
+        recent.on(move |event: &MenuItemActivated| recorder.borrow_mut().push(event.label.clone()));
+
+        let toggles = Rc::new(RefCell::new(Vec::new()));
+        let mut autosave = <MockCheckMenuItemWidget<_> as MockCheckMenuItem<_>>::new(
+            CheckMenuItemOutlet {},
+            "Autosave",
+        )?;
+        let recorder = toggles.clone();
+        autosave.on(move |event: &CheckedChanged| recorder.borrow_mut().push(event.checked));
+
+        let submenu = <MockMenuWidget<_> as MockMenu<_>>::new(
+            MenuItemsOutlet {
+                items: children_list!(recent),
+            },
+            "Open Recent",
+        )?;
+        let file = <MockMenuWidget<_> as MockMenu<_>>::new(
+            MenuItemsOutlet {
+                items: children_list!(
+                    open,
+                    autosave,
+                    <MockMenuSeparatorWidget<_> as MockMenuSeparator<_>>::new(
+                        MenuSeparatorOutlet {}
+                    )?,
+                    submenu
+                ),
+            },
+            "File",
+        )?;
+
+        file.pick(&[0])?;
+        file.pick(&[3, 0])?;
+        file.pick(&[1])?;
+        assert!(file.pick(&[2]).is_err());
+        assert!(file.pick(&[3]).is_err());
+        assert!(file.pick(&[0, 0]).is_err());
+        assert!(matches!(
+            file.pick(&[4]),
+            Err(PlatingError::OutOfRange {
+                item: "entry",
+                index: 4,
+                ..
+            })
+        ));
+
+        assert_eq!(*activated.borrow(), vec!["Open", "notes.txt"]);
+        assert_eq!(*toggles.borrow(), vec![true]);
+        assert_eq!(
+            file.entries(),
+            vec![
+                MockMenuEntry::Item {
+                    label: String::from("Open"),
+                },
+                MockMenuEntry::CheckItem {
+                    label:   String::from("Autosave"),
+                    checked: true,
+                },
+                MockMenuEntry::Separator,
+                MockMenuEntry::Submenu {
+                    label:   String::from("Open Recent"),
+                    entries: vec![MockMenuEntry::Item {
+                        label: String::from("notes.txt"),
+                    }],
+                },
+            ]
+        );
+
+        let _window = <MockWindowWidget<_> as mock::MockWindow<_>>::new(MockWindowOutlet {
+            children: (),
+            menu: children_list!(file),
+            toolbar: (),
+            status_bar: (),
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn context_menu_reports_choice_and_position() -> PlatingResult<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use crate::mock::{
+            MockContextMenuHost,
+            MockMenu,
+            MockMenuItem,
+            MockMenuItemWidget,
+            MockMenuWidget,
+        };
+        use crate::widgets::{
+            ContextMenuItemChosen,
+            ContextMenuOpened,
+            ContextMenuTrigger,
+            MenuItemOutlet,
+            MenuItemsOutlet,
+            Widget,
+            WithContextMenu,
+        };
+
+        let mut menu = <MockMenuWidget<_> as MockMenu<_>>::new(
+            MenuItemsOutlet {
+                items: children_list!(
+                    <MockMenuItemWidget<_> as MockMenuItem<_>>::new(MenuItemOutlet {}, "Copy")?,
+                    <MockMenuItemWidget<_> as MockMenuItem<_>>::new(MenuItemOutlet {}, "Paste")?
+                ),
+            },
+            "Edit",
+        )?;
+        let opened = Rc::new(RefCell::new(Vec::new()));
+        let recorder = opened.clone();
+        menu.on(move |event: &ContextMenuOpened| recorder.borrow_mut().push(*event));
+        let chosen = Rc::new(RefCell::new(Vec::new()));
+        let recorder = chosen.clone();
+        menu.on(move |event: &ContextMenuItemChosen| recorder.borrow_mut().push(event.clone()));
+
+        let button = MockButtonWidget::new(WithContextMenu {
+            outlet: MockButtonOutlet {},
+            context_menu: children_list!(menu),
+        })?;
+        let menu = &button.outlet().context_menu.0;
+
+        // picking from a closed menu does not report a context menu choice
+        menu.pick(&[0])?;
+        assert!(chosen.borrow().is_empty());
+
+        button.secondary_click(Point::new(12.0, 8.0))?;
+        assert!(menu.is_open());
+        menu.pick(&[1])?;
+        assert!(!menu.is_open());
+
+        button.press_context_menu_key()?;
+        menu.pick(&[0])?;
+
+        assert_eq!(
+            *opened.borrow(),
+            vec![
+                ContextMenuOpened {
+                    position: Point::new(12.0, 8.0),
+                    trigger:  ContextMenuTrigger::SecondaryClick,
+                },
+                ContextMenuOpened {
+                    position: Point::default(),
+                    trigger:  ContextMenuTrigger::Keyboard,
+                },
+            ]
+        );
+        assert_eq!(
+            *chosen.borrow(),
+            vec![
+                ContextMenuItemChosen {
+                    path:     vec![1],
+                    label:    String::from("Paste"),
+                    position: Point::new(12.0, 8.0),
+                },
+                ContextMenuItemChosen {
+                    path:     vec![0],
+                    label:    String::from("Copy"),
+                    position: Point::default(),
+                },
+            ]
+        );
+
+        let plain = MockButtonWidget::new(WithContextMenu {
+            outlet: MockButtonOutlet {},
+            context_menu: (),
+        })?;
+        assert!(matches!(
+            plain.secondary_click(Point::default()),
+            Err(PlatingError::Unsupported("context menu"))
+        ));
+
+        // windows keep all their outlets when wrapped
+        let window_menu = <MockMenuWidget<_> as MockMenu<_>>::new(
+            MenuItemsOutlet {
+                items: children_list!(<MockMenuItemWidget<_> as MockMenuItem<_>>::new(
+                    MenuItemOutlet {},
+                    "Close"
+                )?),
+            },
+            "Window",
+        )?;
+        let window = <MockWindowWidget<_> as mock::MockWindow<_>>::new(WithContextMenu {
+            outlet: MockWindowOutlet {
+                children: children_list!(MockButtonWidget::new(MockButtonOutlet {})?),
+                menu: (),
+                toolbar: children_list!(MockButtonWidget::new(MockButtonOutlet {})?),
+                status_bar: children_list!(MockButtonWidget::new(MockButtonOutlet {})?),
+            },
+            context_menu: children_list!(window_menu),
+        })?;
+        let _ = &window.outlet().outlet.toolbar.0;
+        let _ = &window.outlet().outlet.status_bar.0;
+
+        window.secondary_click(Point::new(4.0, 2.0))?;
+        let window_menu = &window.outlet().context_menu.0;
+        assert!(window_menu.is_open());
+        window_menu.pick(&[0])?;
+        assert!(!window_menu.is_open());
+
+        Ok(())
+    }
+
+    #[test]
+    fn dialogs_answer_sync_and_async() -> PlatingResult<()> {
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::task::{Context, Poll, Waker};
+
+        use crate::mock::{
+            MockAutoAnswer,
+            MockDialog,
+            MockDialogAnswer,
+            MockDialogWidget,
+            MockDialogs,
+        };
+        use crate::widgets::{DialogResponse, MessageOptions, Modality, PromptOptions};
+
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let window = <MockWindowWidget<_> as mock::MockWindow<_>>::new(MockWindowOutlet {
+            children: (),
+            menu: (),
+            toolbar: (),
+            status_bar: (),
+        })?;
+        let mut dialog = <MockDialogWidget<_> as MockDialog<_>>::new(
+            WindowOutlet {
+                children: children_list!(MockButtonWidget::new(MockButtonOutlet {})?),
+                menu: (),
+                toolbar: (),
+                status_bar: (),
+            },
+            "Preferences",
+        )?;
+
+        let mut pending = dialog.show_modal(&window)?;
+        assert_eq!(dialog.modality(), Some(Modality::Modal));
+        assert!(!window.accepts_input());
+        assert!(dialog.show().is_err());
+        assert!(Pin::new(&mut pending).poll(&mut cx).is_pending());
+
+        dialog.close_by_user(DialogResponse::Accept)?;
+        assert!(window.accepts_input());
+        assert!(matches!(
+            Pin::new(&mut pending).poll(&mut cx),
+            Poll::Ready(Ok(DialogResponse::Accept))
+        ));
+
+        let modeless = dialog.show()?;
+        assert!(window.accepts_input());
+        dialog.close(DialogResponse::Custom(3))?;
+        assert_eq!(modeless.wait()?, DialogResponse::Custom(3));
+
+        let abandoned = dialog.show_modal(&window)?;
+        drop(dialog);
+        assert!(window.accepts_input());
+        assert!(matches!(abandoned.wait(), Err(PlatingError::NoResult)));
+
+        let dialogs = MockDialogs::new();
+        dialogs.queue_answer(MockDialogAnswer::Confirm(false));
+        assert!(!dialogs
+            .confirm(&MessageOptions::new("Quit", "Really quit?"))?
+            .wait()?);
+        let prompt = PromptOptions::new("Rename", "New name:").with_default_value("untitled");
+        assert_eq!(
+            dialogs.prompt(&prompt)?.wait()?,
+            Some(String::from("untitled"))
+        );
+        dialogs.set_auto_answer(MockAutoAnswer::Reject);
+        assert_eq!(dialogs.prompt(&prompt)?.wait()?, None);
+        dialogs.set_auto_answer(MockAutoAnswer::Fail);
+        assert!(dialogs
+            .message(&MessageOptions::new("Saved", "Done"))
+            .is_err());
+        assert_eq!(dialogs.shown().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_dialog_scripted_answers() -> PlatingResult<()> {
+        use std::path::PathBuf;
+
+        use crate::mock::{MockFileDialog, MockFileDialogAnswer, MockFileDialogKind};
+        use crate::widgets::{FileDialogOptions, FileFilter};
+
+        let dialog = <MockFileDialog as FileDialog>::new()?;
+        let import = FileDialogOptions::new("Import")
+            .with_default_path("/home/user")
+            .with_filter(FileFilter::new("Spreadsheets", &["*.csv", "*.ods"]))
+            .with_multi_select(true);
+
+        dialog.queue_answer(MockFileDialogAnswer::Pick(vec![
+            PathBuf::from("/home/user/a.csv"),
+            PathBuf::from("/home/user/b.ODS"),
+        ]));
+        assert_eq!(dialog.open_file(&import)?.wait()?.len(), 2);
+
+        dialog.queue_answer(MockFileDialogAnswer::pick("/home/user/notes.txt"));
+        assert!(dialog.open_file(&import).is_err());
+
+        let export = FileDialogOptions::new("Export")
+            .with_filter(FileFilter::new("CSV", &["*.csv"]))
+            .with_multi_select(true);
+        dialog.queue_answer(MockFileDialogAnswer::Pick(vec![
+            PathBuf::from("/tmp/a.csv"),
+            PathBuf::from("/tmp/b.csv"),
+        ]));
+        assert!(dialog.save_file(&export).is_err());
+        dialog.queue_answer(MockFileDialogAnswer::pick("/tmp/out.csv"));
+        assert_eq!(
+            dialog.save_file(&export)?.wait()?,
+            Some(PathBuf::from("/tmp/out.csv"))
+        );
+        dialog.queue_answer(MockFileDialogAnswer::Cancel);
+        assert_eq!(dialog.save_file(&export)?.wait()?, None);
+
+        dialog.queue_answer(MockFileDialogAnswer::pick("/tmp/backups"));
+        assert_eq!(
+            dialog
+                .choose_folder(&FileDialogOptions::new("Backup to"))?
+                .wait()?,
+            vec![PathBuf::from("/tmp/backups")]
+        );
+        assert!(dialog
+            .choose_folder(&FileDialogOptions::new("Backup to"))
+            .is_err());
+
+        assert_eq!(dialog.pending_answers(), 0);
+        let kinds: Vec<_> = dialog.shown().into_iter().map(|(kind, _)| kind).collect();
+        assert_eq!(kinds.first(), Some(&MockFileDialogKind::OpenFile));
+        assert_eq!(kinds.last(), Some(&MockFileDialogKind::ChooseFolder));
+        assert_eq!(kinds.len(), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tray_icon_and_notifications() -> PlatingResult<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use crate::mock::{
+            MockMenu,
+            MockMenuItem,
+            MockMenuItemWidget,
+            MockMenuWidget,
+            MockNotifications,
+            MockTrayIcon,
+            MockTrayIconWidget,
+        };
+        use crate::widgets::{
+            MenuItemActivated,
+            MenuItemOutlet,
+            MenuItemsOutlet,
+            Notification,
+            NotificationResponse,
+            TrayIconActivated,
+            TrayIconOutlet,
+            Widget,
+        };
+
+        let synced = Rc::new(RefCell::new(0));
+        let mut sync_now =
+            <MockMenuItemWidget<_> as MockMenuItem<_>>::new(MenuItemOutlet {}, "Sync now")?;
+        let counter = synced.clone();
+        sync_now.on(move |_: &MenuItemActivated| *counter.borrow_mut() += 1);
+        let mut tray = <MockTrayIconWidget<_> as MockTrayIcon<_>>::new(
+            TrayIconOutlet {
+                menu: children_list!(<MockMenuWidget<_> as MockMenu<_>>::new(
+                    MenuItemsOutlet {
+                        items: children_list!(sync_now),
+                    },
+                    "Sync",
+                )?),
+            },
+            Icon::named("sync-idle"),
+            "Up to date",
+        )?;
+        let counter = synced.clone();
+        tray.on(move |_: &TrayIconActivated| *counter.borrow_mut() += 10);
+
+        tray.click()?;
+        tray.outlet().menu.0.pick(&[0])?;
+        assert_eq!(*synced.borrow(), 11);
+        tray.set_visible(false)?;
+        assert!(tray.click().is_err());
+
+        let notifications = <MockNotifications as Notifications>::new()?;
+        let responses = Rc::new(RefCell::new(Vec::new()));
+        let recorder = responses.clone();
+        let conflict = notifications.notify(
+            &Notification::new("Sync conflict", "report.odt changed on two devices")
+                .with_action("Keep mine")
+                .with_action("Keep theirs"),
+            move |response| recorder.borrow_mut().push(response.clone()),
+        )?;
+        let done = notifications.notify(&Notification::new("Sync done", "42 files"), |_| {})?;
+
+        notifications.choose_action(conflict, 1)?;
+        assert!(notifications.click(conflict).is_err());
+        notifications.withdraw(done)?;
+        assert!(notifications.dismiss(done).is_err());
+
+        assert_eq!(
+            *responses.borrow(),
+            vec![NotificationResponse::Action {
+                index: 1,
+                label: String::from("Keep theirs"),
+            }]
+        );
+        let shown = notifications.shown();
+        assert_eq!(shown.len(), 2);
+        assert_eq!(shown[0].notification.actions.len(), 2);
+        assert!(shown.iter().all(|shown| !shown.pending));
+
+        Ok(())
+    }
+
+    #[test]
+    fn window_toolbar_and_status_bar() -> PlatingResult<()> {
+        use crate::mock::{MockOnly, MockOnlyOutlet, MockOnlyWidget};
+        use crate::widgets::Widget;
+
+        let window = <MockWindowWidget<_> as mock::MockWindow<_>>::new(MockWindowOutlet {
+            children: (),
+            menu: (),
+            toolbar: children_list!(
+                MockButtonWidget::new(MockButtonOutlet {})?,
+                MockOnlyWidget::new(MockOnlyOutlet {})?
+            ),
+            status_bar: children_list!(MockOnlyWidget::new(MockOnlyOutlet {})?),
+        })?;
+        let _ = &window.outlet().toolbar.1;
+        let _ = &window.outlet().status_bar.0;
+
+        let _window = <MockWindowWidget<_> as Window<_>>::new(WindowOutlet {
+            children: children_list!(MockButtonWidget::new(MockButtonOutlet {})?),
+            menu: (),
+            toolbar: children_list!(MockButtonWidget::new(MockButtonOutlet {})?),
+            status_bar: (),
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn flex_layout_computes_frames() -> PlatingResult<()> {
+        use crate::layout::*;
+        use crate::utils::settings::setting_list;
+        use crate::widgets::Widget;
+
+        let window = <MockWindowWidget<_> as mock::MockWindow<_>>::new(MockWindowOutlet {
+            children: children_list!(
+                MockButtonWidget::new(MockButtonOutlet {})?,
+                MockButtonWidget::new(MockButtonOutlet {})?,
+                MockButtonWidget::new(MockButtonOutlet {})?
+            ),
+            menu: (),
+            toolbar: (),
+            status_bar: (),
         })?;
+        window.apply_container_settings(&setting_list!(
+            Orientation::Horizontal,
+            Align::Center,
+            Gap(10.0),
+            Padding::all(5.0)
+        ));
+        let (first, (second, (third, ()))) = &window.outlet().children;
+        first.apply_item_settings(&setting_list!(
+            PreferredSize(Size::new(100.0, 20.0)),
+            MaxSize(Size::new(120.0, f64::INFINITY)),
+            Grow(1.0)
+        ));
+        second.apply_item_settings(&setting_list!(
+            Basis(50.0),
+            Grow(1.0),
+            AlignSelf(Align::Stretch)
+        ));
+        third.apply_item_settings(&setting_list!(PreferredSize(Size::new(80.0, 40.0))));
+
+        let frames = window.resize(Size::new(400.0, 100.0));
+        assert_eq!(
+            frames,
+            vec![
+                Rect::new(5.0, 40.0, 120.0, 20.0),
+                Rect::new(135.0, 5.0, 170.0, 90.0),
+                Rect::new(315.0, 30.0, 80.0, 40.0),
+            ]
+        );
+        assert_eq!(second.frame(), frames[1]);
+
+        let column = FlexLayout::from_settings(&setting_list!(Orientation::Vertical));
+        let shrinking = [
+            FlexItem::from_settings(&setting_list!(PreferredSize(Size::new(10.0, 80.0)))),
+            FlexItem::from_settings(&setting_list!(
+                PreferredSize(Size::new(10.0, 80.0)),
+                Shrink(3.0)
+            )),
+        ];
+        assert_eq!(
+            column.compute(Size::new(100.0, 100.0), &shrinking),
+            vec![
+                Rect::new(0.0, 0.0, 100.0, 65.0),
+                Rect::new(0.0, 65.0, 100.0, 35.0),
+            ]
+        );
+
+        let row = FlexLayout::from_settings(&setting_list!(Justify::SpaceBetween, Align::Start));
+        let fixed = FlexItem::from_settings(&setting_list!(PreferredSize(Size::new(10.0, 10.0))));
+        let xs: Vec<f64> = row
+            .compute(Size::new(100.0, 50.0), &[fixed, fixed, fixed])
+            .iter()
+            .map(|frame| frame.origin.x)
+            .collect();
+        assert_eq!(xs, vec![0.0, 45.0, 90.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn grid_aligns_labels_and_fields() -> PlatingResult<()> {
+        use crate::layout::*;
+        use crate::mock::{MockGrid, MockGridWidget, MockOnly, MockOnlyOutlet, MockOnlyWidget};
+        use crate::utils::settings::setting_list;
+        use crate::widgets::{GridOutlet, Widget};
+
+        let grid = <MockGridWidget<_> as MockGrid<_>>::new(
+            GridOutlet {
+                children: children_list!(
+                    MockOnlyWidget::new(MockOnlyOutlet {})?,
+                    MockButtonWidget::new(MockButtonOutlet {})?,
+                    MockOnlyWidget::new(MockOnlyOutlet {})?,
+                    MockButtonWidget::new(MockButtonOutlet {})?,
+                    MockButtonWidget::new(MockButtonOutlet {})?
+                ),
+            },
+            GridLayout::from_settings(&setting_list!(
+                Columns(vec![Track::Auto, Track::Fraction(1.0)]),
+                Rows(vec![Track::Auto, Track::Auto, Track::Fixed(40.0)]),
+                Gap(8.0),
+                Padding::all(10.0)
+            )),
+        )?;
+        let (name_label, (name_field, (mail_label, (mail_field, (submit, ()))))) =
+            &grid.outlet().children;
+        name_label.apply_item_settings(&setting_list!(PreferredSize(Size::new(60.0, 20.0))));
+        name_field.apply_item_settings(&setting_list!(PreferredSize(Size::new(100.0, 24.0))));
+        name_field.apply_grid_settings(&setting_list!(GridColumn(1)));
+        mail_label.apply_item_settings(&setting_list!(PreferredSize(Size::new(90.0, 20.0))));
+        mail_label.apply_grid_settings(&setting_list!(GridRow(1)));
+        mail_field.apply_item_settings(&setting_list!(PreferredSize(Size::new(50.0, 30.0))));
+        mail_field.apply_grid_settings(&setting_list!(GridRow(1), GridColumn(1)));
+        submit.apply_item_settings(&setting_list!(PreferredSize(Size::new(500.0, 20.0))));
+        submit.apply_grid_settings(&setting_list!(GridRow(2), ColumnSpan(2)));
+
+        grid.resize(Size::new(400.0, 300.0));
+        assert_eq!(
+            grid.cell_frames(),
+            vec![
+                Rect::new(10.0, 10.0, 90.0, 24.0),
+                Rect::new(108.0, 10.0, 282.0, 24.0),
+                Rect::new(10.0, 42.0, 90.0, 30.0),
+                Rect::new(108.0, 42.0, 282.0, 30.0),
+                Rect::new(10.0, 80.0, 380.0, 40.0),
+            ]
+        );
+        assert_eq!(mail_field.frame(), Rect::new(108.0, 42.0, 282.0, 30.0));
 
         Ok(())
     }
@@ -3,9 +3,13 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
-use super::MockWindowWidget;
+use std::cell::Cell;
+
+use super::traits::MockContainer;
+use crate::data::Rect;
+use crate::layout::{FlexItem, GridItem, LayoutChild};
 use crate::utils::children::ChildOf;
-use crate::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+use crate::utils::outlet::{OutletHolder, OutletType};
 use crate::widgets::{Button, Widget};
 use crate::PlatingResult;
 
@@ -17,8 +21,11 @@ pub struct MockButtonWidget<OUTLET>
 where
     OUTLET: OutletHolder,
 {
-    outlet:   OUTLET,
-    _private: crate::Private, // Creation is limited to our constructors
+    outlet:    OUTLET,
+    flex_item: Cell<FlexItem>,
+    grid_item: Cell<GridItem>,
+    frame:     Cell<Rect>,
+    _private:  crate::Private, // Creation is limited to our constructors
 }
 
 pub trait MockButton<OUTLET>
@@ -36,6 +43,9 @@ where
     fn new(outlet: OUTLET) -> PlatingResult<Self> {
         let result = Self {
             outlet,
+            flex_item: Cell::new(FlexItem::default()),
+            grid_item: Cell::new(GridItem::default()),
+            frame: Cell::new(Rect::default()),
             _private: crate::Private {},
         };
         Ok(result)
@@ -60,17 +70,46 @@ where
     }
 }
 
-impl<OUTLET1, OUTLET2> ChildOf<MockWindowWidget<OUTLET1>, ChildrenOutlet>
-    for MockButtonWidget<OUTLET2>
+impl<OUTLET> LayoutChild for MockButtonWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn flex_item(&self) -> FlexItem {
+        self.flex_item.get()
+    }
+
+    fn set_flex_item(&self, item: FlexItem) {
+        self.flex_item.set(item);
+    }
+
+    fn grid_item(&self) -> GridItem {
+        self.grid_item.get()
+    }
+
+    fn set_grid_item(&self, item: GridItem) {
+        self.grid_item.set(item);
+    }
+
+    fn frame(&self) -> Rect {
+        self.frame.get()
+    }
+
+    fn set_frame(&self, frame: Rect) {
+        self.frame.set(frame);
+    }
+}
+
+impl<PARENT, OL, OUTLET> ChildOf<PARENT, OL> for MockButtonWidget<OUTLET>
 where
-    OUTLET1: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
-    OUTLET2: OutletHolder,
+    PARENT: MockContainer<OL>,
+    OL: OutletType,
+    OUTLET: OutletHolder,
 {
-    fn setup(&mut self, _parent: &MockWindowWidget<OUTLET1>) {
+    fn setup(&mut self, _parent: &PARENT) {
         //todo!()
     }
 
-    fn connect(&self, _parent: &MockWindowWidget<OUTLET1>) {
+    fn connect(&self, _parent: &PARENT) {
         //todo!()
     }
 
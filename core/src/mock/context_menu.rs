@@ -0,0 +1,86 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use super::{MockMenu, MockMenuWidget};
+use crate::data::Point;
+use crate::utils::outlet::{ChildrenOutlet, ContextMenuOutlet, Outlet, OutletHolder};
+use crate::widgets::{ContextMenuTrigger, Widget};
+use crate::{PlatingError, PlatingResult};
+
+/// The content of a mock [`ContextMenuOutlet`].
+pub trait MockContextMenuList {
+    /// Opens the first menu in the list. Returns `false` if there is none.
+    fn open_first(&self, position: Point, trigger: ContextMenuTrigger) -> bool;
+}
+
+impl MockContextMenuList for () {
+    fn open_first(&self, _position: Point, _trigger: ContextMenuTrigger) -> bool {
+        false
+    }
+}
+
+impl<HEAD, TAIL> MockContextMenuList for (HEAD, TAIL)
+where
+    HEAD: MockContextMenuList,
+    TAIL: MockContextMenuList,
+{
+    fn open_first(&self, position: Point, trigger: ContextMenuTrigger) -> bool {
+        self.0.open_first(position, trigger) || self.1.open_first(position, trigger)
+    }
+}
+
+impl<OUTLET> MockContextMenuList for MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn open_first(&self, position: Point, trigger: ContextMenuTrigger) -> bool {
+        self.open(position, trigger);
+        true
+    }
+}
+
+/// Simulates context menu requests on any mock widget whose outlet has a
+/// [`ContextMenuOutlet`].
+pub trait MockContextMenuHost<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ContextMenuOutlet>,
+{
+    /// Simulates a secondary click at `position`.
+    fn secondary_click(&self, position: Point) -> PlatingResult<()>;
+
+    /// Simulates the context menu key, opening the menu at the widgets origin.
+    fn press_context_menu_key(&self) -> PlatingResult<()>;
+}
+
+impl<WIDGET, OUTLET> MockContextMenuHost<OUTLET> for WIDGET
+where
+    WIDGET: Widget<OUTLET>,
+    OUTLET: OutletHolder + Outlet<ContextMenuOutlet>,
+    <OUTLET as Outlet<ContextMenuOutlet>>::Children: MockContextMenuList,
+{
+    fn secondary_click(&self, position: Point) -> PlatingResult<()> {
+        open(self.outlet(), position, ContextMenuTrigger::SecondaryClick)
+    }
+
+    fn press_context_menu_key(&self) -> PlatingResult<()> {
+        open(
+            self.outlet(),
+            Point::default(),
+            ContextMenuTrigger::Keyboard,
+        )
+    }
+}
+
+fn open<OUTLET>(outlet: &OUTLET, position: Point, trigger: ContextMenuTrigger) -> PlatingResult<()>
+where
+    OUTLET: OutletHolder + Outlet<ContextMenuOutlet>,
+    <OUTLET as Outlet<ContextMenuOutlet>>::Children: MockContextMenuList,
+{
+    if <OUTLET as Outlet<ContextMenuOutlet>>::get(outlet).open_first(position, trigger) {
+        Ok(())
+    } else {
+        Err(PlatingError::Unsupported("context menu"))
+    }
+}
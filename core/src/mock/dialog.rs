@@ -0,0 +1,204 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use super::traits::{MockChildOf, MockContainer, MockMenuHost};
+use crate::utils::children::ChildOf;
+use crate::utils::events::{Emits, EventHandlers};
+use crate::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+use crate::widgets::{
+    Dialog,
+    DialogClosed,
+    DialogHandle,
+    DialogParent,
+    DialogResolver,
+    DialogResponse,
+    ModalBlock,
+    ModalGuard,
+    Modality,
+    Widget,
+};
+use crate::{PlatingError, PlatingResult};
+
+pub struct MockDialogWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+{
+    outlet: OUTLET,
+    title: String,
+    modality: Option<Modality>,
+    /// Blocks the parent, while shown modally.
+    parent_lock: Option<ModalGuard>,
+    resolver: Option<DialogResolver<DialogResponse>>,
+    modal_block: ModalBlock,
+    closed: EventHandlers<DialogClosed>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+impl<OUTLET> MockDialogWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+{
+    fn open(
+        &mut self,
+        modality: Modality,
+        parent_lock: Option<ModalGuard>,
+    ) -> PlatingResult<DialogHandle<DialogResponse>> {
+        if self.modality.is_some() {
+            return Err(PlatingError::state(format!(
+                "dialog '{}' is already shown",
+                self.title
+            )));
+        }
+
+        let (handle, resolver) = DialogHandle::pending();
+        self.modality = Some(modality);
+        self.parent_lock = parent_lock;
+        self.resolver = Some(resolver);
+        Ok(handle)
+    }
+
+    fn hide(&mut self, response: DialogResponse) -> PlatingResult<()> {
+        if self.modality.take().is_none() {
+            return Err(PlatingError::state(format!(
+                "dialog '{}' is not shown",
+                self.title
+            )));
+        }
+
+        self.parent_lock = None;
+        if let Some(resolver) = self.resolver.take() {
+            resolver.resolve(response);
+        }
+        Ok(())
+    }
+}
+
+pub trait MockDialog<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, title: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<MenuOutlet>>::Children: MockChildOf<Self, MenuOutlet>,
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+
+    /// Simulates the user closing the dialog, e.g. by pressing one of its
+    /// buttons.
+    fn close_by_user(&mut self, response: DialogResponse) -> PlatingResult<()>;
+}
+
+impl<OUTLET> MockDialog<OUTLET> for MockDialogWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, title: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<MenuOutlet>>::Children: MockChildOf<Self, MenuOutlet>,
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+    {
+        let result = Self {
+            outlet,
+            title: String::from(title),
+            modality: None,
+            parent_lock: None,
+            resolver: None,
+            modal_block: ModalBlock::default(),
+            closed: EventHandlers::new(),
+            _private: crate::Private {},
+        };
+        <OUTLET as Outlet<MenuOutlet>>::get(&result.outlet).connect(&result);
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+
+        Ok(result)
+    }
+
+    fn close_by_user(&mut self, response: DialogResponse) -> PlatingResult<()> {
+        self.hide(response)?;
+        self.closed.emit(&DialogClosed { response });
+        Ok(())
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockDialogWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MockMenuHost for MockDialogWidget<OUTLET> where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>
+{
+}
+
+impl<OUTLET> MockContainer for MockDialogWidget<OUTLET> where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>
+{
+}
+
+impl<OUTLET> DialogParent for MockDialogWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+{
+    fn modal_block(&self) -> &ModalBlock {
+        &self.modal_block
+    }
+}
+
+impl<OUTLET> Emits<DialogClosed> for MockDialogWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&DialogClosed) + 'static,
+    {
+        self.closed.add(handler);
+    }
+}
+
+impl<OUTLET> Dialog<OUTLET> for MockDialogWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, title: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<MenuOutlet>>::Children: ChildOf<Self, MenuOutlet>,
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+    {
+        <Self as MockDialog<OUTLET>>::new(outlet, title)
+    }
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn set_title(&mut self, title: &str) -> PlatingResult<()> {
+        self.title = String::from(title);
+        Ok(())
+    }
+
+    fn show_modal<PARENT>(&mut self, parent: &PARENT) -> PlatingResult<DialogHandle<DialogResponse>>
+    where
+        PARENT: DialogParent,
+    {
+        self.open(Modality::Modal, Some(parent.modal_block().block()))
+    }
+
+    fn show(&mut self) -> PlatingResult<DialogHandle<DialogResponse>> {
+        self.open(Modality::Modeless, None)
+    }
+
+    fn close(&mut self, response: DialogResponse) -> PlatingResult<()> {
+        self.hide(response)
+    }
+
+    fn modality(&self) -> Option<Modality> {
+        self.modality
+    }
+}
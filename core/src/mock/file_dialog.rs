@@ -0,0 +1,134 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+use crate::widgets::{DialogHandle, FileDialog, FileDialogOptions};
+use crate::{PlatingError, PlatingResult};
+
+/// The kind of dialog shown via [`MockFileDialog`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MockFileDialogKind {
+    OpenFile,
+    SaveFile,
+    ChooseFolder,
+}
+
+/// A scripted answer to the next dialog shown by [`MockFileDialog`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum MockFileDialogAnswer {
+    /// The user picked these paths. Save dialogs need exactly one.
+    Pick(Vec<PathBuf>),
+    Cancel,
+}
+
+impl MockFileDialogAnswer {
+    pub fn pick<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self::Pick(vec![path.into()])
+    }
+}
+
+/// A [`FileDialog`] answering with scripted answers.
+///
+/// Answers are checked against the options of the dialog: picking several
+/// paths without multi select or files rejected by all filters fails.
+#[derive(Debug, Default)]
+pub struct MockFileDialog {
+    answers: RefCell<VecDeque<MockFileDialogAnswer>>,
+    shown:   RefCell<Vec<(MockFileDialogKind, FileDialogOptions)>>,
+}
+
+impl MockFileDialog {
+    /// Queues the answer to the next dialog.
+    pub fn queue_answer(&self, answer: MockFileDialogAnswer) {
+        self.answers.borrow_mut().push_back(answer);
+    }
+
+    /// All dialogs shown so far, oldest first.
+    pub fn shown(&self) -> Vec<(MockFileDialogKind, FileDialogOptions)> {
+        self.shown.borrow().clone()
+    }
+
+    /// Number of queued answers not used up yet.
+    pub fn pending_answers(&self) -> usize {
+        self.answers.borrow().len()
+    }
+
+    fn answer(
+        &self,
+        kind: MockFileDialogKind,
+        options: &FileDialogOptions,
+    ) -> PlatingResult<Vec<PathBuf>> {
+        self.shown.borrow_mut().push((kind, options.clone()));
+        let paths = match self.answers.borrow_mut().pop_front() {
+            Some(MockFileDialogAnswer::Pick(paths)) => paths,
+            Some(MockFileDialogAnswer::Cancel) => return Ok(Vec::new()),
+            None => {
+                return Err(PlatingError::state(format!(
+                    "no answer queued for the {:?} dialog '{}'",
+                    kind, options.title
+                )))
+            },
+        };
+
+        let allows_several = options.multi_select && kind != MockFileDialogKind::SaveFile;
+        if paths.is_empty() || (paths.len() > 1 && !allows_several) {
+            return Err(PlatingError::invalid(
+                "answer",
+                format!(
+                    "the {:?} dialog '{}' can not answer with {} paths",
+                    kind,
+                    options.title,
+                    paths.len()
+                ),
+            ));
+        }
+        if kind != MockFileDialogKind::ChooseFolder {
+            if let Some(path) = paths.iter().find(|path| !options.allows(path)) {
+                return Err(PlatingError::invalid(
+                    "answer",
+                    format!(
+                        "{} is rejected by the filters of '{}'",
+                        path.display(),
+                        options.title
+                    ),
+                ));
+            }
+        }
+        Ok(paths)
+    }
+}
+
+impl FileDialog for MockFileDialog {
+    fn new() -> PlatingResult<Self> {
+        Ok(Self::default())
+    }
+
+    fn open_file(&self, options: &FileDialogOptions) -> PlatingResult<DialogHandle<Vec<PathBuf>>> {
+        let paths = self.answer(MockFileDialogKind::OpenFile, options)?;
+        Ok(DialogHandle::resolved(paths))
+    }
+
+    fn save_file(
+        &self,
+        options: &FileDialogOptions,
+    ) -> PlatingResult<DialogHandle<Option<PathBuf>>> {
+        let paths = self.answer(MockFileDialogKind::SaveFile, options)?;
+        Ok(DialogHandle::resolved(paths.into_iter().next()))
+    }
+
+    fn choose_folder(
+        &self,
+        options: &FileDialogOptions,
+    ) -> PlatingResult<DialogHandle<Vec<PathBuf>>> {
+        let paths = self.answer(MockFileDialogKind::ChooseFolder, options)?;
+        Ok(DialogHandle::resolved(paths))
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::RefCell;
+
+use super::traits::{MockChildOf, MockContainer};
+use crate::data::{Rect, Size};
+use crate::layout::{GridLayout, LayoutChildren};
+use crate::utils::children::ChildOf;
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::{Grid, Widget};
+use crate::PlatingResult;
+
+pub struct MockGridWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    outlet:      OUTLET,
+    layout:      GridLayout,
+    cell_frames: RefCell<Vec<Rect>>,
+    _private:    crate::Private, // Creation is limited to our constructors
+}
+
+impl<OUTLET> MockGridWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    /// The cell frames computed by the last call to
+    /// [`resize`](Self::resize), in the order of the children.
+    pub fn cell_frames(&self) -> Vec<Rect> {
+        self.cell_frames.borrow().clone()
+    }
+}
+
+impl<OUTLET> MockGridWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    <OUTLET as Outlet<ChildrenOutlet>>::Children: LayoutChildren,
+{
+    /// Lays out the children as if the grid had been resized to `size` and
+    /// returns the computed cell frames.
+    pub fn resize(&self, size: Size) -> Vec<Rect> {
+        let frames = self
+            .layout
+            .arrange(size, <OUTLET as Outlet<ChildrenOutlet>>::get(&self.outlet));
+        *self.cell_frames.borrow_mut() = frames.clone();
+        frames
+    }
+}
+
+pub trait MockGrid<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, layout: GridLayout) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+}
+
+impl<OUTLET> MockGrid<OUTLET> for MockGridWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, layout: GridLayout) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+    {
+        let result = Self {
+            outlet,
+            layout,
+            cell_frames: RefCell::new(Vec::new()),
+            _private: crate::Private {},
+        };
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+
+        Ok(result)
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockGridWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MockContainer for MockGridWidget<OUTLET> where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>
+{
+}
+
+impl<OUTLET> Grid<OUTLET> for MockGridWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, layout: GridLayout) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+    {
+        <Self as MockGrid<OUTLET>>::new(outlet, layout)
+    }
+
+    fn grid_layout(&self) -> &GridLayout {
+        &self.layout
+    }
+
+    fn set_grid_layout(&mut self, layout: GridLayout) -> PlatingResult<()> {
+        self.layout = layout;
+        Ok(())
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockGridWidget<OUTLET>
+where
+    PARENT: MockContainer,
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        <<OUTLET as Outlet<ChildrenOutlet>>::Children as ChildOf<Self, ChildrenOutlet>>::disconnect(
+            <OUTLET as Outlet<ChildrenOutlet>>::get(&self.outlet),
+        );
+    }
+}
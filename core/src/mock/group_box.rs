@@ -0,0 +1,104 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use super::traits::{MockChildOf, MockContainer};
+use crate::utils::children::ChildOf;
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::{GroupBox, Widget};
+use crate::PlatingResult;
+
+pub struct MockGroupBoxWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    outlet:   OUTLET,
+    title:    String,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+pub trait MockGroupBox<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, title: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+}
+
+impl<OUTLET> MockGroupBox<OUTLET> for MockGroupBoxWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, title: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+    {
+        let result = Self {
+            outlet,
+            title: String::from(title),
+            _private: crate::Private {},
+        };
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+
+        Ok(result)
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockGroupBoxWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MockContainer for MockGroupBoxWidget<OUTLET> where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>
+{
+}
+
+impl<OUTLET> GroupBox<OUTLET> for MockGroupBoxWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, title: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+    {
+        <Self as MockGroupBox<OUTLET>>::new(outlet, title)
+    }
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn set_title(&mut self, title: &str) -> PlatingResult<()> {
+        self.title = String::from(title);
+        Ok(())
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockGroupBoxWidget<OUTLET>
+where
+    PARENT: MockContainer,
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        <<OUTLET as Outlet<ChildrenOutlet>>::Children as ChildOf<Self, ChildrenOutlet>>::disconnect(
+            <OUTLET as Outlet<ChildrenOutlet>>::get(&self.outlet),
+        );
+    }
+}
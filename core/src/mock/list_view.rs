@@ -0,0 +1,640 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::ops::Range;
+
+use super::traits::MockContainer;
+use crate::data::{CellValue, ColumnDefinition, Selection, SelectionMode, SortOrder};
+use crate::utils::children::ChildOf;
+use crate::utils::events::{Emits, EventHandlers};
+use crate::utils::outlet::{ChildrenOutlet, OutletHolder};
+use crate::widgets::{
+    DataSource,
+    ListView,
+    RowActivated,
+    SelectionChanged,
+    SortChanged,
+    TableView,
+    Widget,
+};
+use crate::{PlatingError, PlatingResult};
+
+/// Number of rows the mock list and table views have room for, until changed
+/// via `set_visible_row_count`.
+pub const MOCK_VISIBLE_ROWS: usize = 20;
+
+/// A row the mock list or table view asked its [`DataSource`] about.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRow {
+    pub index: usize,
+    pub cells: Vec<CellValue>,
+}
+
+/// Virtualization and selection bookkeeping shared by the mock list and table
+/// views.
+#[derive(Debug)]
+struct MockRows {
+    row_count:      usize,
+    first_visible:  usize,
+    visible_count:  usize,
+    materialized:   Vec<MockRow>,
+    selection_mode: SelectionMode,
+    selection:      Selection,
+}
+
+impl MockRows {
+    fn new() -> Self {
+        Self {
+            row_count:      0,
+            first_visible:  0,
+            visible_count:  MOCK_VISIBLE_ROWS,
+            materialized:   Vec::new(),
+            selection_mode: SelectionMode::default(),
+            selection:      Selection::none(),
+        }
+    }
+
+    fn visible_rows(&self) -> Range<usize> {
+        let end = self.row_count.min(self.first_visible + self.visible_count);
+        self.first_visible.min(end)..end
+    }
+
+    /// Re-reads the row count and materializes the visible rows only.
+    fn reload<SOURCE: DataSource>(&mut self, source: &SOURCE, column_count: usize) {
+        self.row_count = source.row_count();
+        self.first_visible = self
+            .first_visible
+            .min(self.row_count.saturating_sub(self.visible_count));
+
+        let row_count = self.row_count;
+        self.selection = self
+            .selection
+            .rows()
+            .filter(|row| *row < row_count)
+            .collect();
+
+        self.materialized = self
+            .visible_rows()
+            .map(|index| MockRow {
+                index,
+                cells: (0..column_count)
+                    .map(|column| source.cell_value(index, column))
+                    .collect(),
+            })
+            .collect();
+    }
+
+    /// Moves the selection along with the rows after the source got sorted.
+    ///
+    /// `previous` holds the index every row had before sorting.
+    fn reorder(&mut self, previous: &[usize]) {
+        let mut moved_to = vec![None; previous.len()];
+        for (row, previous_row) in previous.iter().enumerate() {
+            if let Some(entry) = moved_to.get_mut(*previous_row) {
+                *entry = Some(row);
+            }
+        }
+
+        self.selection = self
+            .selection
+            .rows()
+            .filter_map(|row| moved_to.get(row).copied().flatten())
+            .collect();
+    }
+
+    fn check_row(&self, row: usize) -> PlatingResult<()> {
+        if row < self.row_count {
+            Ok(())
+        } else {
+            Err(PlatingError::OutOfRange {
+                item:  "row",
+                index: row,
+                len:   self.row_count,
+            })
+        }
+    }
+
+    fn scroll_to_row(&mut self, row: usize) -> PlatingResult<()> {
+        self.check_row(row)?;
+
+        if row < self.first_visible {
+            self.first_visible = row;
+        } else if row >= self.first_visible + self.visible_count {
+            self.first_visible = row + 1 - self.visible_count;
+        }
+        Ok(())
+    }
+
+    fn set_selection_mode(&mut self, mode: SelectionMode) {
+        self.selection = match mode {
+            SelectionMode::None => Selection::none(),
+            SelectionMode::Single => self.selection.first().into_iter().collect(),
+            SelectionMode::Multiple => self.selection.clone(),
+        };
+        self.selection_mode = mode;
+    }
+
+    fn set_selection(&mut self, selection: Selection) -> PlatingResult<()> {
+        for row in selection.rows() {
+            self.check_row(row)?;
+        }
+        let allowed = match self.selection_mode {
+            SelectionMode::None => selection.is_empty(),
+            SelectionMode::Single => selection.len() <= 1,
+            SelectionMode::Multiple => true,
+        };
+        if !allowed {
+            return Err(PlatingError::invalid(
+                "selection",
+                format!(
+                    "{} rows are not allowed in {:?} selection mode",
+                    selection.len(),
+                    self.selection_mode
+                ),
+            ));
+        }
+
+        self.selection = selection;
+        Ok(())
+    }
+
+    /// Applies a click on `row` to the selection.
+    ///
+    /// Returns the new selection if it changed.
+    fn click(&mut self, row: usize) -> PlatingResult<Option<Selection>> {
+        self.check_row(row)?;
+
+        let selection = match self.selection_mode {
+            SelectionMode::None => return Ok(None),
+            SelectionMode::Single => Selection::single(row),
+            SelectionMode::Multiple if self.selection.contains(row) => {
+                self.selection.rows().filter(|r| *r != row).collect()
+            },
+            SelectionMode::Multiple => self.selection.rows().chain(Some(row)).collect(),
+        };
+
+        if selection == self.selection {
+            Ok(None)
+        } else {
+            self.selection = selection.clone();
+            Ok(Some(selection))
+        }
+    }
+}
+
+pub struct MockListViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    outlet: OUTLET,
+    source: SOURCE,
+    rows: MockRows,
+    selection_changed: EventHandlers<SelectionChanged>,
+    row_activated: EventHandlers<RowActivated>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+pub trait MockListView<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self>;
+
+    /// Pretends the list got resized so that `count` rows fit into it.
+    fn set_visible_row_count(&mut self, count: usize) -> PlatingResult<()>;
+
+    /// The rows the source was asked about during the last reload or scroll.
+    fn materialized_rows(&self) -> &[MockRow];
+
+    /// Simulates the user clicking `row`.
+    fn click_row(&mut self, row: usize) -> PlatingResult<()>;
+
+    /// Simulates the user activating `row`, e.g. by double clicking it.
+    fn activate_row(&mut self, row: usize) -> PlatingResult<()>;
+}
+
+impl<OUTLET, SOURCE> MockListView<OUTLET, SOURCE> for MockListViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self> {
+        let mut result = Self {
+            outlet,
+            source,
+            rows: MockRows::new(),
+            selection_changed: EventHandlers::new(),
+            row_activated: EventHandlers::new(),
+            _private: crate::Private {},
+        };
+        result.rows.reload(&result.source, 1);
+        Ok(result)
+    }
+
+    fn set_visible_row_count(&mut self, count: usize) -> PlatingResult<()> {
+        self.rows.visible_count = count;
+        self.rows.reload(&self.source, 1);
+        Ok(())
+    }
+
+    fn materialized_rows(&self) -> &[MockRow] {
+        &self.rows.materialized
+    }
+
+    fn click_row(&mut self, row: usize) -> PlatingResult<()> {
+        if let Some(selection) = self.rows.click(row)? {
+            self.selection_changed.emit(&SelectionChanged { selection });
+        }
+        Ok(())
+    }
+
+    fn activate_row(&mut self, row: usize) -> PlatingResult<()> {
+        self.rows.check_row(row)?;
+        self.row_activated.emit(&RowActivated { row });
+        Ok(())
+    }
+}
+
+impl<OUTLET, SOURCE> Widget<OUTLET> for MockListViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<SelectionChanged> for MockListViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&SelectionChanged) + 'static,
+    {
+        self.selection_changed.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<RowActivated> for MockListViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&RowActivated) + 'static,
+    {
+        self.row_activated.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> ListView<OUTLET, SOURCE> for MockListViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self> {
+        <Self as MockListView<OUTLET, SOURCE>>::new(outlet, source)
+    }
+
+    fn source(&self) -> &SOURCE {
+        &self.source
+    }
+
+    fn source_mut(&mut self) -> &mut SOURCE {
+        &mut self.source
+    }
+
+    fn reload(&mut self) -> PlatingResult<()> {
+        self.rows.reload(&self.source, 1);
+        Ok(())
+    }
+
+    fn visible_rows(&self) -> Range<usize> {
+        self.rows.visible_rows()
+    }
+
+    fn scroll_to_row(&mut self, row: usize) -> PlatingResult<()> {
+        self.rows.scroll_to_row(row)?;
+        self.rows.reload(&self.source, 1);
+        Ok(())
+    }
+
+    fn selection_mode(&self) -> SelectionMode {
+        self.rows.selection_mode
+    }
+
+    fn set_selection_mode(&mut self, mode: SelectionMode) -> PlatingResult<()> {
+        self.rows.set_selection_mode(mode);
+        Ok(())
+    }
+
+    fn selection(&self) -> &Selection {
+        &self.rows.selection
+    }
+
+    fn set_selection(&mut self, selection: Selection) -> PlatingResult<()> {
+        self.rows.set_selection(selection)
+    }
+}
+
+impl<PARENT, OUTLET, SOURCE> ChildOf<PARENT, ChildrenOutlet> for MockListViewWidget<OUTLET, SOURCE>
+where
+    PARENT: MockContainer,
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
+
+
+pub struct MockTableViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    outlet: OUTLET,
+    source: SOURCE,
+    columns: Vec<ColumnDefinition>,
+    sorting: Option<(usize, SortOrder)>,
+    rows: MockRows,
+    selection_changed: EventHandlers<SelectionChanged>,
+    row_activated: EventHandlers<RowActivated>,
+    sort_changed: EventHandlers<SortChanged>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+impl<OUTLET, SOURCE> MockTableViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn check_column(&self, column: usize) -> PlatingResult<()> {
+        if column < self.columns.len() {
+            Ok(())
+        } else {
+            Err(PlatingError::OutOfRange {
+                item:  "column",
+                index: column,
+                len:   self.columns.len(),
+            })
+        }
+    }
+
+    /// Asks the source to sort and returns whether it did.
+    fn sort(&mut self, column: usize, order: SortOrder) -> PlatingResult<bool> {
+        self.check_column(column)?;
+
+        match self.source.sort(column, order)? {
+            Some(previous) => {
+                self.sorting = Some((column, order));
+                self.rows.reorder(&previous);
+                self.rows.reload(&self.source, self.columns.len());
+                Ok(true)
+            },
+            None => Ok(false),
+        }
+    }
+}
+
+pub trait MockTableView<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self>;
+
+    /// Pretends the table got resized so that `count` rows fit into it.
+    fn set_visible_row_count(&mut self, count: usize) -> PlatingResult<()>;
+
+    /// The rows the source was asked about during the last reload or scroll.
+    fn materialized_rows(&self) -> &[MockRow];
+
+    /// Simulates the user clicking `row`.
+    fn click_row(&mut self, row: usize) -> PlatingResult<()>;
+
+    /// Simulates the user activating `row`, e.g. by double clicking it.
+    fn activate_row(&mut self, row: usize) -> PlatingResult<()>;
+
+    /// Simulates the user clicking the header of `column`.
+    ///
+    /// Sorts ascending, or reverses the order if the table is already sorted
+    /// by that column. Does nothing for columns that are not sortable.
+    fn click_column_header(&mut self, column: usize) -> PlatingResult<()>;
+}
+
+impl<OUTLET, SOURCE> MockTableView<OUTLET, SOURCE> for MockTableViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self> {
+        let mut result = Self {
+            outlet,
+            columns: source.columns(),
+            source,
+            sorting: None,
+            rows: MockRows::new(),
+            selection_changed: EventHandlers::new(),
+            row_activated: EventHandlers::new(),
+            sort_changed: EventHandlers::new(),
+            _private: crate::Private {},
+        };
+        result.rows.reload(&result.source, result.columns.len());
+        Ok(result)
+    }
+
+    fn set_visible_row_count(&mut self, count: usize) -> PlatingResult<()> {
+        self.rows.visible_count = count;
+        self.rows.reload(&self.source, self.columns.len());
+        Ok(())
+    }
+
+    fn materialized_rows(&self) -> &[MockRow] {
+        &self.rows.materialized
+    }
+
+    fn click_row(&mut self, row: usize) -> PlatingResult<()> {
+        if let Some(selection) = self.rows.click(row)? {
+            self.selection_changed.emit(&SelectionChanged { selection });
+        }
+        Ok(())
+    }
+
+    fn activate_row(&mut self, row: usize) -> PlatingResult<()> {
+        self.rows.check_row(row)?;
+        self.row_activated.emit(&RowActivated { row });
+        Ok(())
+    }
+
+    fn click_column_header(&mut self, column: usize) -> PlatingResult<()> {
+        self.check_column(column)?;
+        if !self.columns[column].sortable {
+            return Ok(());
+        }
+
+        let order = match self.sorting {
+            Some((sorted_column, order)) if sorted_column == column => order.reversed(),
+            _ => SortOrder::Ascending,
+        };
+        if self.sort(column, order)? {
+            self.sort_changed.emit(&SortChanged { column, order });
+        }
+        Ok(())
+    }
+}
+
+impl<OUTLET, SOURCE> Widget<OUTLET> for MockTableViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<SelectionChanged> for MockTableViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&SelectionChanged) + 'static,
+    {
+        self.selection_changed.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<RowActivated> for MockTableViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&RowActivated) + 'static,
+    {
+        self.row_activated.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<SortChanged> for MockTableViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&SortChanged) + 'static,
+    {
+        self.sort_changed.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> TableView<OUTLET, SOURCE> for MockTableViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self> {
+        <Self as MockTableView<OUTLET, SOURCE>>::new(outlet, source)
+    }
+
+    fn source(&self) -> &SOURCE {
+        &self.source
+    }
+
+    fn source_mut(&mut self) -> &mut SOURCE {
+        &mut self.source
+    }
+
+    fn reload(&mut self) -> PlatingResult<()> {
+        self.columns = self.source.columns();
+        if let Some((column, _)) = self.sorting {
+            if column >= self.columns.len() {
+                self.sorting = None;
+            }
+        }
+        self.rows.reload(&self.source, self.columns.len());
+        Ok(())
+    }
+
+    fn columns(&self) -> &[ColumnDefinition] {
+        &self.columns
+    }
+
+    fn visible_rows(&self) -> Range<usize> {
+        self.rows.visible_rows()
+    }
+
+    fn scroll_to_row(&mut self, row: usize) -> PlatingResult<()> {
+        self.rows.scroll_to_row(row)?;
+        self.rows.reload(&self.source, self.columns.len());
+        Ok(())
+    }
+
+    fn selection_mode(&self) -> SelectionMode {
+        self.rows.selection_mode
+    }
+
+    fn set_selection_mode(&mut self, mode: SelectionMode) -> PlatingResult<()> {
+        self.rows.set_selection_mode(mode);
+        Ok(())
+    }
+
+    fn selection(&self) -> &Selection {
+        &self.rows.selection
+    }
+
+    fn set_selection(&mut self, selection: Selection) -> PlatingResult<()> {
+        self.rows.set_selection(selection)
+    }
+
+    fn sorting(&self) -> Option<(usize, SortOrder)> {
+        self.sorting
+    }
+
+    fn sort_by(&mut self, column: usize, order: SortOrder) -> PlatingResult<()> {
+        self.sort(column, order).map(|_| ())
+    }
+}
+
+impl<PARENT, OUTLET, SOURCE> ChildOf<PARENT, ChildrenOutlet> for MockTableViewWidget<OUTLET, SOURCE>
+where
+    PARENT: MockContainer,
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
@@ -0,0 +1,682 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use super::traits::{MockChildOf, MockMenuContainer, MockMenuHost};
+use crate::data::Point;
+use crate::utils::children::ChildOf;
+use crate::utils::events::{Emits, EventHandlers};
+use crate::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+use crate::widgets::{
+    CheckMenuItem,
+    CheckedChanged,
+    ContextMenu,
+    ContextMenuItemChosen,
+    ContextMenuOpened,
+    ContextMenuTrigger,
+    Menu,
+    MenuItem,
+    MenuItemActivated,
+    MenuSeparator,
+    Widget,
+};
+use crate::{PlatingError, PlatingResult};
+
+/// Snapshot of an entry of a mock menu, as returned by
+/// [`MockMenu::entries`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum MockMenuEntry {
+    Item {
+        label: String,
+    },
+    CheckItem {
+        label:   String,
+        checked: bool,
+    },
+    Separator,
+    Submenu {
+        label:   String,
+        entries: Vec<MockMenuEntry>,
+    },
+}
+
+#[derive(Debug)]
+enum MockMenuKind {
+    Menu { label: String },
+    Item { label: String },
+    CheckItem { label: String, checked: bool },
+    Separator,
+}
+
+/// State of a mock menu, menu item or separator.
+///
+/// Shared between the widget and the menu it is connected to, so that the menu
+/// can simulate the user choosing one of its entries.
+#[derive(Debug)]
+pub struct MockMenuNode {
+    kind: RefCell<MockMenuKind>,
+    children: RefCell<Vec<Rc<MockMenuNode>>>,
+    activated: RefCell<EventHandlers<MenuItemActivated>>,
+    checked_changed: RefCell<EventHandlers<CheckedChanged>>,
+}
+
+impl MockMenuNode {
+    fn new(kind: MockMenuKind) -> Rc<Self> {
+        Rc::new(Self {
+            kind: RefCell::new(kind),
+            children: RefCell::new(Vec::new()),
+            activated: RefCell::new(EventHandlers::new()),
+            checked_changed: RefCell::new(EventHandlers::new()),
+        })
+    }
+
+    fn label(&self) -> String {
+        match &*self.kind.borrow() {
+            MockMenuKind::Menu { label }
+            | MockMenuKind::Item { label }
+            | MockMenuKind::CheckItem { label, .. } => label.clone(),
+            MockMenuKind::Separator => String::new(),
+        }
+    }
+
+    fn set_label(&self, new_label: &str) {
+        match &mut *self.kind.borrow_mut() {
+            MockMenuKind::Menu { label }
+            | MockMenuKind::Item { label }
+            | MockMenuKind::CheckItem { label, .. } => *label = String::from(new_label),
+            MockMenuKind::Separator => {},
+        }
+    }
+
+    fn checked(&self) -> bool {
+        matches!(
+            *self.kind.borrow(),
+            MockMenuKind::CheckItem { checked: true, .. }
+        )
+    }
+
+    fn set_checked(&self, new_checked: bool) {
+        if let MockMenuKind::CheckItem { checked, .. } = &mut *self.kind.borrow_mut() {
+            *checked = new_checked;
+        }
+    }
+
+    pub(crate) fn add_child(&self, child: Rc<MockMenuNode>) {
+        self.children.borrow_mut().push(child);
+    }
+
+    pub(crate) fn entries(&self) -> Vec<MockMenuEntry> {
+        self.children
+            .borrow()
+            .iter()
+            .map(|child| child.entry())
+            .collect()
+    }
+
+    fn entry(&self) -> MockMenuEntry {
+        match &*self.kind.borrow() {
+            MockMenuKind::Menu { label } => MockMenuEntry::Submenu {
+                label:   label.clone(),
+                entries: self.entries(),
+            },
+            MockMenuKind::Item { label } => MockMenuEntry::Item {
+                label: label.clone(),
+            },
+            MockMenuKind::CheckItem { label, checked } => MockMenuEntry::CheckItem {
+                label:   label.clone(),
+                checked: *checked,
+            },
+            MockMenuKind::Separator => MockMenuEntry::Separator,
+        }
+    }
+
+    /// Simulates the user choosing this entry.
+    fn choose(&self) -> PlatingResult<()> {
+        let toggled = match &mut *self.kind.borrow_mut() {
+            MockMenuKind::Item { .. } => None,
+            MockMenuKind::CheckItem { checked, .. } => {
+                *checked = !*checked;
+                Some(*checked)
+            },
+            MockMenuKind::Menu { label } => {
+                return Err(PlatingError::invalid(
+                    "menu path",
+                    format!("'{}' is a submenu, not an item", label),
+                ))
+            },
+            MockMenuKind::Separator => {
+                return Err(PlatingError::invalid(
+                    "menu path",
+                    "separators can not be chosen",
+                ))
+            },
+        };
+
+        match toggled {
+            None => self.activated.borrow_mut().emit(&MenuItemActivated {
+                label: self.label(),
+            }),
+            Some(checked) => self
+                .checked_changed
+                .borrow_mut()
+                .emit(&CheckedChanged { checked }),
+        }
+        Ok(())
+    }
+
+    /// Simulates the user choosing the entry at `path`, descending into
+    /// submenus for every index but the last.
+    ///
+    /// Returns the label of the chosen entry.
+    pub(crate) fn pick(&self, path: &[usize]) -> PlatingResult<String> {
+        let (index, rest) = path
+            .split_first()
+            .ok_or_else(|| PlatingError::invalid("menu path", "can not pick an empty path"))?;
+
+        let child = self.children.borrow().get(*index).cloned().ok_or_else(|| {
+            PlatingError::OutOfRange {
+                item:  "entry",
+                index: *index,
+                len:   self.children.borrow().len(),
+            }
+        })?;
+
+        if rest.is_empty() {
+            child.choose()?;
+            Ok(child.label())
+        } else if matches!(*child.kind.borrow(), MockMenuKind::Menu { .. }) {
+            child.pick(rest)
+        } else {
+            Err(PlatingError::invalid(
+                "menu path",
+                format!("entry {} is not a submenu", index),
+            ))
+        }
+    }
+}
+
+
+pub struct MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    outlet:   OUTLET,
+    node:     Rc<MockMenuNode>,
+    /// Where the menu is shown, while it is open as a popup.
+    popup:    Cell<Option<Point>>,
+    opened:   RefCell<EventHandlers<ContextMenuOpened>>,
+    chosen:   RefCell<EventHandlers<ContextMenuItemChosen>>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+pub trait MockMenu<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+
+    /// The connected entries, including the content of submenus.
+    fn entries(&self) -> Vec<MockMenuEntry>;
+
+    /// Simulates the user choosing the entry at `path`.
+    ///
+    /// Every index but the last one opens a submenu. If the menu is open as a
+    /// popup, it emits [`ContextMenuItemChosen`] and closes.
+    fn pick(&self, path: &[usize]) -> PlatingResult<()>;
+
+    /// Simulates the menu getting shown as a popup at `position`.
+    fn open(&self, position: Point, trigger: ContextMenuTrigger);
+
+    /// Where the menu is shown, if it is open as a popup.
+    fn popup_position(&self) -> Option<Point>;
+}
+
+impl<OUTLET> MockMenu<OUTLET> for MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+    {
+        let result = Self {
+            outlet,
+            node: MockMenuNode::new(MockMenuKind::Menu {
+                label: String::from(label),
+            }),
+            popup: Cell::new(None),
+            opened: RefCell::new(EventHandlers::new()),
+            chosen: RefCell::new(EventHandlers::new()),
+            _private: crate::Private {},
+        };
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+
+        Ok(result)
+    }
+
+    fn entries(&self) -> Vec<MockMenuEntry> {
+        self.node.entries()
+    }
+
+    fn pick(&self, path: &[usize]) -> PlatingResult<()> {
+        let label = self.node.pick(path)?;
+        if let Some(position) = self.popup.take() {
+            self.chosen.borrow_mut().emit(&ContextMenuItemChosen {
+                path: path.to_vec(),
+                label,
+                position,
+            });
+        }
+        Ok(())
+    }
+
+    fn open(&self, position: Point, trigger: ContextMenuTrigger) {
+        self.popup.set(Some(position));
+        self.opened
+            .borrow_mut()
+            .emit(&ContextMenuOpened { position, trigger });
+    }
+
+    fn popup_position(&self) -> Option<Point> {
+        self.popup.get()
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MockMenuContainer for MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn add_menu_entry(&self, entry: Rc<MockMenuNode>) {
+        self.node.add_child(entry);
+    }
+}
+
+impl<OUTLET> Menu<OUTLET> for MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+    {
+        <Self as MockMenu<OUTLET>>::new(outlet, label)
+    }
+
+    fn label(&self) -> String {
+        self.node.label()
+    }
+
+    fn set_label(&mut self, label: &str) -> PlatingResult<()> {
+        self.node.set_label(label);
+        Ok(())
+    }
+}
+
+impl<OUTLET> Emits<ContextMenuOpened> for MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&ContextMenuOpened) + 'static,
+    {
+        self.opened.borrow_mut().add(handler);
+    }
+}
+
+impl<OUTLET> Emits<ContextMenuItemChosen> for MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&ContextMenuItemChosen) + 'static,
+    {
+        self.chosen.borrow_mut().add(handler);
+    }
+}
+
+impl<OUTLET> ContextMenu<OUTLET> for MockMenuWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn popup(&mut self, position: Point) -> PlatingResult<()> {
+        <Self as MockMenu<OUTLET>>::open(self, position, ContextMenuTrigger::Programmatic);
+        Ok(())
+    }
+
+    fn dismiss(&mut self) -> PlatingResult<()> {
+        self.popup.set(None);
+        Ok(())
+    }
+
+    fn is_open(&self) -> bool {
+        self.popup.get().is_some()
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, MenuOutlet> for MockMenuWidget<OUTLET>
+where
+    PARENT: MockMenuHost,
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockMenuWidget<OUTLET>
+where
+    PARENT: MockMenuContainer,
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, parent: &PARENT) {
+        parent.add_menu_entry(self.node.clone());
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
+
+
+pub struct MockMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    outlet:   OUTLET,
+    node:     Rc<MockMenuNode>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+pub trait MockMenuItem<OUTLET>
+where
+    OUTLET: OutletHolder,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>;
+
+    /// Simulates the user choosing this item.
+    fn click(&self) -> PlatingResult<()>;
+}
+
+impl<OUTLET> MockMenuItem<OUTLET> for MockMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self> {
+        let result = Self {
+            outlet,
+            node: MockMenuNode::new(MockMenuKind::Item {
+                label: String::from(label),
+            }),
+            _private: crate::Private {},
+        };
+        Ok(result)
+    }
+
+    fn click(&self) -> PlatingResult<()> {
+        self.node.choose()
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> Emits<MenuItemActivated> for MockMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&MenuItemActivated) + 'static,
+    {
+        self.node.activated.borrow_mut().add(handler);
+    }
+}
+
+impl<OUTLET> MenuItem<OUTLET> for MockMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self> {
+        <Self as MockMenuItem<OUTLET>>::new(outlet, label)
+    }
+
+    fn label(&self) -> String {
+        self.node.label()
+    }
+
+    fn set_label(&mut self, label: &str) -> PlatingResult<()> {
+        self.node.set_label(label);
+        Ok(())
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockMenuItemWidget<OUTLET>
+where
+    PARENT: MockMenuContainer,
+    OUTLET: OutletHolder,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, parent: &PARENT) {
+        parent.add_menu_entry(self.node.clone());
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
+
+
+pub struct MockCheckMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    outlet:   OUTLET,
+    node:     Rc<MockMenuNode>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+pub trait MockCheckMenuItem<OUTLET>
+where
+    OUTLET: OutletHolder,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>;
+
+    /// Simulates the user choosing this item, toggling its checkmark.
+    fn click(&self) -> PlatingResult<()>;
+}
+
+impl<OUTLET> MockCheckMenuItem<OUTLET> for MockCheckMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self> {
+        let result = Self {
+            outlet,
+            node: MockMenuNode::new(MockMenuKind::CheckItem {
+                label:   String::from(label),
+                checked: false,
+            }),
+            _private: crate::Private {},
+        };
+        Ok(result)
+    }
+
+    fn click(&self) -> PlatingResult<()> {
+        self.node.choose()
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockCheckMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> Emits<CheckedChanged> for MockCheckMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&CheckedChanged) + 'static,
+    {
+        self.node.checked_changed.borrow_mut().add(handler);
+    }
+}
+
+impl<OUTLET> CheckMenuItem<OUTLET> for MockCheckMenuItemWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self> {
+        <Self as MockCheckMenuItem<OUTLET>>::new(outlet, label)
+    }
+
+    fn label(&self) -> String {
+        self.node.label()
+    }
+
+    fn set_label(&mut self, label: &str) -> PlatingResult<()> {
+        self.node.set_label(label);
+        Ok(())
+    }
+
+    fn checked(&self) -> bool {
+        self.node.checked()
+    }
+
+    fn set_checked(&mut self, checked: bool) -> PlatingResult<()> {
+        self.node.set_checked(checked);
+        Ok(())
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockCheckMenuItemWidget<OUTLET>
+where
+    PARENT: MockMenuContainer,
+    OUTLET: OutletHolder,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, parent: &PARENT) {
+        parent.add_menu_entry(self.node.clone());
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
+
+
+pub struct MockMenuSeparatorWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    outlet:   OUTLET,
+    node:     Rc<MockMenuNode>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+pub trait MockMenuSeparator<OUTLET>
+where
+    OUTLET: OutletHolder,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>;
+}
+
+impl<OUTLET> MockMenuSeparator<OUTLET> for MockMenuSeparatorWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self> {
+        let result = Self {
+            outlet,
+            node: MockMenuNode::new(MockMenuKind::Separator),
+            _private: crate::Private {},
+        };
+        Ok(result)
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockMenuSeparatorWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MenuSeparator<OUTLET> for MockMenuSeparatorWidget<OUTLET>
+where
+    OUTLET: OutletHolder,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self> {
+        <Self as MockMenuSeparator<OUTLET>>::new(outlet)
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockMenuSeparatorWidget<OUTLET>
+where
+    PARENT: MockMenuContainer,
+    OUTLET: OutletHolder,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, parent: &PARENT) {
+        parent.add_menu_entry(self.node.clone());
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
@@ -0,0 +1,131 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+
+use crate::widgets::{DialogHandle, MessageOptions, PromptOptions, StandardDialogs};
+use crate::{PlatingError, PlatingResult};
+
+/// A scripted answer to the next standard dialog shown by [`MockDialogs`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum MockDialogAnswer {
+    /// Dismisses a message dialog.
+    Dismiss,
+    /// Answers a confirm dialog.
+    Confirm(bool),
+    /// Answers a prompt dialog, `None` cancels it.
+    Prompt(Option<String>),
+}
+
+/// How [`MockDialogs`] answers dialogs without a scripted answer.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MockAutoAnswer {
+    /// Confirms and accepts the default value of prompts.
+    #[default]
+    Accept,
+    /// Declines and cancels prompts.
+    Reject,
+    /// Fails every dialog that has no scripted answer.
+    Fail,
+}
+
+/// A standard dialog shown via [`MockDialogs`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum MockShownDialog {
+    Message(MessageOptions),
+    Confirm(MessageOptions),
+    Prompt(PromptOptions),
+}
+
+/// [`StandardDialogs`] that answer immediately, either with scripted answers
+/// or the configured [`MockAutoAnswer`].
+#[derive(Debug, Default)]
+pub struct MockDialogs {
+    answers: RefCell<VecDeque<MockDialogAnswer>>,
+    auto_answer: Cell<MockAutoAnswer>,
+    shown: RefCell<Vec<MockShownDialog>>,
+}
+
+impl MockDialogs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues the answer to the next dialog.
+    pub fn queue_answer(&self, answer: MockDialogAnswer) {
+        self.answers.borrow_mut().push_back(answer);
+    }
+
+    pub fn set_auto_answer(&self, auto_answer: MockAutoAnswer) {
+        self.auto_answer.set(auto_answer);
+    }
+
+    /// All dialogs shown so far, oldest first.
+    pub fn shown(&self) -> Vec<MockShownDialog> {
+        self.shown.borrow().clone()
+    }
+
+    /// Number of queued answers not used up yet.
+    pub fn pending_answers(&self) -> usize {
+        self.answers.borrow().len()
+    }
+
+    fn answer(&self, dialog: MockShownDialog) -> PlatingResult<MockDialogAnswer> {
+        let answer = match self.answers.borrow_mut().pop_front() {
+            Some(answer) => answer,
+            None => match (self.auto_answer.get(), &dialog) {
+                (MockAutoAnswer::Fail, _) => {
+                    return Err(PlatingError::state(format!(
+                        "no answer queued for {:?}",
+                        dialog
+                    )))
+                },
+                (_, MockShownDialog::Message(_)) => MockDialogAnswer::Dismiss,
+                (auto_answer, MockShownDialog::Confirm(_)) => {
+                    MockDialogAnswer::Confirm(auto_answer == MockAutoAnswer::Accept)
+                },
+                (MockAutoAnswer::Accept, MockShownDialog::Prompt(options)) => {
+                    MockDialogAnswer::Prompt(Some(options.default_value.clone()))
+                },
+                (_, MockShownDialog::Prompt(_)) => MockDialogAnswer::Prompt(None),
+            },
+        };
+        self.shown.borrow_mut().push(dialog);
+        Ok(answer)
+    }
+}
+
+impl StandardDialogs for MockDialogs {
+    fn message(&self, options: &MessageOptions) -> PlatingResult<DialogHandle<()>> {
+        match self.answer(MockShownDialog::Message(options.clone()))? {
+            MockDialogAnswer::Dismiss => Ok(DialogHandle::resolved(())),
+            answer => Err(PlatingError::invalid(
+                "answer",
+                format!("{:?} does not answer a message dialog", answer),
+            )),
+        }
+    }
+
+    fn confirm(&self, options: &MessageOptions) -> PlatingResult<DialogHandle<bool>> {
+        match self.answer(MockShownDialog::Confirm(options.clone()))? {
+            MockDialogAnswer::Confirm(confirmed) => Ok(DialogHandle::resolved(confirmed)),
+            answer => Err(PlatingError::invalid(
+                "answer",
+                format!("{:?} does not answer a confirm dialog", answer),
+            )),
+        }
+    }
+
+    fn prompt(&self, options: &PromptOptions) -> PlatingResult<DialogHandle<Option<String>>> {
+        match self.answer(MockShownDialog::Prompt(options.clone()))? {
+            MockDialogAnswer::Prompt(value) => Ok(DialogHandle::resolved(value)),
+            answer => Err(PlatingError::invalid(
+                "answer",
+                format!("{:?} does not answer a prompt dialog", answer),
+            )),
+        }
+    }
+}
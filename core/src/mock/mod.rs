@@ -14,4 +14,46 @@ pub use window::*;
 mod mock_only;
 pub use mock_only::*;
 
+mod context_menu;
+pub use context_menu::*;
+
+mod dialog;
+pub use dialog::*;
+
+mod file_dialog;
+pub use file_dialog::*;
+
+mod grid;
+pub use grid::*;
+
+mod group_box;
+pub use group_box::*;
+
+mod list_view;
+pub use list_view::*;
+
+mod menu;
+pub use menu::*;
+
+mod message_dialog;
+pub use message_dialog::*;
+
+mod notifications;
+pub use notifications::*;
+
+mod scroll_view;
+pub use scroll_view::*;
+
+mod split_view;
+pub use split_view::*;
+
+mod tabs;
+pub use tabs::*;
+
+mod tray_icon;
+pub use tray_icon::*;
+
+mod tree_view;
+pub use tree_view::*;
+
 pub mod traits;
@@ -0,0 +1,119 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use crate::utils::events::EventHandlers;
+use crate::widgets::{Notification, NotificationId, NotificationResponse, Notifications};
+use crate::{PlatingError, PlatingResult};
+
+/// A notification shown via [`MockNotifications`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MockShownNotification {
+    pub id: NotificationId,
+    pub notification: Notification,
+    /// Whether the notification is still shown, i.e. neither answered nor
+    /// withdrawn.
+    pub pending: bool,
+}
+
+/// [`Notifications`] recording every notification, so tests can inspect and
+/// answer them.
+#[derive(Debug, Default)]
+pub struct MockNotifications {
+    next_id:   Cell<u64>,
+    shown:     RefCell<Vec<MockShownNotification>>,
+    responses: RefCell<HashMap<NotificationId, EventHandlers<NotificationResponse>>>,
+}
+
+impl MockNotifications {
+    /// All notifications shown so far, oldest first.
+    pub fn shown(&self) -> Vec<MockShownNotification> {
+        self.shown.borrow().clone()
+    }
+
+    /// Simulates the user clicking the notification.
+    pub fn click(&self, id: NotificationId) -> PlatingResult<()> {
+        self.respond(id, NotificationResponse::Clicked)
+    }
+
+    /// Simulates the user choosing the action at `index`.
+    pub fn choose_action(&self, id: NotificationId, index: usize) -> PlatingResult<()> {
+        let actions = self.notification(id)?.actions;
+        let label = actions
+            .get(index)
+            .cloned()
+            .ok_or(PlatingError::OutOfRange {
+                item: "action",
+                index,
+                len: actions.len(),
+            })?;
+        self.respond(id, NotificationResponse::Action { index, label })
+    }
+
+    /// Simulates the user closing the notification.
+    pub fn dismiss(&self, id: NotificationId) -> PlatingResult<()> {
+        self.respond(id, NotificationResponse::Dismissed)
+    }
+
+    fn notification(&self, id: NotificationId) -> PlatingResult<Notification> {
+        self.shown
+            .borrow()
+            .iter()
+            .find(|shown| shown.id == id && shown.pending)
+            .map(|shown| shown.notification.clone())
+            .ok_or_else(|| PlatingError::state(format!("notification {:?} is not shown", id)))
+    }
+
+    /// Marks the notification as no longer shown and returns its handlers.
+    fn close(&self, id: NotificationId) -> PlatingResult<EventHandlers<NotificationResponse>> {
+        let mut shown = self.shown.borrow_mut();
+        let entry = shown
+            .iter_mut()
+            .find(|shown| shown.id == id && shown.pending)
+            .ok_or_else(|| PlatingError::state(format!("notification {:?} is not shown", id)))?;
+        entry.pending = false;
+        Ok(self.responses.borrow_mut().remove(&id).unwrap_or_default())
+    }
+
+    fn respond(&self, id: NotificationId, response: NotificationResponse) -> PlatingResult<()> {
+        // handlers are taken out first, so they can show new notifications
+        self.close(id)?.emit(&response);
+        Ok(())
+    }
+}
+
+impl Notifications for MockNotifications {
+    fn new() -> PlatingResult<Self> {
+        Ok(Self::default())
+    }
+
+    fn notify<F>(
+        &self,
+        notification: &Notification,
+        on_response: F,
+    ) -> PlatingResult<NotificationId>
+    where
+        F: FnMut(&NotificationResponse) + 'static,
+    {
+        let id = NotificationId(self.next_id.get());
+        self.next_id.set(id.0 + 1);
+
+        let mut handlers = EventHandlers::new();
+        handlers.add(on_response);
+        self.responses.borrow_mut().insert(id, handlers);
+        self.shown.borrow_mut().push(MockShownNotification {
+            id,
+            notification: notification.clone(),
+            pending: true,
+        });
+        Ok(id)
+    }
+
+    fn withdraw(&self, id: NotificationId) -> PlatingResult<()> {
+        self.close(id).map(drop)
+    }
+}
@@ -0,0 +1,234 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use super::traits::{MockChildOf, MockContainer};
+use crate::data::{Point, Rect, Size};
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::events::{Emits, EventHandlers};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::{ScrollView, Scrolled, Widget};
+use crate::{PlatingError, PlatingResult};
+
+/// Size of the visible area of a mock scroll view, until changed via
+/// `set_viewport_size`.
+pub const MOCK_VIEWPORT_SIZE: Size = Size::new(400.0, 300.0);
+
+pub struct MockScrollViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    outlet: OUTLET,
+    viewport: Size,
+    content: Size,
+    offset: Point,
+    /// Frames of the children within the content, if known.
+    child_frames: Vec<Option<Rect>>,
+    scrolled: EventHandlers<Scrolled>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+impl<OUTLET> MockScrollViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn clamp(&self, offset: Point) -> Point {
+        let max_x = (self.content.width - self.viewport.width).max(0.0);
+        let max_y = (self.content.height - self.viewport.height).max(0.0);
+        Point::new(offset.x.clamp(0.0, max_x), offset.y.clamp(0.0, max_y))
+    }
+
+    fn child_frame(&self, index: usize) -> PlatingResult<Rect> {
+        match self.child_frames.get(index) {
+            Some(Some(frame)) => Ok(*frame),
+            Some(None) => Err(PlatingError::state(format!(
+                "frame of child {} is unknown",
+                index
+            ))),
+            None => Err(PlatingError::OutOfRange {
+                item: "child",
+                index,
+                len: self.child_frames.len(),
+            }),
+        }
+    }
+}
+
+pub trait MockScrollView<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+
+    /// Pretends the scroll view got resized.
+    fn set_viewport_size(&mut self, size: Size) -> PlatingResult<()>;
+
+    /// Pretends the children got laid out to fill `size`.
+    fn set_content_size(&mut self, size: Size) -> PlatingResult<()>;
+
+    /// Pretends the child at `index` got laid out at `frame`.
+    fn set_child_frame(&mut self, index: usize, frame: Rect) -> PlatingResult<()>;
+
+    /// Simulates the user scrolling to `offset`.
+    fn scroll_by_user(&mut self, offset: Point) -> PlatingResult<()>;
+}
+
+impl<OUTLET> MockScrollView<OUTLET> for MockScrollViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+    {
+        let child_count =
+            <<OUTLET as Outlet<ChildrenOutlet>>::Children as ChildrenList>::TUPLE_LIST_SIZE;
+        let result = Self {
+            outlet,
+            viewport: MOCK_VIEWPORT_SIZE,
+            content: MOCK_VIEWPORT_SIZE,
+            offset: Point::default(),
+            child_frames: vec![None; child_count],
+            scrolled: EventHandlers::new(),
+            _private: crate::Private {},
+        };
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+
+        Ok(result)
+    }
+
+    fn set_viewport_size(&mut self, size: Size) -> PlatingResult<()> {
+        self.viewport = size;
+        self.offset = self.clamp(self.offset);
+        Ok(())
+    }
+
+    fn set_content_size(&mut self, size: Size) -> PlatingResult<()> {
+        self.content = size;
+        self.offset = self.clamp(self.offset);
+        Ok(())
+    }
+
+    fn set_child_frame(&mut self, index: usize, frame: Rect) -> PlatingResult<()> {
+        let child_count = self.child_frames.len();
+        match self.child_frames.get_mut(index) {
+            Some(slot) => {
+                *slot = Some(frame);
+                Ok(())
+            },
+            None => Err(PlatingError::OutOfRange {
+                item: "child",
+                index,
+                len: child_count,
+            }),
+        }
+    }
+
+    fn scroll_by_user(&mut self, offset: Point) -> PlatingResult<()> {
+        let offset = self.clamp(offset);
+        if offset != self.offset {
+            self.offset = offset;
+            self.scrolled.emit(&Scrolled { offset });
+        }
+        Ok(())
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockScrollViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MockContainer for MockScrollViewWidget<OUTLET> where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>
+{
+}
+
+impl<OUTLET> Emits<Scrolled> for MockScrollViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&Scrolled) + 'static,
+    {
+        self.scrolled.add(handler);
+    }
+}
+
+impl<OUTLET> ScrollView<OUTLET> for MockScrollViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+    {
+        <Self as MockScrollView<OUTLET>>::new(outlet)
+    }
+
+    fn content_size(&self) -> Size {
+        self.content
+    }
+
+    fn viewport_size(&self) -> Size {
+        self.viewport
+    }
+
+    fn scroll_offset(&self) -> Point {
+        self.offset
+    }
+
+    fn scroll_to(&mut self, offset: Point) -> PlatingResult<()> {
+        self.offset = self.clamp(offset);
+        Ok(())
+    }
+
+    fn scroll_to_child(&mut self, index: usize) -> PlatingResult<()> {
+        let frame = self.child_frame(index)?;
+
+        let mut offset = self.offset;
+        if frame.origin.x < offset.x {
+            offset.x = frame.origin.x;
+        } else if frame.max_x() > offset.x + self.viewport.width {
+            offset.x = frame.max_x() - self.viewport.width;
+        }
+        if frame.origin.y < offset.y {
+            offset.y = frame.origin.y;
+        } else if frame.max_y() > offset.y + self.viewport.height {
+            offset.y = frame.max_y() - self.viewport.height;
+        }
+
+        self.offset = self.clamp(offset);
+        Ok(())
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockScrollViewWidget<OUTLET>
+where
+    PARENT: MockContainer,
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        <<OUTLET as Outlet<ChildrenOutlet>>::Children as ChildOf<Self, ChildrenOutlet>>::disconnect(
+            <OUTLET as Outlet<ChildrenOutlet>>::get(&self.outlet),
+        );
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use super::traits::{MockChildOf, MockContainer};
+use crate::data::{Orientation, Size};
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::events::{Emits, EventHandlers};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::{DividerMoved, SplitView, Widget};
+use crate::{PlatingError, PlatingResult};
+
+/// Size of every mock split view. The dividers start out evenly distributed
+/// over it.
+pub const MOCK_SPLIT_VIEW_SIZE: Size = Size::new(400.0, 300.0);
+
+pub struct MockSplitViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    outlet: OUTLET,
+    orientation: Orientation,
+    dividers: Vec<f64>,
+    divider_moved: EventHandlers<DividerMoved>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+impl<OUTLET> MockSplitViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn length(&self) -> f64 {
+        match self.orientation {
+            Orientation::Horizontal => MOCK_SPLIT_VIEW_SIZE.width,
+            Orientation::Vertical => MOCK_SPLIT_VIEW_SIZE.height,
+        }
+    }
+
+    /// Clamps `position` between the neighbours of divider `index`.
+    fn clamp(&self, index: usize, position: f64) -> PlatingResult<f64> {
+        if index >= self.dividers.len() {
+            return Err(PlatingError::OutOfRange {
+                item: "divider",
+                index,
+                len: self.dividers.len(),
+            });
+        }
+
+        let min = index
+            .checked_sub(1)
+            .map(|previous| self.dividers[previous])
+            .unwrap_or(0.0);
+        let max = self
+            .dividers
+            .get(index + 1)
+            .copied()
+            .unwrap_or_else(|| self.length());
+        Ok(position.clamp(min, max))
+    }
+}
+
+pub trait MockSplitView<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, orientation: Orientation) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+
+    /// Simulates the user dragging divider `index` to `position`.
+    fn drag_divider(&mut self, index: usize, position: f64) -> PlatingResult<()>;
+}
+
+impl<OUTLET> MockSplitView<OUTLET> for MockSplitViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, orientation: Orientation) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+    {
+        let child_count =
+            <<OUTLET as Outlet<ChildrenOutlet>>::Children as ChildrenList>::TUPLE_LIST_SIZE;
+        let mut result = Self {
+            outlet,
+            orientation,
+            dividers: Vec::new(),
+            divider_moved: EventHandlers::new(),
+            _private: crate::Private {},
+        };
+        let pane = result.length() / child_count.max(1) as f64;
+        result.dividers = (1..child_count).map(|pos| pane * pos as f64).collect();
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+
+        Ok(result)
+    }
+
+    fn drag_divider(&mut self, index: usize, position: f64) -> PlatingResult<()> {
+        let position = self.clamp(index, position)?;
+        if position != self.dividers[index] {
+            self.dividers[index] = position;
+            self.divider_moved.emit(&DividerMoved { index, position });
+        }
+        Ok(())
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockSplitViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MockContainer for MockSplitViewWidget<OUTLET> where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>
+{
+}
+
+impl<OUTLET> Emits<DividerMoved> for MockSplitViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&DividerMoved) + 'static,
+    {
+        self.divider_moved.add(handler);
+    }
+}
+
+impl<OUTLET> SplitView<OUTLET> for MockSplitViewWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, orientation: Orientation) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+    {
+        <Self as MockSplitView<OUTLET>>::new(outlet, orientation)
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn divider_positions(&self) -> &[f64] {
+        &self.dividers
+    }
+
+    fn set_divider_position(&mut self, index: usize, position: f64) -> PlatingResult<()> {
+        self.dividers[index] = self.clamp(index, position)?;
+        Ok(())
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockSplitViewWidget<OUTLET>
+where
+    PARENT: MockContainer,
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        <<OUTLET as Outlet<ChildrenOutlet>>::Children as ChildOf<Self, ChildrenOutlet>>::disconnect(
+            <OUTLET as Outlet<ChildrenOutlet>>::get(&self.outlet),
+        );
+    }
+}
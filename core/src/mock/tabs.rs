@@ -0,0 +1,348 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::traits::{MockChildOf, MockContainer};
+use crate::utils::children::ChildOf;
+use crate::utils::events::{Emits, EventHandlers};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::{Tab, TabClosed, TabSelectionChanged, Tabs, Widget};
+use crate::{PlatingError, PlatingResult};
+
+/// State of a [`MockTabWidget`], shared with the [`MockTabsWidget`] it is
+/// connected to.
+#[derive(Debug, Clone, Default)]
+struct MockTabState {
+    label:    String,
+    closable: bool,
+    open:     bool,
+}
+
+pub struct MockTabsWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    outlet: OUTLET,
+    /// Filled by the tabs when they get connected.
+    tabs: RefCell<Vec<Rc<RefCell<MockTabState>>>>,
+    selected: Option<usize>,
+    selection_changed: EventHandlers<TabSelectionChanged>,
+    tab_closed: EventHandlers<TabClosed>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+impl<OUTLET> MockTabsWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn tab(&self, index: usize) -> PlatingResult<Rc<RefCell<MockTabState>>> {
+        let tabs = self.tabs.borrow();
+        tabs.get(index)
+            .cloned()
+            .ok_or_else(|| PlatingError::OutOfRange {
+                item: "tab",
+                index,
+                len: tabs.len(),
+            })
+    }
+
+    fn check_open(&self, index: usize) -> PlatingResult<()> {
+        if self.tab(index)?.borrow().open {
+            Ok(())
+        } else {
+            Err(PlatingError::state(format!("tab {} is closed", index)))
+        }
+    }
+
+    /// The open tab closest to `index`, preferring the ones after it.
+    fn nearest_open(&self, index: usize) -> Option<usize> {
+        let tabs = self.tabs.borrow();
+        let is_open = |candidate: &usize| tabs[*candidate].borrow().open;
+        (index..tabs.len())
+            .find(is_open)
+            .or_else(|| (0..index.min(tabs.len())).rev().find(is_open))
+    }
+
+    /// Changes the selection and emits [`TabSelectionChanged`] if it changed.
+    fn change_selection(&mut self, selected: Option<usize>) {
+        let previous = self.selected;
+        if previous != selected {
+            self.selected = selected;
+            self.selection_changed
+                .emit(&TabSelectionChanged { selected, previous });
+        }
+    }
+}
+
+pub trait MockTabs<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+
+    /// Simulates the user clicking the tab at `index`.
+    fn click_tab(&mut self, index: usize) -> PlatingResult<()>;
+
+    /// Simulates the user clicking the close button of the tab at `index`.
+    ///
+    /// Selects the nearest open tab if the closed one was selected.
+    fn close_tab(&mut self, index: usize) -> PlatingResult<()>;
+}
+
+impl<OUTLET> MockTabs<OUTLET> for MockTabsWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+    {
+        let mut result = Self {
+            outlet,
+            tabs: RefCell::new(Vec::new()),
+            selected: None,
+            selection_changed: EventHandlers::new(),
+            tab_closed: EventHandlers::new(),
+            _private: crate::Private {},
+        };
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+        result.selected = result.nearest_open(0);
+
+        Ok(result)
+    }
+
+    fn click_tab(&mut self, index: usize) -> PlatingResult<()> {
+        self.check_open(index)?;
+        self.change_selection(Some(index));
+        Ok(())
+    }
+
+    fn close_tab(&mut self, index: usize) -> PlatingResult<()> {
+        self.check_open(index)?;
+        let tab = self.tab(index)?;
+        if !tab.borrow().closable {
+            return Err(PlatingError::state(format!(
+                "tab {} is not closable",
+                index
+            )));
+        }
+
+        tab.borrow_mut().open = false;
+        self.tab_closed.emit(&TabClosed { index });
+        if self.selected == Some(index) {
+            let selected = self.nearest_open(index);
+            self.change_selection(selected);
+        }
+        Ok(())
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockTabsWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> Emits<TabSelectionChanged> for MockTabsWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&TabSelectionChanged) + 'static,
+    {
+        self.selection_changed.add(handler);
+    }
+}
+
+impl<OUTLET> Emits<TabClosed> for MockTabsWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&TabClosed) + 'static,
+    {
+        self.tab_closed.add(handler);
+    }
+}
+
+impl<OUTLET> Tabs<OUTLET> for MockTabsWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+    {
+        <Self as MockTabs<OUTLET>>::new(outlet)
+    }
+
+    fn tab_count(&self) -> usize {
+        self.tabs.borrow().len()
+    }
+
+    fn tab_label(&self, index: usize) -> Option<String> {
+        let tabs = self.tabs.borrow();
+        let label = tabs.get(index)?.borrow().label.clone();
+        Some(label)
+    }
+
+    fn is_tab_open(&self, index: usize) -> bool {
+        self.tab(index)
+            .map(|tab| tab.borrow().open)
+            .unwrap_or(false)
+    }
+
+    fn reopen_tab(&mut self, index: usize) -> PlatingResult<()> {
+        self.tab(index)?.borrow_mut().open = true;
+        if self.selected.is_none() {
+            self.selected = Some(index);
+        }
+        Ok(())
+    }
+
+    fn selected_tab(&self) -> Option<usize> {
+        self.selected
+    }
+
+    fn select_tab(&mut self, index: usize) -> PlatingResult<()> {
+        self.check_open(index)?;
+        self.selected = Some(index);
+        Ok(())
+    }
+}
+
+impl<PARENT, OUTLET> ChildOf<PARENT, ChildrenOutlet> for MockTabsWidget<OUTLET>
+where
+    PARENT: MockContainer,
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
+
+
+pub struct MockTabWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    outlet:   OUTLET,
+    state:    Rc<RefCell<MockTabState>>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+pub trait MockTab<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+}
+
+impl<OUTLET> MockTab<OUTLET> for MockTabWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+    {
+        let result = Self {
+            outlet,
+            state: Rc::new(RefCell::new(MockTabState {
+                label:    String::from(label),
+                closable: false,
+                open:     true,
+            })),
+            _private: crate::Private {},
+        };
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+
+        Ok(result)
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockTabWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MockContainer for MockTabWidget<OUTLET> where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>
+{
+}
+
+impl<OUTLET> Tab<OUTLET> for MockTabWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+    {
+        <Self as MockTab<OUTLET>>::new(outlet, label)
+    }
+
+    fn label(&self) -> String {
+        self.state.borrow().label.clone()
+    }
+
+    fn set_label(&mut self, label: &str) -> PlatingResult<()> {
+        self.state.borrow_mut().label = String::from(label);
+        Ok(())
+    }
+
+    fn closable(&self) -> bool {
+        self.state.borrow().closable
+    }
+
+    fn set_closable(&mut self, closable: bool) -> PlatingResult<()> {
+        self.state.borrow_mut().closable = closable;
+        Ok(())
+    }
+}
+
+impl<OUTLET1, OUTLET2> ChildOf<MockTabsWidget<OUTLET1>, ChildrenOutlet> for MockTabWidget<OUTLET2>
+where
+    OUTLET1: OutletHolder + Outlet<ChildrenOutlet>,
+    OUTLET2: OutletHolder + Outlet<ChildrenOutlet>,
+{
+    fn setup(&mut self, _parent: &MockTabsWidget<OUTLET1>) {
+        //todo!()
+    }
+
+    fn connect(&self, parent: &MockTabsWidget<OUTLET1>) {
+        self.state.borrow_mut().open = true;
+        parent.tabs.borrow_mut().push(self.state.clone());
+    }
+
+    fn disconnect(&self) {
+        self.state.borrow_mut().open = false;
+    }
+}
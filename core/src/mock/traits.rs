@@ -3,8 +3,11 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+use std::rc::Rc;
+
+use super::MockMenuNode;
 use crate::utils::children::ChildOf;
-use crate::utils::outlet::OutletType;
+use crate::utils::outlet::{ChildrenOutlet, OutletType};
 
 //TODO: autogenerate
 pub trait MockChildOf<TY, OL>
@@ -30,3 +33,23 @@ where
         <Self as ChildOf<TY, OL>>::disconnect(self);
     }
 }
+
+/// Marker for mock widgets whose outlet `OL` accepts any regular mock widget.
+///
+/// Mock widgets implement `ChildOf<PARENT, ChildrenOutlet>` for all
+/// `PARENT: MockContainer` instead of once per parent type. Widgets that also
+/// fit into toolbars and status bars do so for every outlet `OL`.
+pub trait MockContainer<OL = ChildrenOutlet>
+where
+    OL: OutletType,
+{
+}
+
+/// Implemented by mock widgets whose `ChildrenOutlet` holds menu entries.
+pub trait MockMenuContainer {
+    /// Registers a connected menu entry, so it can be picked later on.
+    fn add_menu_entry(&self, entry: Rc<MockMenuNode>);
+}
+
+/// Marker for mock widgets whose `MenuOutlet` accepts mock menus.
+pub trait MockMenuHost {}
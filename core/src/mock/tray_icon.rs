@@ -0,0 +1,132 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use super::traits::{MockChildOf, MockMenuHost};
+use crate::data::Icon;
+use crate::utils::children::ChildOf;
+use crate::utils::events::{Emits, EventHandlers};
+use crate::utils::outlet::{MenuOutlet, Outlet, OutletHolder};
+use crate::widgets::{TrayIcon, TrayIconActivated, Widget};
+use crate::{PlatingError, PlatingResult};
+
+pub struct MockTrayIconWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>,
+{
+    outlet:    OUTLET,
+    icon:      Icon,
+    tooltip:   String,
+    visible:   bool,
+    activated: EventHandlers<TrayIconActivated>,
+    _private:  crate::Private, // Creation is limited to our constructors
+}
+
+pub trait MockTrayIcon<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, icon: Icon, tooltip: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<MenuOutlet>>::Children: MockChildOf<Self, MenuOutlet>;
+
+    /// Simulates the user clicking the icon.
+    fn click(&mut self) -> PlatingResult<()>;
+}
+
+impl<OUTLET> MockTrayIcon<OUTLET> for MockTrayIconWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>,
+{
+    fn new(outlet: OUTLET, icon: Icon, tooltip: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<MenuOutlet>>::Children: MockChildOf<Self, MenuOutlet>,
+    {
+        let result = Self {
+            outlet,
+            icon,
+            tooltip: String::from(tooltip),
+            visible: true,
+            activated: EventHandlers::new(),
+            _private: crate::Private {},
+        };
+        <OUTLET as Outlet<MenuOutlet>>::get(&result.outlet).connect(&result);
+
+        Ok(result)
+    }
+
+    fn click(&mut self) -> PlatingResult<()> {
+        if !self.visible {
+            return Err(PlatingError::state("the tray icon is hidden"));
+        }
+        self.activated.emit(&TrayIconActivated {});
+        Ok(())
+    }
+}
+
+impl<OUTLET> Widget<OUTLET> for MockTrayIconWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET> MockMenuHost for MockTrayIconWidget<OUTLET> where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>
+{
+}
+
+impl<OUTLET> Emits<TrayIconActivated> for MockTrayIconWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&TrayIconActivated) + 'static,
+    {
+        self.activated.add(handler);
+    }
+}
+
+impl<OUTLET> TrayIcon<OUTLET> for MockTrayIconWidget<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>,
+{
+    fn new(outlet: OUTLET, icon: Icon, tooltip: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<MenuOutlet>>::Children: ChildOf<Self, MenuOutlet>,
+    {
+        <Self as MockTrayIcon<OUTLET>>::new(outlet, icon, tooltip)
+    }
+
+    fn icon(&self) -> &Icon {
+        &self.icon
+    }
+
+    fn set_icon(&mut self, icon: Icon) -> PlatingResult<()> {
+        self.icon = icon;
+        Ok(())
+    }
+
+    fn tooltip(&self) -> String {
+        self.tooltip.clone()
+    }
+
+    fn set_tooltip(&mut self, tooltip: &str) -> PlatingResult<()> {
+        self.tooltip = String::from(tooltip);
+        Ok(())
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) -> PlatingResult<()> {
+        self.visible = visible;
+        Ok(())
+    }
+}
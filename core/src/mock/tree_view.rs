@@ -0,0 +1,416 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::traits::MockContainer;
+use crate::data::{ColumnDefinition, SelectionMode, TreePath};
+use crate::utils::children::ChildOf;
+use crate::utils::events::{Emits, EventHandlers};
+use crate::utils::outlet::{ChildrenOutlet, OutletHolder};
+use crate::widgets::{
+    NodeActivated,
+    NodeCollapsed,
+    NodeExpanded,
+    NodeSelectionChanged,
+    TreeDataSource,
+    TreeView,
+    Widget,
+};
+use crate::{PlatingError, PlatingResult};
+
+pub struct MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    outlet: OUTLET,
+    source: SOURCE,
+    columns: Vec<ColumnDefinition>,
+    /// Child count of every node whose children got loaded.
+    loaded: BTreeMap<TreePath, usize>,
+    expanded: BTreeSet<TreePath>,
+    selection_mode: SelectionMode,
+    selection: Vec<TreePath>,
+    selection_changed: EventHandlers<NodeSelectionChanged>,
+    node_activated: EventHandlers<NodeActivated>,
+    node_expanded: EventHandlers<NodeExpanded>,
+    node_collapsed: EventHandlers<NodeCollapsed>,
+    _private: crate::Private, // Creation is limited to our constructors
+}
+
+impl<OUTLET, SOURCE> MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn load(&mut self, node: &TreePath) -> PlatingResult<()> {
+        if !self.loaded.contains_key(node) {
+            self.source.load_children(node)?;
+            let count = self.source.child_count(node);
+            self.loaded.insert(node.clone(), count);
+        }
+        Ok(())
+    }
+
+    /// Checks that `node` is not the root and exists within its loaded parent.
+    fn check_node(&self, node: &TreePath) -> PlatingResult<()> {
+        let (parent, index) = match (node.parent(), node.index()) {
+            (Some(parent), Some(index)) => (parent, index),
+            _ => {
+                return Err(PlatingError::invalid(
+                    "node",
+                    "the root node is not part of the tree view",
+                ))
+            },
+        };
+        match self.loaded.get(&parent) {
+            Some(count) if index < *count => Ok(()),
+            Some(count) => Err(PlatingError::invalid(
+                "node",
+                format!(
+                    "{} is out of range, its parent has {} children",
+                    node, count
+                ),
+            )),
+            None => Err(PlatingError::invalid(
+                "node",
+                format!("{} is unknown, its parent was never expanded", node),
+            )),
+        }
+    }
+
+    /// Checks that `node` exists and all its ancestors are expanded.
+    fn check_visible(&self, node: &TreePath) -> PlatingResult<()> {
+        self.check_node(node)?;
+
+        let mut ancestor = node.parent();
+        while let Some(current) = ancestor {
+            if !current.is_root() && !self.expanded.contains(&current) {
+                return Err(PlatingError::state(format!(
+                    "node {} is hidden, {} is collapsed",
+                    node, current
+                )));
+            }
+            ancestor = current.parent();
+        }
+        Ok(())
+    }
+
+    /// Expands `node` and returns whether it was collapsed before.
+    ///
+    /// Nodes without children stay collapsed.
+    fn expand_inner(&mut self, node: &TreePath) -> PlatingResult<bool> {
+        self.check_node(node)?;
+        if self.expanded.contains(node) || !self.source.has_children(node) {
+            return Ok(false);
+        }
+
+        self.load(node)?;
+        self.expanded.insert(node.clone());
+        Ok(true)
+    }
+
+    /// Collapses `node` and returns whether it was expanded before.
+    fn collapse_inner(&mut self, node: &TreePath) -> PlatingResult<bool> {
+        self.check_node(node)?;
+        Ok(self.expanded.remove(node))
+    }
+
+    fn push_visible(&self, parent: &TreePath, nodes: &mut Vec<TreePath>) {
+        let count = self.loaded.get(parent).copied().unwrap_or_default();
+        for index in 0..count {
+            let node = parent.child(index);
+            nodes.push(node.clone());
+            if self.expanded.contains(&node) {
+                self.push_visible(&node, nodes);
+            }
+        }
+    }
+}
+
+pub trait MockTreeView<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+    Self: Sized,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self>;
+
+    /// All nodes whose ancestors are expanded, in display order.
+    fn visible_nodes(&self) -> Vec<TreePath>;
+
+    /// The number of children loaded for `node`, `None` if they were never
+    /// requested from the source.
+    fn loaded_child_count(&self, node: &TreePath) -> Option<usize>;
+
+    /// Simulates the user clicking the expander of `node`.
+    fn expand_node(&mut self, node: &TreePath) -> PlatingResult<()>;
+
+    /// Simulates the user clicking the expander of the expanded `node`.
+    fn collapse_node(&mut self, node: &TreePath) -> PlatingResult<()>;
+
+    /// Simulates the user clicking `node`.
+    fn click_node(&mut self, node: &TreePath) -> PlatingResult<()>;
+
+    /// Simulates the user activating `node`, e.g. by double clicking it.
+    fn activate_node(&mut self, node: &TreePath) -> PlatingResult<()>;
+}
+
+impl<OUTLET, SOURCE> MockTreeView<OUTLET, SOURCE> for MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self> {
+        let mut result = Self {
+            outlet,
+            columns: source.columns(),
+            source,
+            loaded: BTreeMap::new(),
+            expanded: BTreeSet::new(),
+            selection_mode: SelectionMode::default(),
+            selection: Vec::new(),
+            selection_changed: EventHandlers::new(),
+            node_activated: EventHandlers::new(),
+            node_expanded: EventHandlers::new(),
+            node_collapsed: EventHandlers::new(),
+            _private: crate::Private {},
+        };
+        result.load(&TreePath::root())?;
+        Ok(result)
+    }
+
+    fn visible_nodes(&self) -> Vec<TreePath> {
+        let mut nodes = Vec::new();
+        self.push_visible(&TreePath::root(), &mut nodes);
+        nodes
+    }
+
+    fn loaded_child_count(&self, node: &TreePath) -> Option<usize> {
+        self.loaded.get(node).copied()
+    }
+
+    fn expand_node(&mut self, node: &TreePath) -> PlatingResult<()> {
+        self.check_visible(node)?;
+        if self.expand_inner(node)? {
+            self.node_expanded
+                .emit(&NodeExpanded { node: node.clone() });
+        }
+        Ok(())
+    }
+
+    fn collapse_node(&mut self, node: &TreePath) -> PlatingResult<()> {
+        self.check_visible(node)?;
+        if self.collapse_inner(node)? {
+            self.node_collapsed
+                .emit(&NodeCollapsed { node: node.clone() });
+        }
+        Ok(())
+    }
+
+    fn click_node(&mut self, node: &TreePath) -> PlatingResult<()> {
+        self.check_visible(node)?;
+
+        let selection = match self.selection_mode {
+            SelectionMode::None => return Ok(()),
+            SelectionMode::Single => vec![node.clone()],
+            SelectionMode::Multiple if self.selection.contains(node) => self
+                .selection
+                .iter()
+                .filter(|selected| *selected != node)
+                .cloned()
+                .collect(),
+            SelectionMode::Multiple => {
+                let mut selection = self.selection.clone();
+                selection.push(node.clone());
+                selection.sort();
+                selection
+            },
+        };
+
+        if selection != self.selection {
+            self.selection = selection.clone();
+            self.selection_changed
+                .emit(&NodeSelectionChanged { selection });
+        }
+        Ok(())
+    }
+
+    fn activate_node(&mut self, node: &TreePath) -> PlatingResult<()> {
+        self.check_visible(node)?;
+        self.node_activated
+            .emit(&NodeActivated { node: node.clone() });
+        Ok(())
+    }
+}
+
+impl<OUTLET, SOURCE> Widget<OUTLET> for MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn outlet(&self) -> &OUTLET {
+        &self.outlet
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<NodeSelectionChanged> for MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&NodeSelectionChanged) + 'static,
+    {
+        self.selection_changed.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<NodeActivated> for MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&NodeActivated) + 'static,
+    {
+        self.node_activated.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<NodeExpanded> for MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&NodeExpanded) + 'static,
+    {
+        self.node_expanded.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> Emits<NodeCollapsed> for MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&NodeCollapsed) + 'static,
+    {
+        self.node_collapsed.add(handler);
+    }
+}
+
+impl<OUTLET, SOURCE> TreeView<OUTLET, SOURCE> for MockTreeViewWidget<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self> {
+        <Self as MockTreeView<OUTLET, SOURCE>>::new(outlet, source)
+    }
+
+    fn source(&self) -> &SOURCE {
+        &self.source
+    }
+
+    fn source_mut(&mut self) -> &mut SOURCE {
+        &mut self.source
+    }
+
+    fn reload(&mut self) -> PlatingResult<()> {
+        self.columns = self.source.columns();
+        self.loaded.clear();
+        self.expanded.clear();
+        self.selection.clear();
+        self.load(&TreePath::root())
+    }
+
+    fn columns(&self) -> &[ColumnDefinition] {
+        &self.columns
+    }
+
+    fn is_expanded(&self, node: &TreePath) -> bool {
+        self.expanded.contains(node)
+    }
+
+    fn expand(&mut self, node: &TreePath) -> PlatingResult<()> {
+        self.expand_inner(node).map(|_| ())
+    }
+
+    fn collapse(&mut self, node: &TreePath) -> PlatingResult<()> {
+        self.collapse_inner(node).map(|_| ())
+    }
+
+    fn selection_mode(&self) -> SelectionMode {
+        self.selection_mode
+    }
+
+    fn set_selection_mode(&mut self, mode: SelectionMode) -> PlatingResult<()> {
+        match mode {
+            SelectionMode::None => self.selection.clear(),
+            SelectionMode::Single => self.selection.truncate(1),
+            SelectionMode::Multiple => {},
+        }
+        self.selection_mode = mode;
+        Ok(())
+    }
+
+    fn selection(&self) -> &[TreePath] {
+        &self.selection
+    }
+
+    fn set_selection(&mut self, mut selection: Vec<TreePath>) -> PlatingResult<()> {
+        for node in &selection {
+            self.check_node(node)?;
+        }
+        selection.sort();
+        selection.dedup();
+
+        let allowed = match self.selection_mode {
+            SelectionMode::None => selection.is_empty(),
+            SelectionMode::Single => selection.len() <= 1,
+            SelectionMode::Multiple => true,
+        };
+        if !allowed {
+            return Err(PlatingError::invalid(
+                "selection",
+                format!(
+                    "{} nodes are not allowed in {:?} selection mode",
+                    selection.len(),
+                    self.selection_mode
+                ),
+            ));
+        }
+
+        self.selection = selection;
+        Ok(())
+    }
+}
+
+impl<PARENT, OUTLET, SOURCE> ChildOf<PARENT, ChildrenOutlet> for MockTreeViewWidget<OUTLET, SOURCE>
+where
+    PARENT: MockContainer,
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+{
+    fn setup(&mut self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn connect(&self, _parent: &PARENT) {
+        //todo!()
+    }
+
+    fn disconnect(&self) {
+        //todo!()
+    }
+}
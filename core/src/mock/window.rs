@@ -3,121 +3,102 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
-use crate::mock::traits::MockChildOf;
+use std::cell::Cell;
+
+use plating_systems_macros::Outlets;
+
+use crate::data::{Rect, Size};
+use crate::layout::{FlexLayout, LayoutChildren, LayoutContainer};
+use crate::mock::traits::{MockChildOf, MockContainer, MockMenuHost};
 use crate::utils::children::{ChildOf, ChildrenList};
-use crate::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
-use crate::widgets::{Widget, Window, WindowOutlet};
+use crate::utils::outlet::{
+    ChildrenOutlet,
+    MenuOutlet,
+    Outlet,
+    OutletHolder,
+    StatusBarOutlet,
+    ToolbarOutlet,
+};
+use crate::widgets::{DialogParent, ModalBlock, Widget, Window, WindowOutlet};
 use crate::PlatingResult;
 
 
-//TODO: fully automate
-#[derive(Debug, Clone, Hash, Default)]
-pub struct MockWindowOutlet<MAIN, MENU>
+#[derive(Debug, Clone, Hash, Default, Outlets)]
+#[outlets(path = crate::utils::outlet, from = WindowOutlet<MAIN, MENU, TOOLBAR, STATUSBAR>)]
+pub struct MockWindowOutlet<MAIN, MENU, TOOLBAR = (), STATUSBAR = ()>
 where
     MAIN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
+    #[outlet(ChildrenOutlet)]
     pub children: MAIN,
-    pub menu:     MENU,
-}
-
-
-impl<MAIN, MENU> From<WindowOutlet<MAIN, MENU>> for MockWindowOutlet<MAIN, MENU>
-where
-    MAIN: ChildrenList,
-    MENU: ChildrenList,
-{
-    fn from(app: WindowOutlet<MAIN, MENU>) -> Self {
-        Self {
-            children: app.children,
-            menu:     app.menu,
-        }
-    }
-}
-
-impl<MAIN, MENU> Outlet<ChildrenOutlet> for MockWindowOutlet<MAIN, MENU>
-where
-    MAIN: ChildrenList,
-    MENU: ChildrenList,
-{
-    type Children = MAIN;
-
-    fn get(&self) -> &Self::Children {
-        &self.children
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.children
-    }
-}
-
-impl<MAIN, MENU> Outlet<MenuOutlet> for MockWindowOutlet<MAIN, MENU>
-where
-    MAIN: ChildrenList,
-    MENU: ChildrenList,
-{
-    type Children = MENU;
-
-    fn get(&self) -> &Self::Children {
-        &self.menu
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.menu
-    }
-}
-
-impl<MAIN, MENU> OutletHolder for MockWindowOutlet<MAIN, MENU>
-where
-    MAIN: ChildrenList,
-    MENU: ChildrenList,
-{
+    #[outlet(MenuOutlet)]
+    pub menu: MENU,
+    #[outlet(ToolbarOutlet)]
+    pub toolbar: TOOLBAR,
+    #[outlet(StatusBarOutlet)]
+    pub status_bar: STATUSBAR,
 }
-/*
-impl<CHILDREN, MENU> Native<Cocoa> for MockWindowOutlet<MAIN_CHILDREN, MENU_CHILDREN>
-where
-    MAIN_CHILDREN: ChildrenList,
-    MENU_CHILDREN: ChildrenList,
-{ }*/
-
-
 
-//TODO: end fully automate
 
 pub struct MockWindowWidget<OUTLET>
 where
-    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>,
 {
-    outlet:   OUTLET,
-    _private: crate::Private, // Creation is limited to our constructors
+    outlet:      OUTLET,
+    modal_block: ModalBlock,
+    flex_layout: Cell<FlexLayout>,
+    _private:    crate::Private, // Creation is limited to our constructors
 }
 
 pub trait MockWindow<OUTLET>
 where
-    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>,
     Self: Sized,
 {
     fn new(outlet: OUTLET) -> PlatingResult<Self>
     where
         <OUTLET as Outlet<MenuOutlet>>::Children: MockChildOf<Self, MenuOutlet>,
-        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>;
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+        <OUTLET as Outlet<ToolbarOutlet>>::Children: MockChildOf<Self, ToolbarOutlet>,
+        <OUTLET as Outlet<StatusBarOutlet>>::Children: MockChildOf<Self, StatusBarOutlet>;
 }
 
 impl<OUTLET> MockWindow<OUTLET> for MockWindowWidget<OUTLET>
 where
-    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>,
 {
     fn new(outlet: OUTLET) -> PlatingResult<Self>
     where
         <OUTLET as Outlet<MenuOutlet>>::Children: MockChildOf<Self, MenuOutlet>,
         <OUTLET as Outlet<ChildrenOutlet>>::Children: MockChildOf<Self, ChildrenOutlet>,
+        <OUTLET as Outlet<ToolbarOutlet>>::Children: MockChildOf<Self, ToolbarOutlet>,
+        <OUTLET as Outlet<StatusBarOutlet>>::Children: MockChildOf<Self, StatusBarOutlet>,
     {
         let result = Self {
             outlet,
+            modal_block: ModalBlock::default(),
+            flex_layout: Cell::new(FlexLayout::default()),
             _private: crate::Private {},
         };
         <OUTLET as Outlet<MenuOutlet>>::get(&result.outlet).connect(&result);
         <OUTLET as Outlet<ChildrenOutlet>>::get(&result.outlet).connect(&result);
+        <OUTLET as Outlet<ToolbarOutlet>>::get(&result.outlet).connect(&result);
+        <OUTLET as Outlet<StatusBarOutlet>>::get(&result.outlet).connect(&result);
 
         Ok(result)
     }
@@ -125,22 +106,115 @@ where
 
 impl<OUTLET> Widget<OUTLET> for MockWindowWidget<OUTLET>
 where
-    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>,
 {
     fn outlet(&self) -> &OUTLET {
         &self.outlet
     }
 }
 
+impl<OUTLET> MockWindowWidget<OUTLET>
+where
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>,
+    <OUTLET as Outlet<ChildrenOutlet>>::Children: LayoutChildren,
+{
+    /// Lays out the contents of the `ChildrenOutlet` as if the window had
+    /// been resized to `size` and returns the computed frames.
+    pub fn resize(&self, size: Size) -> Vec<Rect> {
+        self.flex_layout()
+            .arrange(size, <OUTLET as Outlet<ChildrenOutlet>>::get(&self.outlet))
+    }
+}
+
+impl<OUTLET> LayoutContainer for MockWindowWidget<OUTLET>
+where
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>,
+{
+    fn flex_layout(&self) -> FlexLayout {
+        self.flex_layout.get()
+    }
+
+    fn set_flex_layout(&self, layout: FlexLayout) {
+        self.flex_layout.set(layout);
+    }
+}
+
+impl<OUTLET> DialogParent for MockWindowWidget<OUTLET>
+where
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>,
+{
+    fn modal_block(&self) -> &ModalBlock {
+        &self.modal_block
+    }
+}
+
+impl<OUTLET> MockMenuHost for MockWindowWidget<OUTLET> where
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>
+{
+}
+
+impl<OUTLET> MockContainer for MockWindowWidget<OUTLET> where
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>
+{
+}
+
+impl<OUTLET> MockContainer<ToolbarOutlet> for MockWindowWidget<OUTLET> where
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>
+{
+}
+
+impl<OUTLET> MockContainer<StatusBarOutlet> for MockWindowWidget<OUTLET> where
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>
+{
+}
+
 impl<OUTLET> Window<OUTLET> for MockWindowWidget<OUTLET>
 where
-    OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>,
+    OUTLET: OutletHolder
+        + Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>,
     Self: Sized,
 {
     fn new(outlet: OUTLET) -> PlatingResult<Self>
     where
         <OUTLET as Outlet<MenuOutlet>>::Children: ChildOf<Self, MenuOutlet>,
         <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+        <OUTLET as Outlet<ToolbarOutlet>>::Children: ChildOf<Self, ToolbarOutlet>,
+        <OUTLET as Outlet<StatusBarOutlet>>::Children: ChildOf<Self, StatusBarOutlet>,
     {
         <Self as MockWindow<OUTLET>>::new(outlet)
     }
@@ -0,0 +1,87 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::fmt;
+
+/// Marker trait for everything a widget can hand to its event handlers.
+pub trait Event: fmt::Debug {}
+
+/// A boxed callback handling one specific [`Event`] type.
+pub type EventHandler<EVENT> = Box<dyn FnMut(&EVENT)>;
+
+/// The handlers registered for one specific [`Event`] type.
+///
+/// Widgets keep one of these per event they emit and call
+/// [`EventHandlers::emit`] whenever the event occurs.
+pub struct EventHandlers<EVENT>
+where
+    EVENT: Event,
+{
+    handlers: Vec<EventHandler<EVENT>>,
+}
+
+impl<EVENT> EventHandlers<EVENT>
+where
+    EVENT: Event,
+{
+    pub fn new() -> Self {
+        Self {
+            handlers: Vec::new(),
+        }
+    }
+
+    pub fn add<F>(&mut self, handler: F)
+    where
+        F: FnMut(&EVENT) + 'static,
+    {
+        self.handlers.push(Box::new(handler));
+    }
+
+    /// Calls all registered handlers in the order they were added.
+    pub fn emit(&mut self, event: &EVENT) {
+        for handler in self.handlers.iter_mut() {
+            handler(event);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+}
+
+impl<EVENT> Default for EventHandlers<EVENT>
+where
+    EVENT: Event,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<EVENT> fmt::Debug for EventHandlers<EVENT>
+where
+    EVENT: Event,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventHandlers")
+            .field("handlers", &self.handlers.len())
+            .finish()
+    }
+}
+
+/// Implemented by widgets for every [`Event`] they emit.
+pub trait Emits<EVENT>
+where
+    EVENT: Event,
+{
+    /// Registers a handler that is called every time `EVENT` occurs.
+    fn on<F>(&mut self, handler: F)
+    where
+        F: FnMut(&EVENT) + 'static;
+}
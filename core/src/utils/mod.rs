@@ -4,10 +4,13 @@
  */
 
 pub mod children;
+pub mod events;
 pub mod outlet;
 pub mod settings;
 
 mod serde;
 pub use self::serde::*;
 
-pub mod prelude {}
+pub mod prelude {
+    pub use super::events::Emits;
+}
@@ -15,6 +15,22 @@ impl OutletType for ChildrenOutlet {}
 pub struct MenuOutlet {}
 impl OutletType for MenuOutlet {}
 
+/// Outlet holding the items of a windows toolbar.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct ToolbarOutlet {}
+impl OutletType for ToolbarOutlet {}
+
+/// Outlet holding the widgets shown in a windows status bar.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct StatusBarOutlet {}
+impl OutletType for StatusBarOutlet {}
+
+/// Outlet holding the menu shown when the user requests a context menu on a
+/// widget.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct ContextMenuOutlet {}
+impl OutletType for ContextMenuOutlet {}
+
 pub trait Outlet<OUTLET>
 where
     OUTLET: OutletType,
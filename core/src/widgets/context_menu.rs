@@ -0,0 +1,169 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::data::Point;
+use crate::utils::children::ChildrenList;
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::{
+    ChildrenOutlet,
+    ContextMenuOutlet,
+    MenuOutlet,
+    Outlet,
+    OutletHolder,
+    StatusBarOutlet,
+    ToolbarOutlet,
+};
+use crate::widgets::Menu;
+use crate::PlatingResult;
+
+/// Wraps the outlet of any widget, adding a [`ContextMenuOutlet`].
+///
+/// The first [`ContextMenu`] in `context_menu` is shown when the user
+/// secondary clicks the widget or presses the context menu key while it is
+/// focused. All other outlets of `outlet` are passed through.
+#[derive(Debug, Default)]
+pub struct WithContextMenu<OUTLET, MENU>
+where
+    OUTLET: OutletHolder,
+    MENU: ChildrenList,
+{
+    pub outlet: OUTLET,
+    pub context_menu: MENU,
+}
+
+impl<OUTLET, MENU> OutletHolder for WithContextMenu<OUTLET, MENU>
+where
+    OUTLET: OutletHolder,
+    MENU: ChildrenList,
+{
+}
+
+impl<OUTLET, MENU> Outlet<ContextMenuOutlet> for WithContextMenu<OUTLET, MENU>
+where
+    OUTLET: OutletHolder,
+    MENU: ChildrenList,
+{
+    type Children = MENU;
+
+    fn get(&self) -> &Self::Children {
+        &self.context_menu
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.context_menu
+    }
+}
+
+impl<OUTLET, MENU> Outlet<ChildrenOutlet> for WithContextMenu<OUTLET, MENU>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    MENU: ChildrenList,
+{
+    type Children = <OUTLET as Outlet<ChildrenOutlet>>::Children;
+
+    fn get(&self) -> &Self::Children {
+        <OUTLET as Outlet<ChildrenOutlet>>::get(&self.outlet)
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        <OUTLET as Outlet<ChildrenOutlet>>::get_mut(&mut self.outlet)
+    }
+}
+
+impl<OUTLET, MENU> Outlet<MenuOutlet> for WithContextMenu<OUTLET, MENU>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>,
+    MENU: ChildrenList,
+{
+    type Children = <OUTLET as Outlet<MenuOutlet>>::Children;
+
+    fn get(&self) -> &Self::Children {
+        <OUTLET as Outlet<MenuOutlet>>::get(&self.outlet)
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        <OUTLET as Outlet<MenuOutlet>>::get_mut(&mut self.outlet)
+    }
+}
+
+impl<OUTLET, MENU> Outlet<ToolbarOutlet> for WithContextMenu<OUTLET, MENU>
+where
+    OUTLET: OutletHolder + Outlet<ToolbarOutlet>,
+    MENU: ChildrenList,
+{
+    type Children = <OUTLET as Outlet<ToolbarOutlet>>::Children;
+
+    fn get(&self) -> &Self::Children {
+        <OUTLET as Outlet<ToolbarOutlet>>::get(&self.outlet)
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        <OUTLET as Outlet<ToolbarOutlet>>::get_mut(&mut self.outlet)
+    }
+}
+
+impl<OUTLET, MENU> Outlet<StatusBarOutlet> for WithContextMenu<OUTLET, MENU>
+where
+    OUTLET: OutletHolder + Outlet<StatusBarOutlet>,
+    MENU: ChildrenList,
+{
+    type Children = <OUTLET as Outlet<StatusBarOutlet>>::Children;
+
+    fn get(&self) -> &Self::Children {
+        <OUTLET as Outlet<StatusBarOutlet>>::get(&self.outlet)
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        <OUTLET as Outlet<StatusBarOutlet>>::get_mut(&mut self.outlet)
+    }
+}
+
+/// How a context menu got requested.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ContextMenuTrigger {
+    /// Secondary (usually right) click on the widget.
+    SecondaryClick,
+    /// The platform context menu key or shortcut.
+    Keyboard,
+    /// Shown by the application via [`ContextMenu::popup`].
+    Programmatic,
+}
+
+/// Emitted when a [`ContextMenu`] gets shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContextMenuOpened {
+    /// Where the menu is shown, relative to the widget it belongs to.
+    pub position: Point,
+    pub trigger:  ContextMenuTrigger,
+}
+impl Event for ContextMenuOpened {}
+
+/// Emitted when the user chooses an item of an open [`ContextMenu`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContextMenuItemChosen {
+    /// Indices leading from the context menu to the chosen item, descending
+    /// into submenus.
+    pub path:     Vec<usize>,
+    pub label:    String,
+    /// Where the menu was opened.
+    pub position: Point,
+}
+impl Event for ContextMenuItemChosen {}
+
+/// A [`Menu`] that can be shown as a popup, either from the context menu
+/// outlet of a widget or on demand.
+pub trait ContextMenu<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Menu<OUTLET> + Emits<ContextMenuOpened> + Emits<ContextMenuItemChosen>,
+{
+    /// Shows the menu at `position`, relative to the widget it belongs to.
+    fn popup(&mut self, position: Point) -> PlatingResult<()>;
+
+    /// Hides the menu without choosing an item.
+    fn dismiss(&mut self) -> PlatingResult<()>;
+
+    fn is_open(&self) -> bool;
+}
@@ -0,0 +1,80 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::data::{CellValue, ColumnDefinition, SortOrder, TreePath};
+use crate::PlatingResult;
+
+/// Provides the rows displayed by a [`ListView`](crate::widgets::ListView)
+/// or [`TableView`](crate::widgets::TableView).
+///
+/// Rows are not owned by the widget. The widget only asks for the cells of the
+/// rows that are currently visible, so sources can be arbitrarily large.
+pub trait DataSource {
+    fn row_count(&self) -> usize;
+
+    /// The columns of this source. Lists only display the first one.
+    fn columns(&self) -> Vec<ColumnDefinition> {
+        vec![ColumnDefinition::default()]
+    }
+
+    fn cell_value(&self, row: usize, column: usize) -> CellValue;
+
+    /// Called when the user asks to sort by `column`.
+    ///
+    /// Returns `None` when the source does not support sorting by that
+    /// column, in which case the widget keeps its current sort state.
+    /// Otherwise returns, for every row after sorting, the index it had
+    /// before, so the widget can keep the selection on the same rows.
+    fn sort(&mut self, _column: usize, _order: SortOrder) -> PlatingResult<Option<Vec<usize>>> {
+        Ok(None)
+    }
+}
+
+impl<T> DataSource for Vec<T>
+where
+    T: Clone + Into<CellValue>,
+{
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn cell_value(&self, row: usize, column: usize) -> CellValue {
+        match (self.get(row), column) {
+            (Some(value), 0) => value.clone().into(),
+            _ => CellValue::Empty,
+        }
+    }
+}
+
+/// Provides the nodes displayed by a [`TreeView`](crate::widgets::TreeView).
+///
+/// Children are loaded lazily: the widget only asks for the children of a node
+/// once it gets expanded, so sources can e.g. read directories on demand.
+pub trait TreeDataSource {
+    /// The columns of this source. The first one holds the node label.
+    fn columns(&self) -> Vec<ColumnDefinition> {
+        vec![ColumnDefinition::default()]
+    }
+
+    /// Called right before the children of `parent` are queried for the first
+    /// time, or again after a reload.
+    ///
+    /// The root is loaded when the widget gets created.
+    fn load_children(&mut self, _parent: &TreePath) -> PlatingResult<()> {
+        Ok(())
+    }
+
+    /// Whether `node` can be expanded.
+    ///
+    /// Used to decide if an expander is shown without loading the children.
+    /// Defaults to asking for the child count, override it if that is costly.
+    fn has_children(&self, node: &TreePath) -> bool {
+        self.child_count(node) > 0
+    }
+
+    fn child_count(&self, parent: &TreePath) -> usize;
+
+    fn cell_value(&self, node: &TreePath, column: usize) -> CellValue;
+}
@@ -0,0 +1,261 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use crate::utils::children::ChildOf;
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+use crate::widgets::Widget;
+use crate::{PlatingError, PlatingResult};
+
+/// How a [`Dialog`] got closed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DialogResponse {
+    Accept,
+    Reject,
+    /// Application defined response, e.g. for a third button.
+    Custom(u32),
+}
+
+/// Whether a shown [`Dialog`] blocks input to its parent window.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Modality {
+    Modal,
+    Modeless,
+}
+
+/// Emitted when the user closes a [`Dialog`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct DialogClosed {
+    pub response: DialogResponse,
+}
+impl Event for DialogClosed {}
+
+#[derive(Debug)]
+struct DialogSlot<T> {
+    result:    Option<T>,
+    abandoned: bool,
+    waker:     Option<Waker>,
+}
+
+/// The pending result of a dialog.
+///
+/// Available synchronously via [`DialogHandle::wait`], which keeps the event
+/// loop running until the dialog got answered, or by awaiting the handle.
+pub struct DialogHandle<T> {
+    slot: Rc<RefCell<DialogSlot<T>>>,
+    event_loop: Option<Box<dyn FnMut() -> PlatingResult<()>>>,
+}
+
+/// Completes the [`DialogHandle`] it was created with.
+///
+/// Dropping it without calling [`DialogResolver::resolve`] fails the handle.
+#[derive(Debug)]
+pub struct DialogResolver<T> {
+    slot: Rc<RefCell<DialogSlot<T>>>,
+}
+
+impl<T> DialogHandle<T> {
+    /// Creates a pending handle and the resolver that completes it.
+    pub fn pending() -> (Self, DialogResolver<T>) {
+        let slot = Rc::new(RefCell::new(DialogSlot {
+            result:    None,
+            abandoned: false,
+            waker:     None,
+        }));
+        let handle = Self {
+            slot: slot.clone(),
+            event_loop: None,
+        };
+        (handle, DialogResolver { slot })
+    }
+
+    /// Creates a handle that is already answered with `result`.
+    pub fn resolved(result: T) -> Self {
+        let (handle, resolver) = Self::pending();
+        resolver.resolve(result);
+        handle
+    }
+
+    /// Sets the function [`DialogHandle::wait`] calls to process a single
+    /// iteration of the event loop.
+    pub fn with_event_loop<F>(mut self, event_loop: F) -> Self
+    where
+        F: FnMut() -> PlatingResult<()> + 'static,
+    {
+        self.event_loop = Some(Box::new(event_loop));
+        self
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.slot.borrow().result.is_some()
+    }
+
+    /// Takes the result, if the dialog got answered.
+    pub fn try_take(&mut self) -> Option<T> {
+        self.slot.borrow_mut().result.take()
+    }
+
+    /// Keeps the event loop running until the dialog got answered.
+    pub fn wait(mut self) -> PlatingResult<T> {
+        loop {
+            if let Some(result) = self.try_take() {
+                return Ok(result);
+            }
+            if self.slot.borrow().abandoned {
+                return Err(PlatingError::NoResult);
+            }
+            match self.event_loop.as_mut() {
+                Some(event_loop) => event_loop()?,
+                None => {
+                    return Err(PlatingError::state(
+                        "the dialog is not answered yet and there is no event loop to wait in",
+                    ))
+                },
+            }
+        }
+    }
+}
+
+impl<T> Future for DialogHandle<T> {
+    type Output = PlatingResult<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.borrow_mut();
+        if let Some(result) = slot.result.take() {
+            Poll::Ready(Ok(result))
+        } else if slot.abandoned {
+            Poll::Ready(Err(PlatingError::NoResult))
+        } else {
+            slot.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> fmt::Debug for DialogHandle<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DialogHandle")
+            .field("slot", &self.slot)
+            .field("event_loop", &self.event_loop.is_some())
+            .finish()
+    }
+}
+
+impl<T> DialogResolver<T> {
+    pub fn resolve(self, result: T) {
+        let waker = {
+            let mut slot = self.slot.borrow_mut();
+            slot.result = Some(result);
+            slot.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for DialogResolver<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut slot = self.slot.borrow_mut();
+            if slot.result.is_some() {
+                return;
+            }
+            slot.abandoned = true;
+            slot.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Counts the modal dialogs blocking input to a window.
+#[derive(Debug, Default, Clone)]
+pub struct ModalBlock {
+    depth: Rc<Cell<usize>>,
+}
+
+impl ModalBlock {
+    pub fn is_blocked(&self) -> bool {
+        self.depth.get() > 0
+    }
+
+    /// Blocks input until the returned guard is dropped.
+    pub fn block(&self) -> ModalGuard {
+        self.depth.set(self.depth.get() + 1);
+        ModalGuard {
+            depth: self.depth.clone(),
+        }
+    }
+}
+
+/// Keeps a window blocked while a modal dialog is shown.
+#[derive(Debug)]
+pub struct ModalGuard {
+    depth: Rc<Cell<usize>>,
+}
+
+impl Drop for ModalGuard {
+    fn drop(&mut self) {
+        self.depth.set(self.depth.get() - 1);
+    }
+}
+
+/// Implemented by widgets a modal [`Dialog`] can be shown on top of.
+pub trait DialogParent {
+    fn modal_block(&self) -> &ModalBlock;
+
+    /// Whether the user can interact with the widget, i.e. no modal dialog
+    /// is shown on top of it.
+    fn accepts_input(&self) -> bool {
+        !self.modal_block().is_blocked()
+    }
+}
+
+/// A custom dialog window.
+///
+/// Built from the same outlets as a [`Window`](`crate::widgets::Window`) and
+/// hidden until shown via [`Dialog::show_modal`] or [`Dialog::show`].
+pub trait Dialog<OUTLET>
+where
+    OUTLET: Outlet<MenuOutlet> + Outlet<ChildrenOutlet> + OutletHolder,
+    Self: Sized + Widget<OUTLET> + DialogParent + Emits<DialogClosed>,
+{
+    fn new(outlet: OUTLET, title: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<MenuOutlet>>::Children: ChildOf<Self, MenuOutlet>,
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+
+    fn title(&self) -> String;
+    fn set_title(&mut self, title: &str) -> PlatingResult<()>;
+
+    /// Shows the dialog and blocks input to `parent` until it is closed.
+    fn show_modal<PARENT>(
+        &mut self,
+        parent: &PARENT,
+    ) -> PlatingResult<DialogHandle<DialogResponse>>
+    where
+        PARENT: DialogParent;
+
+    /// Shows the dialog without blocking any other window.
+    fn show(&mut self) -> PlatingResult<DialogHandle<DialogResponse>>;
+
+    /// Closes the dialog, answering its handle with `response`.
+    fn close(&mut self, response: DialogResponse) -> PlatingResult<()>;
+
+    /// How the dialog is shown, or `None` while it is hidden.
+    fn modality(&self) -> Option<Modality>;
+}
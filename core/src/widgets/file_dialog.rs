@@ -0,0 +1,122 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::path::{Path, PathBuf};
+
+use crate::widgets::DialogHandle;
+use crate::PlatingResult;
+
+/// Restricts the files a [`FileDialog`] offers, e.g. "Images" for `*.png` and
+/// `*.jpg`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FileFilter {
+    pub name:     String,
+    /// Glob patterns like `*.png`. `*` matches every file.
+    pub patterns: Vec<String>,
+}
+
+impl FileFilter {
+    pub fn new(name: &str, patterns: &[&str]) -> Self {
+        Self {
+            name:     String::from(name),
+            patterns: patterns
+                .iter()
+                .map(|pattern| String::from(*pattern))
+                .collect(),
+        }
+    }
+
+    /// Whether the file name of `path` matches one of the patterns.
+    ///
+    /// Only patterns of the form `*`, `*.ext` and exact file names are
+    /// understood. Extensions are compared case insensitive.
+    pub fn matches(&self, path: &Path) -> bool {
+        let file_name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(file_name) => file_name,
+            None => return false,
+        };
+
+        self.patterns.iter().any(|pattern| {
+            if pattern == "*" {
+                true
+            } else if let Some(extension) = pattern.strip_prefix("*.") {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+            } else {
+                pattern == file_name
+            }
+        })
+    }
+}
+
+/// What a [`FileDialog`] shows initially and what it allows to pick.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct FileDialogOptions {
+    pub title: String,
+    /// Folder shown initially. Save dialogs also take the proposed file name
+    /// from it.
+    pub default_path: Option<PathBuf>,
+    /// Offered filters, the first one is active initially. No filters allow
+    /// every file.
+    pub filters: Vec<FileFilter>,
+    /// Allows picking more than one file or folder. Ignored by save dialogs.
+    pub multi_select: bool,
+}
+
+impl FileDialogOptions {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: String::from(title),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_default_path<P>(mut self, default_path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.default_path = Some(default_path.into());
+        self
+    }
+
+    pub fn with_filter(mut self, filter: FileFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    pub fn with_multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Whether `path` is allowed by at least one filter.
+    pub fn allows(&self, path: &Path) -> bool {
+        self.filters.is_empty() || self.filters.iter().any(|filter| filter.matches(path))
+    }
+}
+
+/// The native open file, save file and choose folder dialogs.
+///
+/// All of them are application modal. The open and choose dialogs answer with
+/// an empty list if the user cancelled.
+pub trait FileDialog {
+    fn new() -> PlatingResult<Self>
+    where
+        Self: Sized;
+
+    fn open_file(&self, options: &FileDialogOptions) -> PlatingResult<DialogHandle<Vec<PathBuf>>>;
+
+    /// Answers `None` if the user cancelled.
+    fn save_file(
+        &self,
+        options: &FileDialogOptions,
+    ) -> PlatingResult<DialogHandle<Option<PathBuf>>>;
+
+    fn choose_folder(
+        &self,
+        options: &FileDialogOptions,
+    ) -> PlatingResult<DialogHandle<Vec<PathBuf>>>;
+}
@@ -0,0 +1,53 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::layout::GridLayout;
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::Widget;
+use crate::PlatingResult;
+
+#[derive(Debug, Default)]
+pub struct GridOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    pub children: CHILDREN,
+}
+
+impl<CHILDREN> OutletHolder for GridOutlet<CHILDREN> where CHILDREN: ChildrenList {}
+
+impl<CHILDREN> Outlet<ChildrenOutlet> for GridOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    type Children = CHILDREN;
+
+    fn get(&self) -> &Self::Children {
+        &self.children
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.children
+    }
+}
+
+/// Places its children into the cells of a [`GridLayout`].
+///
+/// Children choose their cell with the
+/// [`GridItem`](crate::layout::GridItem) settings, see
+/// [`LayoutChild::apply_grid_settings`](crate::layout::LayoutChild::apply_grid_settings).
+pub trait Grid<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized + Widget<OUTLET>,
+{
+    fn new(outlet: OUTLET, layout: GridLayout) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+
+    fn grid_layout(&self) -> &GridLayout;
+    fn set_grid_layout(&mut self, layout: GridLayout) -> PlatingResult<()>;
+}
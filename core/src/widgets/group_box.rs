@@ -0,0 +1,48 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::Widget;
+use crate::PlatingResult;
+
+#[derive(Debug, Default)]
+pub struct GroupBoxOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    pub children: CHILDREN,
+}
+
+impl<CHILDREN> OutletHolder for GroupBoxOutlet<CHILDREN> where CHILDREN: ChildrenList {}
+
+impl<CHILDREN> Outlet<ChildrenOutlet> for GroupBoxOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    type Children = CHILDREN;
+
+    fn get(&self) -> &Self::Children {
+        &self.children
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.children
+    }
+}
+
+/// A titled frame around its children.
+pub trait GroupBox<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized + Widget<OUTLET>,
+{
+    fn new(outlet: OUTLET, title: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+
+    fn title(&self) -> String;
+    fn set_title(&mut self, title: &str) -> PlatingResult<()>;
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::ops::Range;
+
+use crate::data::{ColumnDefinition, Selection, SelectionMode, SortOrder};
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::OutletHolder;
+use crate::widgets::{DataSource, Widget};
+use crate::PlatingResult;
+
+#[derive(Debug, Default)]
+pub struct ListViewOutlet {}
+impl OutletHolder for ListViewOutlet {}
+
+#[derive(Debug, Default)]
+pub struct TableViewOutlet {}
+impl OutletHolder for TableViewOutlet {}
+
+/// Emitted when the user changes the selected rows.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SelectionChanged {
+    pub selection: Selection,
+}
+impl Event for SelectionChanged {}
+
+/// Emitted when the user activates a row, e.g. by double clicking it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RowActivated {
+    pub row: usize,
+}
+impl Event for RowActivated {}
+
+/// Emitted when the user sorts a table by clicking a column header.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SortChanged {
+    pub column: usize,
+    pub order:  SortOrder,
+}
+impl Event for SortChanged {}
+
+/// A virtualized list displaying the first column of a [`DataSource`].
+///
+/// Only the visible rows are materialized.
+/// Call [`ListView::reload`] after changing the source.
+pub trait ListView<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+    Self: Sized + Widget<OUTLET> + Emits<SelectionChanged> + Emits<RowActivated>,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self>;
+
+    fn source(&self) -> &SOURCE;
+    fn source_mut(&mut self) -> &mut SOURCE;
+
+    /// Re-reads the row count and the visible rows from the source.
+    fn reload(&mut self) -> PlatingResult<()>;
+
+    fn visible_rows(&self) -> Range<usize>;
+    fn scroll_to_row(&mut self, row: usize) -> PlatingResult<()>;
+
+    fn selection_mode(&self) -> SelectionMode;
+    fn set_selection_mode(&mut self, mode: SelectionMode) -> PlatingResult<()>;
+
+    fn selection(&self) -> &Selection;
+    /// Changes the selection without emitting [`SelectionChanged`].
+    fn set_selection(&mut self, selection: Selection) -> PlatingResult<()>;
+}
+
+/// A virtualized, sortable table displaying all columns of a [`DataSource`].
+///
+/// Only the visible rows are materialized.
+/// Call [`TableView::reload`] after changing the source.
+pub trait TableView<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: DataSource,
+    Self:
+        Sized + Widget<OUTLET> + Emits<SelectionChanged> + Emits<RowActivated> + Emits<SortChanged>,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self>;
+
+    fn source(&self) -> &SOURCE;
+    fn source_mut(&mut self) -> &mut SOURCE;
+
+    /// Re-reads the columns, the row count and the visible rows from the
+    /// source.
+    fn reload(&mut self) -> PlatingResult<()>;
+
+    fn columns(&self) -> &[ColumnDefinition];
+
+    fn visible_rows(&self) -> Range<usize>;
+    fn scroll_to_row(&mut self, row: usize) -> PlatingResult<()>;
+
+    fn selection_mode(&self) -> SelectionMode;
+    fn set_selection_mode(&mut self, mode: SelectionMode) -> PlatingResult<()>;
+
+    fn selection(&self) -> &Selection;
+    /// Changes the selection without emitting [`SelectionChanged`].
+    fn set_selection(&mut self, selection: Selection) -> PlatingResult<()>;
+
+    /// The column and order the table is currently sorted by.
+    fn sorting(&self) -> Option<(usize, SortOrder)>;
+    /// Asks the source to sort by `column`. The selection stays on the same
+    /// rows. Does not emit [`SortChanged`].
+    fn sort_by(&mut self, column: usize, order: SortOrder) -> PlatingResult<()>;
+}
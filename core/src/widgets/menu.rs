@@ -0,0 +1,114 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::Widget;
+use crate::PlatingResult;
+
+/// Outlet of a [`Menu`]. Holds its items, separators and submenus.
+#[derive(Debug, Default)]
+pub struct MenuItemsOutlet<ITEMS>
+where
+    ITEMS: ChildrenList,
+{
+    pub items: ITEMS,
+}
+
+impl<ITEMS> OutletHolder for MenuItemsOutlet<ITEMS> where ITEMS: ChildrenList {}
+
+impl<ITEMS> Outlet<ChildrenOutlet> for MenuItemsOutlet<ITEMS>
+where
+    ITEMS: ChildrenList,
+{
+    type Children = ITEMS;
+
+    fn get(&self) -> &Self::Children {
+        &self.items
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.items
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MenuItemOutlet {}
+impl OutletHolder for MenuItemOutlet {}
+
+#[derive(Debug, Default)]
+pub struct CheckMenuItemOutlet {}
+impl OutletHolder for CheckMenuItemOutlet {}
+
+#[derive(Debug, Default)]
+pub struct MenuSeparatorOutlet {}
+impl OutletHolder for MenuSeparatorOutlet {}
+
+/// Emitted when the user chooses a [`MenuItem`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MenuItemActivated {
+    pub label: String,
+}
+impl Event for MenuItemActivated {}
+
+/// Emitted when the user toggles a [`CheckMenuItem`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CheckedChanged {
+    pub checked: bool,
+}
+impl Event for CheckedChanged {}
+
+/// A labeled menu.
+///
+/// Used in the menu outlet of a window, or nested within another menu as a
+/// submenu.
+pub trait Menu<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized + Widget<OUTLET>,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+
+    fn label(&self) -> String;
+    fn set_label(&mut self, label: &str) -> PlatingResult<()>;
+}
+
+pub trait MenuItem<OUTLET>
+where
+    OUTLET: OutletHolder,
+    Self: Sized + Widget<OUTLET> + Emits<MenuItemActivated>,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>;
+
+    fn label(&self) -> String;
+    fn set_label(&mut self, label: &str) -> PlatingResult<()>;
+}
+
+/// A menu item with a checkmark the user can toggle.
+pub trait CheckMenuItem<OUTLET>
+where
+    OUTLET: OutletHolder,
+    Self: Sized + Widget<OUTLET> + Emits<CheckedChanged>,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>;
+
+    fn label(&self) -> String;
+    fn set_label(&mut self, label: &str) -> PlatingResult<()>;
+
+    fn checked(&self) -> bool;
+    /// Changes the checkmark without emitting [`CheckedChanged`].
+    fn set_checked(&mut self, checked: bool) -> PlatingResult<()>;
+}
+
+pub trait MenuSeparator<OUTLET>
+where
+    OUTLET: OutletHolder,
+    Self: Sized + Widget<OUTLET>,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>;
+}
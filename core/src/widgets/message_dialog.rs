@@ -0,0 +1,78 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::widgets::DialogHandle;
+use crate::PlatingResult;
+
+/// The icon shown by a standard dialog.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MessageKind {
+    #[default]
+    Info,
+    Warning,
+    Error,
+    Question,
+}
+
+/// Content of a message or confirm dialog.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct MessageOptions {
+    pub title: String,
+    pub text:  String,
+    pub kind:  MessageKind,
+}
+
+impl MessageOptions {
+    pub fn new(title: &str, text: &str) -> Self {
+        Self {
+            title: String::from(title),
+            text:  String::from(text),
+            kind:  MessageKind::default(),
+        }
+    }
+
+    pub fn with_kind(mut self, kind: MessageKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+/// Content of a dialog asking the user for a line of text.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct PromptOptions {
+    pub title: String,
+    pub text: String,
+    /// The text the input field starts out with.
+    pub default_value: String,
+}
+
+impl PromptOptions {
+    pub fn new(title: &str, text: &str) -> Self {
+        Self {
+            title: String::from(title),
+            text: String::from(text),
+            default_value: String::new(),
+        }
+    }
+
+    pub fn with_default_value(mut self, default_value: &str) -> Self {
+        self.default_value = String::from(default_value);
+        self
+    }
+}
+
+/// The message, confirm and prompt dialogs every platform ships with.
+///
+/// All of them are application modal.
+pub trait StandardDialogs {
+    /// Shows a message with a single button to dismiss it.
+    fn message(&self, options: &MessageOptions) -> PlatingResult<DialogHandle<()>>;
+
+    /// Asks a yes/no question. Answers `true` if the user confirmed.
+    fn confirm(&self, options: &MessageOptions) -> PlatingResult<DialogHandle<bool>>;
+
+    /// Asks for a line of text. Answers `None` if the user cancelled.
+    fn prompt(&self, options: &PromptOptions) -> PlatingResult<DialogHandle<Option<String>>>;
+}
@@ -3,10 +3,64 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+use plating_systems_macros::Outlets;
+
 use crate::utils::children::{ChildOf, ChildrenList};
-use crate::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+use crate::utils::outlet::{
+    ChildrenOutlet,
+    MenuOutlet,
+    Outlet,
+    OutletHolder,
+    StatusBarOutlet,
+    ToolbarOutlet,
+};
 use crate::PlatingResult;
 
+mod context_menu;
+pub use context_menu::*;
+
+mod data_source;
+pub use data_source::*;
+
+mod dialog;
+pub use dialog::*;
+
+mod file_dialog;
+pub use file_dialog::*;
+
+mod grid;
+pub use grid::*;
+
+mod group_box;
+pub use group_box::*;
+
+mod list_view;
+pub use list_view::*;
+
+mod menu;
+pub use menu::*;
+
+mod message_dialog;
+pub use message_dialog::*;
+
+mod notifications;
+pub use notifications::*;
+
+mod scroll_view;
+pub use scroll_view::*;
+
+mod split_view;
+pub use split_view::*;
+
+mod tabs;
+pub use tabs::*;
+
+mod tray_icon;
+pub use tray_icon::*;
+
+mod tree_view;
+pub use tree_view::*;
+
 
 pub trait Widget<OUTLET>
 where
@@ -15,65 +69,41 @@ where
     fn outlet(&self) -> &OUTLET;
 }
 
-#[derive(Debug, Default)]
-pub struct WindowOutlet<CHILDREN, MENU>
+/// Outlets of a [`Window`]. The toolbar and status bar are empty by default.
+#[derive(Debug, Default, Outlets)]
+#[outlets(path = crate::utils::outlet)]
+pub struct WindowOutlet<CHILDREN, MENU, TOOLBAR = (), STATUSBAR = ()>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
+    #[outlet(ChildrenOutlet)]
     pub children: CHILDREN,
-    pub menu:     MENU,
-}
-
-impl<CHILDREN, MENU> OutletHolder for WindowOutlet<CHILDREN, MENU>
-where
-    CHILDREN: ChildrenList,
-    MENU: ChildrenList,
-{
-}
-
-impl<CHILDREN, MENU> Outlet<MenuOutlet> for WindowOutlet<CHILDREN, MENU>
-where
-    CHILDREN: ChildrenList,
-    MENU: ChildrenList,
-{
-    type Children = MENU;
-
-    fn get(&self) -> &Self::Children {
-        &self.menu
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.menu
-    }
-}
-
-
-impl<CHILDREN, MENU> Outlet<ChildrenOutlet> for WindowOutlet<CHILDREN, MENU>
-where
-    CHILDREN: ChildrenList,
-    MENU: ChildrenList,
-{
-    type Children = CHILDREN;
-
-    fn get(&self) -> &Self::Children {
-        &self.children
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.children
-    }
+    #[outlet(MenuOutlet)]
+    pub menu: MENU,
+    #[outlet(ToolbarOutlet)]
+    pub toolbar: TOOLBAR,
+    #[outlet(StatusBarOutlet)]
+    pub status_bar: STATUSBAR,
 }
 
 pub trait Window<OUTLET>
 where
-    OUTLET: Outlet<MenuOutlet> + Outlet<ChildrenOutlet> + OutletHolder,
+    OUTLET: Outlet<MenuOutlet>
+        + Outlet<ChildrenOutlet>
+        + Outlet<ToolbarOutlet>
+        + Outlet<StatusBarOutlet>
+        + OutletHolder,
     Self: Sized + Widget<OUTLET>,
 {
     fn new(outlet: OUTLET) -> PlatingResult<Self>
     where
         <OUTLET as Outlet<MenuOutlet>>::Children: ChildOf<Self, MenuOutlet>,
-        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>,
+        <OUTLET as Outlet<ToolbarOutlet>>::Children: ChildOf<Self, ToolbarOutlet>,
+        <OUTLET as Outlet<StatusBarOutlet>>::Children: ChildOf<Self, StatusBarOutlet>;
 }
 
 
@@ -104,5 +134,30 @@ mod outlets {
 }
 
 pub mod prelude {
-    pub use super::{Button, Window};
+    pub use super::{
+        Button,
+        CheckMenuItem,
+        ContextMenu,
+        DataSource,
+        Dialog,
+        DialogParent,
+        FileDialog,
+        Grid,
+        GroupBox,
+        ListView,
+        Menu,
+        MenuItem,
+        MenuSeparator,
+        Notifications,
+        ScrollView,
+        SplitView,
+        StandardDialogs,
+        Tab,
+        TableView,
+        Tabs,
+        TrayIcon,
+        TreeDataSource,
+        TreeView,
+        Window,
+    };
 }
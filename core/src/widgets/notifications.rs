@@ -0,0 +1,74 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::data::Icon;
+use crate::utils::events::Event;
+use crate::PlatingResult;
+
+/// A desktop notification.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Notification {
+    pub title:   String,
+    pub body:    String,
+    pub icon:    Option<Icon>,
+    /// Labels of the buttons shown with the notification.
+    pub actions: Vec<String>,
+}
+
+impl Notification {
+    pub fn new(title: &str, body: &str) -> Self {
+        Self {
+            title: String::from(title),
+            body: String::from(body),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_action(mut self, label: &str) -> Self {
+        self.actions.push(String::from(label));
+        self
+    }
+}
+
+/// Identifies a notification shown via [`Notifications::notify`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NotificationId(pub u64);
+
+/// How the user reacted to a notification.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum NotificationResponse {
+    /// The notification itself got clicked.
+    Clicked,
+    /// One of the [actions](`Notification::actions`) got chosen.
+    Action { index: usize, label: String },
+    /// The user closed the notification.
+    Dismissed,
+}
+impl Event for NotificationResponse {}
+
+/// Shows desktop notifications.
+pub trait Notifications {
+    fn new() -> PlatingResult<Self>
+    where
+        Self: Sized;
+
+    /// Shows `notification`. `on_response` gets called once the user reacts
+    /// to it.
+    fn notify<F>(
+        &self,
+        notification: &Notification,
+        on_response: F,
+    ) -> PlatingResult<NotificationId>
+    where
+        F: FnMut(&NotificationResponse) + 'static;
+
+    /// Removes a notification the user did not react to yet.
+    fn withdraw(&self, id: NotificationId) -> PlatingResult<()>;
+}
@@ -0,0 +1,67 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::data::{Point, Size};
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::Widget;
+use crate::PlatingResult;
+
+#[derive(Debug, Default)]
+pub struct ScrollViewOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    pub children: CHILDREN,
+}
+
+impl<CHILDREN> OutletHolder for ScrollViewOutlet<CHILDREN> where CHILDREN: ChildrenList {}
+
+impl<CHILDREN> Outlet<ChildrenOutlet> for ScrollViewOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    type Children = CHILDREN;
+
+    fn get(&self) -> &Self::Children {
+        &self.children
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.children
+    }
+}
+
+/// Emitted when the user scrolls the content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scrolled {
+    pub offset: Point,
+}
+impl Event for Scrolled {}
+
+/// A viewport onto content that can be larger than the widget itself.
+///
+/// Offsets are the position of the visible area within the content and are
+/// clamped to the scrollable range.
+pub trait ScrollView<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized + Widget<OUTLET> + Emits<Scrolled>,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+
+    fn content_size(&self) -> Size;
+    fn viewport_size(&self) -> Size;
+
+    fn scroll_offset(&self) -> Point;
+    /// Scrolls to `offset` without emitting [`Scrolled`].
+    fn scroll_to(&mut self, offset: Point) -> PlatingResult<()>;
+    /// Scrolls just enough to make the child at `index` fully visible.
+    /// Does not emit [`Scrolled`].
+    fn scroll_to_child(&mut self, index: usize) -> PlatingResult<()>;
+}
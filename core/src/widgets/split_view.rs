@@ -0,0 +1,66 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::data::Orientation;
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::Widget;
+use crate::PlatingResult;
+
+#[derive(Debug, Default)]
+pub struct SplitViewOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    pub children: CHILDREN,
+}
+
+impl<CHILDREN> OutletHolder for SplitViewOutlet<CHILDREN> where CHILDREN: ChildrenList {}
+
+impl<CHILDREN> Outlet<ChildrenOutlet> for SplitViewOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    type Children = CHILDREN;
+
+    fn get(&self) -> &Self::Children {
+        &self.children
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.children
+    }
+}
+
+/// Emitted when the user dragged a divider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DividerMoved {
+    pub index:    usize,
+    pub position: f64,
+}
+impl Event for DividerMoved {}
+
+/// Places its children next to each other, separated by draggable dividers.
+///
+/// There is one divider less than there are children. Divider positions are
+/// in logical pixels along the [`Orientation`] of the split view.
+pub trait SplitView<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized + Widget<OUTLET> + Emits<DividerMoved>,
+{
+    fn new(outlet: OUTLET, orientation: Orientation) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+
+    fn orientation(&self) -> Orientation;
+
+    fn divider_positions(&self) -> &[f64];
+    /// Moves a divider without emitting [`DividerMoved`].
+    ///
+    /// The position is clamped between the neighbouring dividers.
+    fn set_divider_position(&mut self, index: usize, position: f64) -> PlatingResult<()>;
+}
@@ -0,0 +1,119 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::{ChildrenOutlet, Outlet, OutletHolder};
+use crate::widgets::Widget;
+use crate::PlatingResult;
+
+/// Outlet of a [`Tabs`] widget. Every child is one [`Tab`].
+#[derive(Debug, Default)]
+pub struct TabsOutlet<TABS>
+where
+    TABS: ChildrenList,
+{
+    pub tabs: TABS,
+}
+
+impl<TABS> OutletHolder for TabsOutlet<TABS> where TABS: ChildrenList {}
+
+impl<TABS> Outlet<ChildrenOutlet> for TabsOutlet<TABS>
+where
+    TABS: ChildrenList,
+{
+    type Children = TABS;
+
+    fn get(&self) -> &Self::Children {
+        &self.tabs
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.tabs
+    }
+}
+
+/// Outlet of a single [`Tab`], holding the content shown while it is selected.
+#[derive(Debug, Default)]
+pub struct TabOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    pub children: CHILDREN,
+}
+
+impl<CHILDREN> OutletHolder for TabOutlet<CHILDREN> where CHILDREN: ChildrenList {}
+
+impl<CHILDREN> Outlet<ChildrenOutlet> for TabOutlet<CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    type Children = CHILDREN;
+
+    fn get(&self) -> &Self::Children {
+        &self.children
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.children
+    }
+}
+
+/// Emitted when the user selects another tab.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TabSelectionChanged {
+    pub selected: Option<usize>,
+    pub previous: Option<usize>,
+}
+impl Event for TabSelectionChanged {}
+
+/// Emitted when the user closes a closable tab.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TabClosed {
+    pub index: usize,
+}
+impl Event for TabClosed {}
+
+/// A tab bar showing the content of the selected [`Tab`].
+///
+/// Tabs are addressed by their position in the outlet. Closed tabs keep their
+/// index and can be brought back with [`Tabs::reopen_tab`].
+pub trait Tabs<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized + Widget<OUTLET> + Emits<TabSelectionChanged> + Emits<TabClosed>,
+{
+    fn new(outlet: OUTLET) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+
+    /// Number of tabs, including closed ones.
+    fn tab_count(&self) -> usize;
+    fn tab_label(&self, index: usize) -> Option<String>;
+    fn is_tab_open(&self, index: usize) -> bool;
+    fn reopen_tab(&mut self, index: usize) -> PlatingResult<()>;
+
+    fn selected_tab(&self) -> Option<usize>;
+    /// Selects an open tab without emitting [`TabSelectionChanged`].
+    fn select_tab(&mut self, index: usize) -> PlatingResult<()>;
+}
+
+/// A single page of a [`Tabs`] widget.
+pub trait Tab<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<ChildrenOutlet>,
+    Self: Sized + Widget<OUTLET>,
+{
+    fn new(outlet: OUTLET, label: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<ChildrenOutlet>>::Children: ChildOf<Self, ChildrenOutlet>;
+
+    fn label(&self) -> String;
+    fn set_label(&mut self, label: &str) -> PlatingResult<()>;
+
+    /// Whether the tab shows a close button.
+    fn closable(&self) -> bool;
+    fn set_closable(&mut self, closable: bool) -> PlatingResult<()>;
+}
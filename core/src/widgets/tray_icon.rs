@@ -0,0 +1,65 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::data::Icon;
+use crate::utils::children::{ChildOf, ChildrenList};
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::{MenuOutlet, Outlet, OutletHolder};
+use crate::widgets::Widget;
+use crate::PlatingResult;
+
+/// Outlet of a [`TrayIcon`], holding the menu shown when it is clicked.
+#[derive(Debug, Default)]
+pub struct TrayIconOutlet<MENU>
+where
+    MENU: ChildrenList,
+{
+    pub menu: MENU,
+}
+
+impl<MENU> OutletHolder for TrayIconOutlet<MENU> where MENU: ChildrenList {}
+
+impl<MENU> Outlet<MenuOutlet> for TrayIconOutlet<MENU>
+where
+    MENU: ChildrenList,
+{
+    type Children = MENU;
+
+    fn get(&self) -> &Self::Children {
+        &self.menu
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Children {
+        &mut self.menu
+    }
+}
+
+/// Emitted when the user activates a [`TrayIcon`], usually by clicking it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TrayIconActivated {}
+impl Event for TrayIconActivated {}
+
+/// An icon in the system tray, notification area or menu bar extras.
+///
+/// Lives independent of any window, so applications can keep running in the
+/// background with the tray icon as their only user interface.
+pub trait TrayIcon<OUTLET>
+where
+    OUTLET: OutletHolder + Outlet<MenuOutlet>,
+    Self: Sized + Widget<OUTLET> + Emits<TrayIconActivated>,
+{
+    fn new(outlet: OUTLET, icon: Icon, tooltip: &str) -> PlatingResult<Self>
+    where
+        <OUTLET as Outlet<MenuOutlet>>::Children: ChildOf<Self, MenuOutlet>;
+
+    fn icon(&self) -> &Icon;
+    fn set_icon(&mut self, icon: Icon) -> PlatingResult<()>;
+
+    fn tooltip(&self) -> String;
+    fn set_tooltip(&mut self, tooltip: &str) -> PlatingResult<()>;
+
+    fn visible(&self) -> bool;
+    fn set_visible(&mut self, visible: bool) -> PlatingResult<()>;
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::data::{ColumnDefinition, SelectionMode, TreePath};
+use crate::utils::events::{Emits, Event};
+use crate::utils::outlet::OutletHolder;
+use crate::widgets::{TreeDataSource, Widget};
+use crate::PlatingResult;
+
+#[derive(Debug, Default)]
+pub struct TreeViewOutlet {}
+impl OutletHolder for TreeViewOutlet {}
+
+/// Emitted when the user changes the selected nodes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NodeSelectionChanged {
+    pub selection: Vec<TreePath>,
+}
+impl Event for NodeSelectionChanged {}
+
+/// Emitted when the user activates a node, e.g. by double clicking it.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NodeActivated {
+    pub node: TreePath,
+}
+impl Event for NodeActivated {}
+
+/// Emitted after the user expanded a node and its children got loaded.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NodeExpanded {
+    pub node: TreePath,
+}
+impl Event for NodeExpanded {}
+
+/// Emitted when the user collapses a node.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NodeCollapsed {
+    pub node: TreePath,
+}
+impl Event for NodeCollapsed {}
+
+/// A hierarchical view displaying the nodes of a [`TreeDataSource`].
+///
+/// Children are only loaded once their parent gets expanded.
+/// Call [`TreeView::reload`] after changing the source.
+pub trait TreeView<OUTLET, SOURCE>
+where
+    OUTLET: OutletHolder,
+    SOURCE: TreeDataSource,
+    Self: Sized
+        + Widget<OUTLET>
+        + Emits<NodeSelectionChanged>
+        + Emits<NodeActivated>
+        + Emits<NodeExpanded>
+        + Emits<NodeCollapsed>,
+{
+    fn new(outlet: OUTLET, source: SOURCE) -> PlatingResult<Self>;
+
+    fn source(&self) -> &SOURCE;
+    fn source_mut(&mut self) -> &mut SOURCE;
+
+    /// Drops all loaded children, collapses all nodes and re-reads the top
+    /// level nodes from the source.
+    fn reload(&mut self) -> PlatingResult<()>;
+
+    fn columns(&self) -> &[ColumnDefinition];
+
+    fn is_expanded(&self, node: &TreePath) -> bool;
+    /// Loads the children of `node` if needed and expands it.
+    /// Does not emit [`NodeExpanded`].
+    fn expand(&mut self, node: &TreePath) -> PlatingResult<()>;
+    /// Collapses `node`. Loaded children are kept.
+    /// Does not emit [`NodeCollapsed`].
+    fn collapse(&mut self, node: &TreePath) -> PlatingResult<()>;
+
+    fn selection_mode(&self) -> SelectionMode;
+    fn set_selection_mode(&mut self, mode: SelectionMode) -> PlatingResult<()>;
+
+    /// The selected nodes, in tree order.
+    fn selection(&self) -> &[TreePath];
+    /// Changes the selection without emitting [`NodeSelectionChanged`].
+    fn set_selection(&mut self, selection: Vec<TreePath>) -> PlatingResult<()>;
+}
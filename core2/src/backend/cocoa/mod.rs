@@ -25,6 +25,7 @@ pub mod foundation {
     pub use cocoa::foundation::{
         NSAutoreleasePool,
         NSComparisonResult,
+        NSInteger,
         NSPoint,
         NSRect,
         NSRunLoop,
@@ -41,10 +42,13 @@ pub mod appkit {
         NSApplicationActivateIgnoringOtherApps,
         NSApplicationActivationPolicyRegular,
         NSBackingStoreBuffered,
+        NSColor,
         NSMenu,
         NSMenuItem,
         NSRunningApplication,
+        NSScreen,
         NSWindow,
         NSWindowStyleMask,
+        NSWindowTitleVisibility,
     };
 }
@@ -58,6 +58,13 @@ impl From<NSString> for id {
     }
 }
 
+impl From<id> for NSString {
+    #[inline]
+    fn from(obj: id) -> Self {
+        NSString(obj)
+    }
+}
+
 impl PartialEq<str> for NSString {
     #[inline]
     fn eq(&self, other: &str) -> bool {
@@ -0,0 +1,63 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::utils::data::Id;
+
+/// Everything that can go wrong when building or changing widgets.
+#[derive(Debug, thiserror::Error)]
+pub enum PlatingError {
+    /// A property got rejected before reaching the backend, e.g. a negative
+    /// size.
+    #[error("invalid {property}: {reason}")]
+    InvalidValue {
+        property: &'static str,
+        reason:   String,
+    },
+
+    /// The backend cannot apply or read this property on this widget.
+    #[error("{property} is not supported by {widget}")]
+    Unsupported {
+        property: &'static str,
+        widget:   &'static str,
+    },
+
+    /// The property was never applied, and the backend has no value to
+    /// report instead.
+    #[error("{property} has not been set")]
+    NotSet { property: &'static str },
+
+    /// The widget is already connected to a parent.
+    #[error("widget is already connected")]
+    AlreadyConnected,
+
+    /// Another widget already uses this [`Id`].
+    #[error("duplicate id '{id}': already used by a {used_by}")]
+    DuplicateId { id: Id, used_by: &'static str },
+
+    /// There is no (registered) widget with this [`Id`].
+    #[error("no widget with id '{0}'")]
+    UnknownId(Id),
+
+    /// The widget with this [`Id`] is not of the requested type.
+    #[error("widget with id '{id}' is a {found}, not a {expected}")]
+    WrongType {
+        id: Id,
+        expected: &'static str,
+        found: &'static str,
+    },
+
+    /// The native toolkit reported an error.
+    #[error("backend failure: {0}")]
+    Backend(#[from] anyhow::Error),
+}
+
+impl PlatingError {
+    pub fn invalid<S: Into<String>>(property: &'static str, reason: S) -> Self {
+        PlatingError::InvalidValue {
+            property,
+            reason: reason.into(),
+        }
+    }
+}
@@ -49,7 +49,10 @@ pub mod utils;
 /// Lib version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-pub type PlatingResult<T> = Result<T, anyhow::Error>;
+mod error;
+pub use error::PlatingError;
+
+pub type PlatingResult<T> = Result<T, PlatingError>;
 
 pub mod prelude {
     pub use crate::backend::prelude::*;
@@ -72,8 +75,10 @@ mod tests {
             <DefaultSystem as System>::Window::new(
                 (),
                 WindowOutlet {
-                    children: (),
-                    menu:     (),
+                    children:   (),
+                    menu:       (),
+                    toolbar:    (),
+                    status_bar: (),
                 },
             )?;
         let _app = <DefaultSystem as System>::App::new_with_state(
@@ -128,21 +133,3 @@ impl<T> ButtonType<T> for WinUI3Button<T> {
         Self {t}
     }
 }
-
-pub trait TabsType<T> {
-    fn new(t: T) -> Self;
-}
-pub struct TabsNotImplemented {
-}
-impl<T> TabsType<T> for TabsNotImplemented {
-    fn new(_t: T) -> Self { unimplemented!() }
-}
-#[cfg(target_os = "macos")]
-pub struct CocoaTabs<T> {
-    _t: T,
-}
-impl<T> TabsType<T> for CocoaTabs<T> {
-    fn new(t: T) -> Self {
-        Self {_t: t}
-    }
-}
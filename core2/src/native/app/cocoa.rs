@@ -8,6 +8,8 @@
 use std::ffi::c_void;
 use std::pin::Pin;
 
+use plating_systems_macros::Outlets;
+
 use crate::backend::cocoa::appkit::{
     NSApp,
     NSApplication,
@@ -27,49 +29,23 @@ use crate::backend::cocoa::core_foundation::runloop::{
 };
 use crate::native::data::cocoa::CocoaLabel;
 use crate::native::{Native, NativeBuilder, NativeChildOf, NativeWidget};
-use crate::utils::{ChildrenList, ChildrenOutlet, Outlet, OutletHolder, Property, SettingsList};
+use crate::utils::{ChildrenList, ChildrenOutlet, Property, SettingsList};
 use crate::widget::cocoa::Cocoa;
 use crate::widget::AppOutlet;
 use crate::PlatingResult;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Outlets)]
+#[outlets(path = crate::utils, from = AppOutlet<CHILDREN>)]
 pub struct CocoaAppOutlet<CHILDREN>
 where
     CHILDREN: ChildrenList,
 {
+    #[outlet(ChildrenOutlet)]
     children: CHILDREN,
 }
 
-impl<CHILDREN> OutletHolder for CocoaAppOutlet<CHILDREN> where CHILDREN: ChildrenList {}
-
 impl<CHILDREN> Native<Cocoa> for CocoaAppOutlet<CHILDREN> where CHILDREN: ChildrenList {}
 
-impl<CHILDREN> From<AppOutlet<CHILDREN>> for CocoaAppOutlet<CHILDREN>
-where
-    CHILDREN: ChildrenList,
-{
-    fn from(app: AppOutlet<CHILDREN>) -> Self {
-        Self {
-            children: app.children,
-        }
-    }
-}
-
-impl<CHILDREN> Outlet<ChildrenOutlet> for CocoaAppOutlet<CHILDREN>
-where
-    CHILDREN: ChildrenList,
-{
-    type Children = CHILDREN;
-
-    fn get(&self) -> &Self::Children {
-        &self.children
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.children
-    }
-}
-
 #[derive(Debug)]
 struct CocoaAppInternal<STATE, CHILDREN>
 where
@@ -163,7 +139,7 @@ where
             self.data.native_handle.finishLaunching();
         }
 
-        self.data.outlet.children.connect(&*self);
+        self.data.outlet.children.connect(&*self)?;
 
         let app_pointer = unsafe {
             let m = Pin::as_mut(&mut self.data);
@@ -248,6 +224,7 @@ where
     where
         SL: SettingsList + Property<STATE, Self, Cocoa> + Native<Cocoa>,
     {
+        settings.validate()?;
         settings.provide(self)
     }
 }
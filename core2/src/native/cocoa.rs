@@ -5,10 +5,27 @@
 
 #![cfg(target_os = "macos")]
 
+use std::cell::{Cell, RefCell};
 use std::pin::Pin;
 
 use crate::backend::cocoa::StrongPtr;
+use crate::native::data::cocoa::{
+    CocoaBackgroundColor,
+    CocoaEnabled,
+    CocoaId,
+    CocoaMaxSize,
+    CocoaMinSize,
+    CocoaPosition,
+    CocoaSize,
+    CocoaTooltip,
+    CocoaVisible,
+};
+use crate::native::NativeWidget;
+use crate::utils::data::Id;
+use crate::utils::registry::{Registry, Reservation};
 use crate::utils::{ChildrenList, OutletHolder};
+use crate::widget::cocoa::Cocoa;
+use crate::PlatingResult;
 
 pub(crate) struct CocoaInternal<STATE, CHILDREN, OUTLET>
 where
@@ -20,6 +37,17 @@ where
 
     pub native_handle: StrongPtr,
 
+    /// Keeps the id reserved in the [`Registry`].
+    pub id: RefCell<Option<Reservation>>,
+    /// The widgets own [`Visible`](crate::widget::properties::Visible) setting.
+    pub visible: Cell<bool>,
+    /// The widgets own [`Enabled`](crate::widget::properties::Enabled) setting.
+    pub enabled: Cell<bool>,
+    /// Whether all ancestors are enabled.
+    pub parent_enabled: Cell<bool>,
+    /// Whether the widget is connected to its parent.
+    pub connected: Cell<bool>,
+
     _unpinned: std::marker::PhantomPinned,
 
     _phantom: std::marker::PhantomData<CHILDREN>,
@@ -39,10 +67,42 @@ where
             state,
             outlet,
 
+            id: RefCell::new(None),
+            visible: Cell::new(true),
+            enabled: Cell::new(true),
+            parent_enabled: Cell::new(true),
+            connected: Cell::new(false),
+
             _unpinned: Default::default(),
             _phantom: Default::default(),
         })
     }
+
+    /// A widget is only enabled if it and all its ancestors are enabled.
+    /// What disabling does depends on the widget, e.g. disabled windows ignore
+    /// mouse events but still get keyboard input.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.get() && self.parent_enabled.get()
+    }
+
+    /// Reserves `id` for `WIDGET`, releasing the previous one.
+    pub fn set_id<WIDGET>(&self, id: &Id) -> PlatingResult<()> {
+        if self.id().as_ref() == Some(id) {
+            return Ok(());
+        }
+
+        let reservation = Registry::current().reserve::<WIDGET>(id)?;
+        // dropping the previous reservation releases its id
+        self.id.replace(Some(reservation));
+        Ok(())
+    }
+
+    pub fn id(&self) -> Option<Id> {
+        self.id
+            .borrow()
+            .as_ref()
+            .map(|reservation| reservation.id().clone())
+    }
 }
 
 impl<STATE, CHILDREN, OUTLET> std::fmt::Debug for CocoaInternal<STATE, CHILDREN, OUTLET>
@@ -58,6 +118,10 @@ where
             .field("state", &self.state)
             .field("outlet", &self.outlet)
             .field("native_handle", &self.native_handle.deref())
+            .field("id", &self.id.borrow())
+            .field("visible", &self.visible.get())
+            .field("enabled", &self.enabled.get())
+            .field("parent_enabled", &self.parent_enabled.get())
             .finish()
     }
 }
@@ -65,3 +129,38 @@ where
 pub use super::app::cocoa::{CocoaApp, CocoaAppOutlet, CocoaAppWidget};
 pub use super::menu::cocoa::{CocoaMenu, CocoaMenuOutlet, CocoaMenuWidget};
 pub use super::window::cocoa::{CocoaWindow, CocoaWindowOutlet, CocoaWindowWidget};
+
+/// Settings every cocoa widget supports.
+pub trait CocoaCommon<STATE>
+where
+    Self: NativeWidget<STATE, Backend = Cocoa> + Sized,
+{
+    fn set_visible(&mut self, visible: &CocoaVisible) -> PlatingResult<()>;
+    fn set_enabled(&mut self, enabled: &CocoaEnabled) -> PlatingResult<()>;
+    fn set_tooltip(&mut self, tooltip: &CocoaTooltip) -> PlatingResult<()>;
+    fn set_id(&mut self, id: &CocoaId) -> PlatingResult<()>;
+
+    fn visible(&self) -> PlatingResult<CocoaVisible>;
+    fn enabled(&self) -> PlatingResult<CocoaEnabled>;
+    fn tooltip(&self) -> PlatingResult<CocoaTooltip>;
+    fn is_enabled(&self) -> bool;
+}
+
+/// Settings of cocoa widgets taking up space on the screen.
+pub trait CocoaGeometry<STATE>
+where
+    Self: NativeWidget<STATE, Backend = Cocoa> + Sized,
+{
+    fn set_size(&mut self, size: &CocoaSize) -> PlatingResult<()>;
+    fn set_position(&mut self, position: &CocoaPosition) -> PlatingResult<()>;
+    fn set_min_size(&mut self, min_size: &CocoaMinSize) -> PlatingResult<()>;
+    fn set_max_size(&mut self, max_size: &CocoaMaxSize) -> PlatingResult<()>;
+    fn set_background_color(&mut self, color: &CocoaBackgroundColor) -> PlatingResult<()>;
+
+    fn size(&self) -> PlatingResult<CocoaSize>;
+    fn position(&self) -> PlatingResult<CocoaPosition>;
+    fn min_size(&self) -> PlatingResult<CocoaMinSize>;
+    fn max_size(&self) -> PlatingResult<CocoaMaxSize>;
+    /// Always a fixed color, even if it got set using a role.
+    fn background_color(&self) -> PlatingResult<CocoaBackgroundColor>;
+}
@@ -3,27 +3,73 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+#![cfg(target_os = "macos")]
+
+use plating_systems_macros::Native;
+
 use crate::backend::cocoa::foundation::NSString;
-use crate::native::cocoa::{CocoaApp, CocoaAppWidget, CocoaWindow, CocoaWindowWidget};
-use crate::native::{Native, ToNative};
-use crate::utils::{ChildrenList, Deserialize, Property, Serialize};
+use crate::native::cocoa::{
+    CocoaApp,
+    CocoaAppWidget,
+    CocoaCommon,
+    CocoaGeometry,
+    CocoaMenu,
+    CocoaMenuWidget,
+    CocoaWindow,
+    CocoaWindowWidget,
+};
+use crate::utils::{ChildrenList, Deserialize, Serialize};
 use crate::widget::cocoa::Cocoa;
-use crate::widget::properties::Label;
-use crate::PlatingResult;
+use crate::widget::properties::{
+    AlwaysOnTop,
+    BackgroundColor,
+    Border,
+    Closable,
+    Enabled,
+    Font,
+    Id,
+    Label,
+    MaxSize,
+    MinSize,
+    Minimizable,
+    Position,
+    Resizable,
+    Size,
+    TitleBarHidden,
+    Tooltip,
+    Visible,
+};
 
 #[repr(transparent)]
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize)]
-pub struct CocoaLabel(NSString);
-
-
-
-impl Native<Cocoa> for CocoaLabel {}
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = Label, setter = set_label)]
+#[native(to_native = CocoaLabel((&self.0).into()))]
+#[native(widget(impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    where CHILDREN: ChildrenList, MENU: ChildrenList,
+        TOOLBAR: ChildrenList, STATUSBAR: ChildrenList))]
+#[native(widget(impl<STATE, CHILDREN> CocoaAppWidget<STATE, CHILDREN> where CHILDREN: ChildrenList))]
+pub struct CocoaLabel(pub NSString);
 
-impl ToNative<Cocoa> for Label {
-    type Result = CocoaLabel;
+impl From<NSString> for CocoaLabel {
+    fn from(label: NSString) -> Self {
+        CocoaLabel(label)
+    }
+}
 
-    fn to_native(&self) -> Self::Result {
-        CocoaLabel((&self.0).into())
+impl From<CocoaLabel> for Label {
+    fn from(label: CocoaLabel) -> Self {
+        Label(String::from(&label.0))
     }
 }
 
@@ -34,22 +80,230 @@ impl std::fmt::Display for CocoaLabel {
     }
 }
 
-impl<STATE, CHILDREN, MENU> Property<STATE, CocoaWindowWidget<STATE, CHILDREN, MENU>, Cocoa>
-    for CocoaLabel
-where
-    CHILDREN: ChildrenList,
-    MENU: ChildrenList,
-{
-    fn provide(&self, target: &mut CocoaWindowWidget<STATE, CHILDREN, MENU>) -> PlatingResult<()> {
-        target.set_label(self)
-    }
-}
+/// A [`Size`] as understood by cocoa. Percentages get resolved against the
+/// parent when applied, which is the screen for windows.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Native)]
+#[native(crate = crate, backend = Cocoa, property = Size, setter = set_size)]
+#[native(validate = self.0.validate())]
+#[native(widget(impl<STATE, T> T where T: CocoaGeometry<STATE>))]
+pub struct CocoaSize(pub Size);
 
-impl<STATE, CHILDREN> Property<STATE, CocoaAppWidget<STATE, CHILDREN>, Cocoa> for CocoaLabel
-where
-    CHILDREN: ChildrenList,
-{
-    fn provide(&self, target: &mut CocoaAppWidget<STATE, CHILDREN>) -> PlatingResult<()> {
-        target.set_label(self)
-    }
-}
+/// A [`Position`] as understood by cocoa. Converted to cocoas bottom left
+/// origin when applied.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Native)]
+#[native(crate = crate, backend = Cocoa, property = Position, setter = set_position)]
+#[native(widget(impl<STATE, T> T where T: CocoaGeometry<STATE>))]
+pub struct CocoaPosition(pub Position);
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Native)]
+#[native(crate = crate, backend = Cocoa, property = MinSize, setter = set_min_size)]
+#[native(validate = (self.0).0.validate())]
+#[native(widget(impl<STATE, T> T where T: CocoaGeometry<STATE>))]
+pub struct CocoaMinSize(pub MinSize);
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Native)]
+#[native(crate = crate, backend = Cocoa, property = MaxSize, setter = set_max_size)]
+#[native(validate = (self.0).0.validate())]
+#[native(widget(impl<STATE, T> T where T: CocoaGeometry<STATE>))]
+pub struct CocoaMaxSize(pub MaxSize);
+
+/// A [`BackgroundColor`] as understood by cocoa. Roles get resolved against
+/// the current theme when applied.
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = BackgroundColor, setter = set_background_color)]
+#[native(widget(impl<STATE, T> T where T: CocoaGeometry<STATE>))]
+pub struct CocoaBackgroundColor(pub BackgroundColor);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Native)]
+#[native(crate = crate, backend = Cocoa, property = Font, setter = set_font)]
+#[native(validate = self.0.validate())]
+#[native(widget(impl<STATE, CHILDREN> CocoaMenuWidget<STATE, CHILDREN>
+    where CHILDREN: ChildrenList, CocoaMenuWidget<STATE, CHILDREN>: CocoaMenu<STATE, CHILDREN>))]
+pub struct CocoaFont(pub Font);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = Visible, setter = set_visible)]
+#[native(to_native = CocoaVisible(self.0))]
+#[native(widget(impl<STATE, T> T where T: CocoaCommon<STATE>))]
+pub struct CocoaVisible(pub bool);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = Enabled, setter = set_enabled)]
+#[native(to_native = CocoaEnabled(self.0))]
+#[native(widget(impl<STATE, T> T where T: CocoaCommon<STATE>))]
+pub struct CocoaEnabled(pub bool);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = Tooltip, setter = set_tooltip)]
+#[native(to_native = CocoaTooltip((&self.0).into()))]
+#[native(widget(impl<STATE, T> T where T: CocoaCommon<STATE>))]
+pub struct CocoaTooltip(pub NSString);
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, Native)]
+#[native(crate = crate, backend = Cocoa, property = Id, setter = set_id)]
+#[native(validate = self.0.validate())]
+#[native(widget(impl<STATE, T> T where T: CocoaCommon<STATE>))]
+pub struct CocoaId(pub Id);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = Border, setter = set_border)]
+#[native(widget(impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    where CHILDREN: ChildrenList, MENU: ChildrenList,
+        TOOLBAR: ChildrenList, STATUSBAR: ChildrenList))]
+pub struct CocoaBorder(pub Border);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = Resizable, setter = set_resizable)]
+#[native(widget(impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    where CHILDREN: ChildrenList, MENU: ChildrenList,
+        TOOLBAR: ChildrenList, STATUSBAR: ChildrenList))]
+pub struct CocoaResizable(pub Resizable);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = Closable, setter = set_closable)]
+#[native(widget(impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    where CHILDREN: ChildrenList, MENU: ChildrenList,
+        TOOLBAR: ChildrenList, STATUSBAR: ChildrenList))]
+pub struct CocoaClosable(pub Closable);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = Minimizable, setter = set_minimizable)]
+#[native(widget(impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    where CHILDREN: ChildrenList, MENU: ChildrenList,
+        TOOLBAR: ChildrenList, STATUSBAR: ChildrenList))]
+pub struct CocoaMinimizable(pub Minimizable);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = TitleBarHidden, setter = set_title_bar_hidden)]
+#[native(widget(impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    where CHILDREN: ChildrenList, MENU: ChildrenList,
+        TOOLBAR: ChildrenList, STATUSBAR: ChildrenList))]
+pub struct CocoaTitleBarHidden(pub TitleBarHidden);
+
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Native
+)]
+#[native(crate = crate, backend = Cocoa, property = AlwaysOnTop, setter = set_always_on_top)]
+#[native(widget(impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    where CHILDREN: ChildrenList, MENU: ChildrenList,
+        TOOLBAR: ChildrenList, STATUSBAR: ChildrenList))]
+pub struct CocoaAlwaysOnTop(pub AlwaysOnTop);
@@ -0,0 +1,55 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+//! The mock backend uses the cross-platform properties as they are.
+
+use crate::native::{Native, ToNative};
+use crate::widget::mock::Mock;
+use crate::widget::properties::{
+    AlwaysOnTop,
+    BackgroundColor,
+    Border,
+    Closable,
+    Label,
+    MaxSize,
+    MinSize,
+    Minimizable,
+    Position,
+    Resizable,
+    Size,
+    TitleBarHidden,
+};
+
+//TODO: macro
+macro_rules! mock_native {
+    ($($property:ty),*) => {
+        $(
+            impl Native<Mock> for $property {}
+
+            impl ToNative<Mock> for $property {
+                type Result = $property;
+
+                fn to_native(&self) -> Self::Result {
+                    self.clone()
+                }
+            }
+        )*
+    };
+}
+
+mock_native!(
+    Label,
+    Size,
+    Position,
+    MinSize,
+    MaxSize,
+    BackgroundColor,
+    Border,
+    Resizable,
+    Closable,
+    Minimizable,
+    TitleBarHidden,
+    AlwaysOnTop
+);
@@ -4,3 +4,4 @@
  */
 
 pub mod cocoa;
+pub mod mock;
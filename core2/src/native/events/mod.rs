@@ -3,6 +3,7 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+use crate::utils::theme::Palette;
 use crate::utils::{Deserialize, Serialize};
 
 pub trait Respondable
@@ -60,7 +61,8 @@ pub enum SystemEvent {
     Tick,
     DisplayChange,
     LanguageChange,
-    ThemeChange,
+    /// The [`Theme`](crate::utils::theme::Theme) switched to a new palette.
+    ThemeChange(Palette),
     SettingChange,
 }
 
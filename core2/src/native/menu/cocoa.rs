@@ -5,62 +5,47 @@
 
 #![cfg(target_os = "macos")]
 
+use std::any::type_name;
 use std::pin::Pin;
 
+use plating_systems_macros::Outlets;
+
 use crate::backend::cocoa::appkit::NSMenu;
-use crate::backend::cocoa::base::nil;
+use crate::backend::cocoa::base::{id, nil};
+use crate::backend::cocoa::foundation::NSString;
 use crate::backend::cocoa::StrongPtr;
-use crate::native::cocoa::CocoaInternal;
-use crate::native::data::cocoa::CocoaLabel;
+use crate::native::cocoa::{CocoaCommon, CocoaInternal};
+use crate::native::data::cocoa::{
+    CocoaEnabled,
+    CocoaFont,
+    CocoaId,
+    CocoaLabel,
+    CocoaTooltip,
+    CocoaVisible,
+};
 use crate::native::{Native, NativeBuilder, NativeChildOf, NativeWidget};
-use crate::utils::{ChildrenList, ChildrenOutlet, Outlet, OutletHolder, Property, SettingsList};
+use crate::utils::data::Id;
+use crate::utils::registry::Identifiable;
+use crate::utils::{ChildrenList, ChildrenOutlet, InheritEnabled, Property, SettingsList};
 use crate::widget::cocoa::Cocoa;
 use crate::widget::MenuOutlet;
-use crate::PlatingResult;
+use crate::{PlatingError, PlatingResult};
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Outlets)]
+#[outlets(path = crate::utils, from = MenuOutlet<CHILDREN>)]
 pub struct CocoaMenuOutlet<CHILDREN>
 where
     CHILDREN: ChildrenList,
 {
+    #[outlet(ChildrenOutlet)]
     children: CHILDREN,
 }
 
-impl<CHILDREN> OutletHolder for CocoaMenuOutlet<CHILDREN> where CHILDREN: ChildrenList {}
-
 //TODO: derive
 //#derive(Native)
 //#[cocoa]
 impl<CHILDREN> Native<Cocoa> for CocoaMenuOutlet<CHILDREN> where CHILDREN: ChildrenList {}
 
-//TODO: automatically
-impl<CHILDREN> Outlet<ChildrenOutlet> for CocoaMenuOutlet<CHILDREN>
-where
-    CHILDREN: ChildrenList,
-{
-    type Children = CHILDREN;
-
-    fn get(&self) -> &Self::Children {
-        &self.children
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.children
-    }
-}
-
-//TODO: Cocoa macro
-impl<CHILDREN> From<MenuOutlet<CHILDREN>> for CocoaMenuOutlet<CHILDREN>
-where
-    CHILDREN: ChildrenList,
-{
-    fn from(menu_outlet: MenuOutlet<CHILDREN>) -> Self {
-        Self {
-            children: menu_outlet.children,
-        }
-    }
-}
-
 #[derive(Debug)]
 pub struct CocoaMenuWidget<STATE, CHILDREN>
 where
@@ -89,18 +74,118 @@ where
     Self: NativeWidget<STATE, Backend = Cocoa> + Sized,
 {
     fn set_label(&mut self, label: &CocoaLabel) -> PlatingResult<()>;
+    fn set_font(&mut self, font: &CocoaFont) -> PlatingResult<()>;
+
+    fn label(&self) -> PlatingResult<CocoaLabel>;
+    fn font(&self) -> PlatingResult<CocoaFont>;
 }
 
 impl<STATE, CHILDREN> CocoaMenu<STATE, CHILDREN> for CocoaMenuWidget<STATE, CHILDREN>
 where
     CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
 {
-    fn set_label(&mut self, _label: &CocoaLabel) -> PlatingResult<()> {
-        //todo:
+    fn set_label(&mut self, label: &CocoaLabel) -> PlatingResult<()> {
+        use objc::*;
+
+        let title: id = label.0.clone().into();
+        unsafe {
+            let _: () = msg_send![*self.data.native_handle, setTitle: title];
+        }
         Ok(())
     }
+
+    fn set_font(&mut self, _font: &CocoaFont) -> PlatingResult<()> {
+        //todo: NSFont lookup, falling back through the families
+        Ok(())
+    }
+
+    fn label(&self) -> PlatingResult<CocoaLabel> {
+        use objc::*;
+
+        let title: id = unsafe { msg_send![*self.data.native_handle, title] };
+        Ok(NSString::from(title).into())
+    }
+
+    fn font(&self) -> PlatingResult<CocoaFont> {
+        Err(PlatingError::Unsupported {
+            property: "Font",
+            widget:   type_name::<Self>(),
+        })
+    }
+}
+
+
+impl<STATE, CHILDREN> CocoaCommon<STATE> for CocoaMenuWidget<STATE, CHILDREN>
+where
+    CHILDREN: ChildrenList + InheritEnabled + NativeChildOf<Self, STATE, Cocoa>,
+{
+    fn set_visible(&mut self, _visible: &CocoaVisible) -> PlatingResult<()> {
+        //todo: hide the NSMenuItem holding this menu
+        Err(PlatingError::Unsupported {
+            property: "Visible",
+            widget:   type_name::<Self>(),
+        })
+    }
+
+    fn set_enabled(&mut self, _enabled: &CocoaEnabled) -> PlatingResult<()> {
+        //todo: disable the NSMenuItem holding this menu
+        Err(PlatingError::Unsupported {
+            property: "Enabled",
+            widget:   type_name::<Self>(),
+        })
+    }
+
+    fn set_tooltip(&mut self, _tooltip: &CocoaTooltip) -> PlatingResult<()> {
+        //todo: NSMenuItem::setToolTip
+        Err(PlatingError::Unsupported {
+            property: "Tooltip",
+            widget:   type_name::<Self>(),
+        })
+    }
+
+    fn visible(&self) -> PlatingResult<CocoaVisible> {
+        Ok(CocoaVisible(self.data.visible.get()))
+    }
+
+    fn enabled(&self) -> PlatingResult<CocoaEnabled> {
+        Ok(CocoaEnabled(self.data.enabled.get()))
+    }
+
+    fn tooltip(&self) -> PlatingResult<CocoaTooltip> {
+        Err(PlatingError::Unsupported {
+            property: "Tooltip",
+            widget:   type_name::<Self>(),
+        })
+    }
+
+    fn set_id(&mut self, id: &CocoaId) -> PlatingResult<()> {
+        self.data.set_id::<Self>(&id.0)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.data.is_enabled()
+    }
 }
 
+impl<STATE, CHILDREN> InheritEnabled for CocoaMenuWidget<STATE, CHILDREN>
+where
+    CHILDREN: ChildrenList + InheritEnabled,
+{
+    fn parent_enabled(&self, enabled: bool) {
+        self.data.parent_enabled.set(enabled);
+        let enabled = self.data.is_enabled();
+        self.data.outlet.children.parent_enabled(enabled);
+    }
+}
+
+impl<STATE, CHILDREN> Identifiable for CocoaMenuWidget<STATE, CHILDREN>
+where
+    CHILDREN: ChildrenList,
+{
+    fn id(&self) -> Option<Id> {
+        self.data.id()
+    }
+}
 
 impl<STATE, CHILDREN> NativeBuilder<STATE> for CocoaMenuWidget<STATE, CHILDREN>
 where
@@ -117,12 +202,7 @@ where
         CHILDREN: NativeChildOf<Self, STATE, Cocoa>,
         SL: SettingsList + Property<STATE, Self, Cocoa> + Native<Cocoa>,
     {
-        let menu = unsafe {
-            let id = StrongPtr::new(NSMenu::alloc(nil));
-            //todo
-            //id.initWithTitle_();
-            id
-        };
+        let menu = unsafe { StrongPtr::new(NSMenu::new(nil)) };
 
         let mut result = CocoaMenuWidget {
             data: CocoaInternal::new(menu, state, outlet),
@@ -146,6 +226,7 @@ where
     where
         SL: SettingsList + Property<STATE, Self, Cocoa> + Native<Cocoa>,
     {
+        settings.validate()?;
         settings.provide(self)
     }
 }
@@ -0,0 +1,6 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+pub use super::window::mock::MockWindowWidget;
@@ -68,6 +68,7 @@ where
 }
 
 pub mod cocoa;
+pub mod mock;
 
 pub mod prelude {
     //nothing to prelude from this stage as this level is not cross-platform
@@ -7,14 +7,17 @@ use super::Native;
 use crate::native::NativeWidget;
 use crate::prelude::Backend;
 use crate::utils::ChildOf;
+use crate::PlatingResult;
 
 pub trait NativeChildOf<T, TState, BACKEND>: Sized
 where
     BACKEND: Backend,
     T: NativeWidget<TState, Backend = BACKEND>,
 {
+    /// Called once the parent is built. The child only gets connected
+    /// when the parent is connected itself.
     fn setup(&self, parent: &T);
-    fn connect(&self, parent: &T);
+    fn connect(&self, parent: &T) -> PlatingResult<()>;
     fn disconnect(&self);
 }
 
@@ -24,12 +27,10 @@ where
     Y: NativeWidget<YState, Backend = BACKEND>,
     BACKEND: Backend,
 {
-    fn setup(&self, parent: &Y) {
-        <Self as ChildOf<Y>>::connect(self, parent);
-    }
+    fn setup(&self, _parent: &Y) {}
 
-    fn connect(&self, parent: &Y) {
-        <Self as ChildOf<Y>>::connect(self, parent);
+    fn connect(&self, parent: &Y) -> PlatingResult<()> {
+        <Self as ChildOf<Y>>::connect(self, parent)
     }
 
     fn disconnect(&self) {
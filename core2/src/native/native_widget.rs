@@ -5,7 +5,9 @@
 
 use crate::native::Native;
 use crate::prelude::Backend;
-use crate::utils::{OutletHolder, Property, SettingsList};
+#[cfg(feature = "apply_possible")]
+use crate::utils::{ApplyReport, PossibleSettings};
+use crate::utils::{OutletHolder, Property, ProvideChanged, Queryable, SettingsList};
 use crate::PlatingResult;
 
 pub trait NativeBuilder<STATE>
@@ -50,6 +52,43 @@ where
     fn apply<SL>(&mut self, settings: &SL) -> PlatingResult<()>
     where
         SL: SettingsList + Property<STATE, Self, Self::Backend> + Native<Self::Backend>;
+
+    /// Applies only the entries of `settings` which differ from `previous`,
+    /// e.g. the settings used to build or last update the widget.
+    ///
+    /// Returns how many settings got applied.
+    fn apply_changed<SL>(&mut self, settings: &SL, previous: &SL) -> PlatingResult<usize>
+    where
+        SL: ProvideChanged<STATE, Self, Self::Backend> + Native<Self::Backend>,
+    {
+        settings.validate()?;
+        settings.provide_changed(previous, self)
+    }
+
+    /// Queries the current value of a property from the backend, e.g.
+    /// `window.get::<Label>()`.
+    ///
+    /// Fails if the backend cannot read the property back.
+    fn get<P>(&self) -> PlatingResult<P>
+    where
+        P: Queryable<STATE, Self, Self::Backend>,
+    {
+        P::query(self)
+    }
+
+    /// Applies every setting this widget understands and skips the others,
+    /// so cross-platform code can pass settings not every backend supports.
+    ///
+    /// The returned report lists the skipped settings.
+    #[cfg(feature = "apply_possible")]
+    fn apply_possible<SL>(&mut self, settings: &SL) -> PlatingResult<ApplyReport>
+    where
+        SL: PossibleSettings<STATE, Self, Self::Backend>,
+    {
+        let mut report = ApplyReport::default();
+        settings.provide_possible(self, &mut report)?;
+        Ok(report)
+    }
 }
 
 
@@ -7,147 +7,647 @@
 
 use std::pin::Pin;
 
-use crate::backend::cocoa::appkit::{NSBackingStoreBuffered, NSWindow, NSWindowStyleMask};
-use crate::backend::cocoa::base::{nil, NO};
-use crate::backend::cocoa::foundation::{NSPoint, NSRect, NSSize};
+use plating_systems_macros::Outlets;
+
+use crate::backend::cocoa::appkit::{
+    NSBackingStoreBuffered,
+    NSColor,
+    NSScreen,
+    NSWindow,
+    NSWindowStyleMask,
+    NSWindowTitleVisibility,
+};
+use crate::backend::cocoa::base::{id, nil, NO, YES};
+use crate::backend::cocoa::foundation::{NSInteger, NSPoint, NSRect, NSSize, NSString};
 use crate::backend::cocoa::StrongPtr;
 use crate::backend::AsBackend;
-use crate::native::cocoa::{CocoaAppWidget, CocoaInternal};
-use crate::native::data::cocoa::CocoaLabel;
+use crate::native::cocoa::{CocoaAppWidget, CocoaCommon, CocoaGeometry, CocoaInternal};
+use crate::native::data::cocoa::{
+    CocoaAlwaysOnTop,
+    CocoaBackgroundColor,
+    CocoaBorder,
+    CocoaClosable,
+    CocoaEnabled,
+    CocoaId,
+    CocoaLabel,
+    CocoaMaxSize,
+    CocoaMinSize,
+    CocoaMinimizable,
+    CocoaPosition,
+    CocoaResizable,
+    CocoaSize,
+    CocoaTitleBarHidden,
+    CocoaTooltip,
+    CocoaVisible,
+};
+use crate::native::events::SystemEvent;
 use crate::native::{Native, NativeBuilder, NativeChildOf, NativeWidget};
+use crate::utils::data::{Color, Id, Length};
+use crate::utils::registry::Identifiable;
+use crate::utils::theme::{ColorValue, Subscription, Theme};
 use crate::utils::{
     ChildOf,
     ChildrenList,
     ChildrenOutlet,
+    InheritEnabled,
+    InheritFrame,
     MenuOutlet,
-    Outlet,
-    OutletHolder,
     Property,
     SettingsList,
+    StatusBarOutlet,
+    ToolbarOutlet,
 };
 use crate::widget::cocoa::Cocoa;
+use crate::widget::properties::{
+    AlwaysOnTop,
+    BackgroundColor,
+    Border,
+    Closable,
+    MaxSize,
+    MinSize,
+    Minimizable,
+    Position,
+    Resizable,
+    Size,
+    TitleBarHidden,
+};
 use crate::widget::WindowOutlet;
-use crate::PlatingResult;
+use crate::{PlatingError, PlatingResult};
 
-#[derive(Debug, Default, Clone, Hash)]
-pub struct CocoaWindowOutlet<CHILDREN, MENU>
+#[derive(Debug, Default, Clone, Hash, Outlets)]
+#[outlets(path = crate::utils, from = WindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>)]
+pub struct CocoaWindowOutlet<CHILDREN, MENU, TOOLBAR = (), STATUSBAR = ()>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
-    pub children: CHILDREN,
-    pub menu:     MENU,
+    #[outlet(ChildrenOutlet)]
+    pub children:   CHILDREN,
+    #[outlet(MenuOutlet)]
+    pub menu:       MENU,
+    #[outlet(ToolbarOutlet)]
+    pub toolbar:    TOOLBAR,
+    #[outlet(StatusBarOutlet)]
+    pub status_bar: STATUSBAR,
 }
 
 
-impl<CHILDREN, MENU> OutletHolder for CocoaWindowOutlet<CHILDREN, MENU>
+impl<CHILDREN, MENU, TOOLBAR, STATUSBAR> Native<Cocoa>
+    for CocoaWindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 { }
 
-impl<CHILDREN, MENU> Native<Cocoa> for CocoaWindowOutlet<CHILDREN, MENU>
+
+#[derive(Debug)]
+pub struct CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR = (), STATUSBAR = ()>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
-{ }
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
+{
+    data: Pin<
+        Box<CocoaInternal<STATE, CHILDREN, CocoaWindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>>>,
+    >,
+    /// Follows the [`Theme`] while the background color is set using a role.
+    background_subscription: Option<Subscription>,
+}
 
-impl<CHILDREN, MENU> From<WindowOutlet<CHILDREN, MENU>> for CocoaWindowOutlet<CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> Native<Cocoa>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
-    CHILDREN: ChildrenList,
-    MENU: ChildrenList,
+    CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    MENU: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    TOOLBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    STATUSBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
 {
-    fn from(app: WindowOutlet<CHILDREN, MENU>) -> Self {
-        Self {
-            children: app.children,
-            menu:     app.menu,
-        }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    MENU: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    TOOLBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    STATUSBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+{
+    pub fn state(&self) -> &STATE {
+        &self.data.state
     }
 }
 
-impl<CHILDREN, MENU> Outlet<ChildrenOutlet> for CocoaWindowOutlet<CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
-    type Children = CHILDREN;
+    /// Visible frame of the screen the window is on, or the main screen
+    /// before the window is shown. Percentages are relative to it.
+    fn screen_frame(&self) -> NSRect {
+        unsafe {
+            let screen = match self.data.native_handle.screen() {
+                screen if screen != nil => screen,
+                _ => NSScreen::mainScreen(nil),
+            };
+            NSScreen::visibleFrame(screen)
+        }
+    }
 
-    fn get(&self) -> &Self::Children {
-        &self.children
+    /// Size of the content area, the frame children are placed in.
+    fn content_size(&self) -> NSSize {
+        let window = &self.data.native_handle;
+        unsafe { window.contentRectForFrameRect_(NSWindow::frame(**window)) }.size
     }
 
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.children
+    fn has_style(&self, style: NSWindowStyleMask) -> bool {
+        unsafe { self.data.native_handle.styleMask() }.contains(style)
+    }
+
+    fn set_style(&mut self, style: NSWindowStyleMask, enabled: bool) {
+        unsafe {
+            let mut mask = self.data.native_handle.styleMask();
+            mask.set(style, enabled);
+            self.data.native_handle.setStyleMask_(mask);
+        }
     }
 }
 
-impl<CHILDREN, MENU> Outlet<MenuOutlet> for CocoaWindowOutlet<CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
-    CHILDREN: ChildrenList,
+    CHILDREN: ChildrenList + InheritFrame,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList + InheritFrame,
+    STATUSBAR: ChildrenList + InheritFrame,
 {
-    type Children = MENU;
-
-    fn get(&self) -> &Self::Children {
-        &self.menu
+    /// Hands the size of the content area down to the children, toolbar
+    /// and status bar, so they can resolve percentages against it.
+    fn update_frame(&self) {
+        let content = self.content_size();
+
+        self.data.outlet.children.parent_frame(content.width, content.height);
+        self.data.outlet.toolbar.parent_frame(content.width, content.height);
+        self.data.outlet.status_bar.parent_frame(content.width, content.height);
     }
+}
 
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.menu
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + InheritEnabled,
+    MENU: ChildrenList + InheritEnabled,
+    TOOLBAR: ChildrenList + InheritEnabled,
+    STATUSBAR: ChildrenList + InheritEnabled,
+{
+    /// Applies the effective enabled state to the window and hands it down
+    /// to all children. A disabled window ignores mouse events.
+    fn update_enabled(&self) {
+        let enabled = self.data.is_enabled();
+        unsafe {
+            self.data
+                .native_handle
+                .setIgnoresMouseEvents_(if enabled { NO } else { YES });
+        }
+
+        self.data.outlet.children.parent_enabled(enabled);
+        self.data.outlet.menu.parent_enabled(enabled);
+        self.data.outlet.toolbar.parent_enabled(enabled);
+        self.data.outlet.status_bar.parent_enabled(enabled);
     }
 }
 
+pub trait CocoaWindow<STATE>
+where
+    Self: NativeWidget<STATE, Backend = Cocoa> + Sized,
+{
+    fn set_label(&mut self, label: &CocoaLabel) -> PlatingResult<()>;
+    fn label(&self) -> PlatingResult<CocoaLabel>;
+
+    fn set_border(&mut self, border: &CocoaBorder) -> PlatingResult<()>;
+    fn set_resizable(&mut self, resizable: &CocoaResizable) -> PlatingResult<()>;
+    fn set_closable(&mut self, closable: &CocoaClosable) -> PlatingResult<()>;
+    fn set_minimizable(&mut self, minimizable: &CocoaMinimizable) -> PlatingResult<()>;
+    fn set_title_bar_hidden(&mut self, title_bar_hidden: &CocoaTitleBarHidden)
+        -> PlatingResult<()>;
+    fn set_always_on_top(&mut self, always_on_top: &CocoaAlwaysOnTop) -> PlatingResult<()>;
+
+    fn border(&self) -> PlatingResult<CocoaBorder>;
+    fn resizable(&self) -> PlatingResult<CocoaResizable>;
+    fn closable(&self) -> PlatingResult<CocoaClosable>;
+    fn minimizable(&self) -> PlatingResult<CocoaMinimizable>;
+    fn title_bar_hidden(&self) -> PlatingResult<CocoaTitleBarHidden>;
+    fn always_on_top(&self) -> PlatingResult<CocoaAlwaysOnTop>;
+}
 
-#[derive(Debug)]
-pub struct CocoaWindowWidget<STATE, CHILDREN, MENU>
+/// `NSNormalWindowLevel`
+const NORMAL_WINDOW_LEVEL: NSInteger = 0;
+/// `NSFloatingWindowLevel`
+const FLOATING_WINDOW_LEVEL: NSInteger = 3;
+
+fn set_window_background(window: &StrongPtr, color: Color) {
+    let (red, green, blue, alpha) = color.to_f64();
+    unsafe {
+        let color = NSColor::colorWithSRGBRed_green_blue_alpha_(nil, red, green, blue, alpha);
+        window.setBackgroundColor_(color);
+    }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> CocoaWindow<STATE>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
-    data: Pin<Box<CocoaInternal<STATE, CHILDREN, CocoaWindowOutlet<CHILDREN, MENU>>>>,
+    fn set_label(&mut self, label: &CocoaLabel) -> PlatingResult<()> {
+        unsafe {
+            self.data.native_handle.setTitle_(label.0.clone().into());
+        }
+        Ok(())
+    }
+
+    fn label(&self) -> PlatingResult<CocoaLabel> {
+        let title: NSString = unsafe { self.data.native_handle.title() }.into();
+        Ok(title.into())
+    }
+
+    fn set_border(&mut self, border: &CocoaBorder) -> PlatingResult<()> {
+        let CocoaBorder(border) = *border;
+        self.set_style(
+            NSWindowStyleMask::NSTitledWindowMask,
+            border == Border::BORDER,
+        );
+        Ok(())
+    }
+
+    fn set_resizable(&mut self, resizable: &CocoaResizable) -> PlatingResult<()> {
+        let CocoaResizable(Resizable(resizable)) = *resizable;
+        self.set_style(NSWindowStyleMask::NSResizableWindowMask, resizable);
+        Ok(())
+    }
+
+    fn set_closable(&mut self, closable: &CocoaClosable) -> PlatingResult<()> {
+        let CocoaClosable(Closable(closable)) = *closable;
+        self.set_style(NSWindowStyleMask::NSClosableWindowMask, closable);
+        Ok(())
+    }
+
+    fn set_minimizable(&mut self, minimizable: &CocoaMinimizable) -> PlatingResult<()> {
+        let CocoaMinimizable(Minimizable(minimizable)) = *minimizable;
+        self.set_style(NSWindowStyleMask::NSMiniaturizableWindowMask, minimizable);
+        Ok(())
+    }
+
+    fn set_title_bar_hidden(
+        &mut self,
+        title_bar_hidden: &CocoaTitleBarHidden,
+    ) -> PlatingResult<()> {
+        let CocoaTitleBarHidden(TitleBarHidden(hidden)) = *title_bar_hidden;
+        // the content extends below the transparent title bar,
+        // the window buttons stay visible
+        self.set_style(NSWindowStyleMask::NSFullSizeContentViewWindowMask, hidden);
+        unsafe {
+            let window = &self.data.native_handle;
+            window.setTitlebarAppearsTransparent_(if hidden { YES } else { NO });
+            window.setTitleVisibility_(if hidden {
+                NSWindowTitleVisibility::NSWindowTitleHidden
+            } else {
+                NSWindowTitleVisibility::NSWindowTitleVisible
+            });
+        }
+        Ok(())
+    }
+
+    fn set_always_on_top(&mut self, always_on_top: &CocoaAlwaysOnTop) -> PlatingResult<()> {
+        let CocoaAlwaysOnTop(AlwaysOnTop(always_on_top)) = *always_on_top;
+        let level = if always_on_top {
+            FLOATING_WINDOW_LEVEL
+        } else {
+            NORMAL_WINDOW_LEVEL
+        };
+        unsafe {
+            self.data.native_handle.setLevel_(level);
+        }
+        Ok(())
+    }
+
+    fn border(&self) -> PlatingResult<CocoaBorder> {
+        Ok(CocoaBorder(
+            if self.has_style(NSWindowStyleMask::NSTitledWindowMask) {
+                Border::BORDER
+            } else {
+                Border::BORDERLESS
+            },
+        ))
+    }
+
+    fn resizable(&self) -> PlatingResult<CocoaResizable> {
+        Ok(CocoaResizable(Resizable(
+            self.has_style(NSWindowStyleMask::NSResizableWindowMask),
+        )))
+    }
+
+    fn closable(&self) -> PlatingResult<CocoaClosable> {
+        Ok(CocoaClosable(Closable(
+            self.has_style(NSWindowStyleMask::NSClosableWindowMask),
+        )))
+    }
+
+    fn minimizable(&self) -> PlatingResult<CocoaMinimizable> {
+        Ok(CocoaMinimizable(Minimizable(
+            self.has_style(NSWindowStyleMask::NSMiniaturizableWindowMask),
+        )))
+    }
+
+    fn title_bar_hidden(&self) -> PlatingResult<CocoaTitleBarHidden> {
+        Ok(CocoaTitleBarHidden(TitleBarHidden(self.has_style(
+            NSWindowStyleMask::NSFullSizeContentViewWindowMask,
+        ))))
+    }
+
+    fn always_on_top(&self) -> PlatingResult<CocoaAlwaysOnTop> {
+        use objc::*;
+
+        let level: NSInteger = unsafe { msg_send![*self.data.native_handle, level] };
+        Ok(CocoaAlwaysOnTop(AlwaysOnTop(
+            level >= FLOATING_WINDOW_LEVEL,
+        )))
+    }
 }
 
-impl<STATE, CHILDREN, MENU> Native<Cocoa> for CocoaWindowWidget<STATE, CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> CocoaGeometry<STATE>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
-    CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
-    MENU: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    CHILDREN: ChildrenList + InheritFrame,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList + InheritFrame,
+    STATUSBAR: ChildrenList + InheritFrame,
 {
+    fn set_size(&mut self, size: &CocoaSize) -> PlatingResult<()> {
+        let screen = self.screen_frame();
+        let (width, height) = size.0.resolve(screen.size.width, screen.size.height);
+        unsafe {
+            self.data
+                .native_handle
+                .setContentSize_(NSSize::new(width, height));
+        }
+        self.update_frame();
+        Ok(())
+    }
+
+    fn set_position(&mut self, position: &CocoaPosition) -> PlatingResult<()> {
+        let screen = self.screen_frame();
+        let (x, y) = position.0.resolve(screen.size.width, screen.size.height);
+        // cocoa places the origin in the bottom left corner of the screen
+        let top_left = NSPoint::new(
+            screen.origin.x + x,
+            screen.origin.y + screen.size.height - y,
+        );
+        unsafe {
+            self.data.native_handle.setFrameTopLeftPoint_(top_left);
+        }
+        Ok(())
+    }
+
+    fn set_min_size(&mut self, min_size: &CocoaMinSize) -> PlatingResult<()> {
+        let CocoaMinSize(MinSize(min_size)) = min_size;
+        let screen = self.screen_frame();
+        let (width, height) = min_size.resolve(screen.size.width, screen.size.height);
+        unsafe {
+            self.data
+                .native_handle
+                .setContentMinSize_(NSSize::new(width, height));
+        }
+        Ok(())
+    }
+
+    fn set_max_size(&mut self, max_size: &CocoaMaxSize) -> PlatingResult<()> {
+        let CocoaMaxSize(MaxSize(max_size)) = max_size;
+        let screen = self.screen_frame();
+        let (width, height) = max_size.resolve(screen.size.width, screen.size.height);
+        unsafe {
+            self.data
+                .native_handle
+                .setContentMaxSize_(NSSize::new(width, height));
+        }
+        Ok(())
+    }
+
+    fn set_background_color(&mut self, color: &CocoaBackgroundColor) -> PlatingResult<()> {
+        let CocoaBackgroundColor(BackgroundColor(color)) = *color;
+        let theme = Theme::current();
+        set_window_background(&self.data.native_handle, theme.resolve(&color));
+
+        // replaces the subscription of a previously set role, if any
+        self.background_subscription = match color {
+            ColorValue::Fixed(_) => None,
+            ColorValue::Role(_) => {
+                let window = self.data.native_handle.weak();
+                Some(theme.subscribe(move |event| {
+                    if let SystemEvent::ThemeChange(palette) = event {
+                        // the window might already be gone
+                        let window = window.load();
+                        if !window.is_null() {
+                            set_window_background(&window, color.resolve(palette));
+                        }
+                    }
+                }))
+            },
+        };
+        Ok(())
+    }
+
+    fn size(&self) -> PlatingResult<CocoaSize> {
+        let content = self.content_size();
+        Ok(CocoaSize(Size::new(
+            Length::Pixel(content.width),
+            Length::Pixel(content.height),
+        )))
+    }
+
+    fn position(&self) -> PlatingResult<CocoaPosition> {
+        let screen = self.screen_frame();
+        let frame = unsafe { NSWindow::frame(*self.data.native_handle) };
+        // back from cocoas bottom left origin
+        let x = frame.origin.x - screen.origin.x;
+        let y = screen.origin.y + screen.size.height - (frame.origin.y + frame.size.height);
+        Ok(CocoaPosition(Position::new(
+            Length::Pixel(x),
+            Length::Pixel(y),
+        )))
+    }
+
+    fn min_size(&self) -> PlatingResult<CocoaMinSize> {
+        let size = unsafe { self.data.native_handle.contentMinSize() };
+        Ok(CocoaMinSize(MinSize(Size::new(
+            Length::Pixel(size.width),
+            Length::Pixel(size.height),
+        ))))
+    }
+
+    fn max_size(&self) -> PlatingResult<CocoaMaxSize> {
+        let size = unsafe { self.data.native_handle.contentMaxSize() };
+        Ok(CocoaMaxSize(MaxSize(Size::new(
+            Length::Pixel(size.width),
+            Length::Pixel(size.height),
+        ))))
+    }
+
+    fn background_color(&self) -> PlatingResult<CocoaBackgroundColor> {
+        use objc::*;
+
+        let component = |value: f64| (value * 255.0).round() as u8;
+        unsafe {
+            let color_space: id = msg_send![class!(NSColorSpace), sRGBColorSpace];
+            let color: id = msg_send![
+                self.data.native_handle.backgroundColor(),
+                colorUsingColorSpace: color_space
+            ];
+            if color == nil {
+                return Err(PlatingError::Backend(anyhow::anyhow!(
+                    "window background is not a plain color"
+                )));
+            }
+
+            let red: f64 = msg_send![color, redComponent];
+            let green: f64 = msg_send![color, greenComponent];
+            let blue: f64 = msg_send![color, blueComponent];
+            let alpha: f64 = msg_send![color, alphaComponent];
+            Ok(CocoaBackgroundColor(BackgroundColor(ColorValue::Fixed(
+                Color::rgba(
+                    component(red),
+                    component(green),
+                    component(blue),
+                    component(alpha),
+                ),
+            ))))
+        }
+    }
 }
 
-impl<STATE, CHILDREN, MENU> CocoaWindowWidget<STATE, CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> CocoaCommon<STATE>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
-    CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
-    MENU: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    CHILDREN: ChildrenList + InheritEnabled,
+    MENU: ChildrenList + InheritEnabled,
+    TOOLBAR: ChildrenList + InheritEnabled,
+    STATUSBAR: ChildrenList + InheritEnabled,
 {
-    pub fn state(&self) -> &STATE {
-        &self.data.state
+    fn set_visible(&mut self, visible: &CocoaVisible) -> PlatingResult<()> {
+        use objc::*;
+
+        self.data.visible.set(visible.0);
+        unsafe {
+            let window_number: NSInteger = msg_send![*self.data.native_handle, windowNumber];
+            if !visible.0 {
+                self.data.native_handle.orderOut_(nil);
+            } else if window_number > 0 {
+                // only windows already shown once, the others get shown on connect
+                self.data.native_handle.orderFront_(nil);
+            }
+        }
+        Ok(())
+    }
+
+    fn set_enabled(&mut self, enabled: &CocoaEnabled) -> PlatingResult<()> {
+        self.data.enabled.set(enabled.0);
+        self.update_enabled();
+        Ok(())
+    }
+
+    fn set_tooltip(&mut self, tooltip: &CocoaTooltip) -> PlatingResult<()> {
+        use objc::*;
+
+        let tooltip: id = if String::from(&tooltip.0).is_empty() {
+            nil
+        } else {
+            tooltip.0.clone().into()
+        };
+        unsafe {
+            let content_view = self.data.native_handle.contentView();
+            let _: () = msg_send![content_view, setToolTip: tooltip];
+        }
+        Ok(())
+    }
+
+    fn set_id(&mut self, id: &CocoaId) -> PlatingResult<()> {
+        self.data.set_id::<Self>(&id.0)
+    }
+
+    fn visible(&self) -> PlatingResult<CocoaVisible> {
+        Ok(CocoaVisible(self.data.visible.get()))
+    }
+
+    fn enabled(&self) -> PlatingResult<CocoaEnabled> {
+        Ok(CocoaEnabled(self.data.enabled.get()))
+    }
+
+    fn tooltip(&self) -> PlatingResult<CocoaTooltip> {
+        use objc::*;
+
+        let tooltip: NSString = unsafe {
+            let content_view = self.data.native_handle.contentView();
+            let tooltip: id = msg_send![content_view, toolTip];
+            if tooltip == nil {
+                NSString::from("")
+            } else {
+                tooltip.into()
+            }
+        };
+        Ok(CocoaTooltip(tooltip))
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.data.is_enabled()
     }
 }
 
-pub trait CocoaWindow<STATE>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> InheritEnabled
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
-    Self: NativeWidget<STATE, Backend = Cocoa> + Sized,
+    CHILDREN: ChildrenList + InheritEnabled,
+    MENU: ChildrenList + InheritEnabled,
+    TOOLBAR: ChildrenList + InheritEnabled,
+    STATUSBAR: ChildrenList + InheritEnabled,
 {
-    fn set_label(&mut self, label: &CocoaLabel) -> PlatingResult<()>;
+    fn parent_enabled(&self, enabled: bool) {
+        self.data.parent_enabled.set(enabled);
+        self.update_enabled();
+    }
 }
 
-impl<STATE, CHILDREN, MENU> CocoaWindow<STATE> for CocoaWindowWidget<STATE, CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> Identifiable
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
-    fn set_label(&mut self, _label: &CocoaLabel) -> PlatingResult<()> {
-        //todo:
-        Ok(())
+    fn id(&self) -> Option<Id> {
+        self.data.id()
     }
 }
 
-impl<STATE, CHILDREN, MENU> NativeBuilder<STATE> for CocoaWindowWidget<STATE, CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> NativeBuilder<STATE>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
     MENU: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    TOOLBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    STATUSBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
 {
-    type OutletType = CocoaWindowOutlet<CHILDREN, MENU>;
+    type OutletType = CocoaWindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>;
 
     fn new_with_state<SL>(
         state: STATE,
@@ -177,6 +677,7 @@ where
                 StrongPtr::new(objc::msg_send![view_class, alloc])
             };
 
+            // Placeholder frame, replaced by the Size and Position settings
             id.initWithContentRect_styleMask_backing_defer_(
                 NSRect::new(NSPoint::new(0., 0.), NSSize::new(200., 200.)),
                 NSWindowStyleMask::NSTitledWindowMask,
@@ -188,20 +689,26 @@ where
 
         let mut result = CocoaWindowWidget {
             data: CocoaInternal::new(window, state, outlet),
+            background_subscription: None,
         };
         result.apply(settings)?;
 
         result.data.outlet.children.setup(&result);
         result.data.outlet.menu.setup(&result);
+        result.data.outlet.toolbar.setup(&result);
+        result.data.outlet.status_bar.setup(&result);
 
         Ok(result)
     }
 }
 
-impl<STATE, CHILDREN, MENU> NativeWidget<STATE> for CocoaWindowWidget<STATE, CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> NativeWidget<STATE>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
     type InternalHandle = StrongPtr;
     type Backend = Cocoa;
@@ -210,27 +717,34 @@ where
     where
         SL: SettingsList + Property<STATE, Self, Cocoa> + Native<Cocoa>,
     {
+        settings.validate()?;
         settings.provide(self)
     }
 }
 
-impl<STATE, CHILDREN, MENU> Default for CocoaWindowWidget<STATE, CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> Default
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     STATE: Default,
     CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
     MENU: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    TOOLBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    STATUSBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
     //Self: NativeBuilder<STATE, ()>,
-    CocoaWindowOutlet<CHILDREN, MENU>: Default,
+    CocoaWindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>: Default,
 {
     fn default() -> Self {
         Self::new(&(), Default::default()).unwrap()
     }
 }
 
-impl<STATE, CHILDREN, MENU> AsBackend<StrongPtr> for CocoaWindowWidget<STATE, CHILDREN, MENU>
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> AsBackend<StrongPtr>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
     MENU: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    TOOLBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
+    STATUSBAR: ChildrenList + NativeChildOf<Self, STATE, Cocoa>,
 {
     fn as_backend(&self) -> &StrongPtr {
         &self.data.native_handle
@@ -245,27 +759,39 @@ where
     }
 }
 
-impl<StateP, ChildrenP, StateC, ChildrenC, MenuC> ChildOf<CocoaAppWidget<StateP, ChildrenP>>
-    for CocoaWindowWidget<StateC, ChildrenC, MenuC>
+impl<StateP, ChildrenP, StateC, ChildrenC, MenuC, ToolbarC, StatusBarC>
+    ChildOf<CocoaAppWidget<StateP, ChildrenP>>
+    for CocoaWindowWidget<StateC, ChildrenC, MenuC, ToolbarC, StatusBarC>
 where
     ChildrenP: ChildrenList + std::fmt::Debug,
     ChildrenC: ChildrenList + std::fmt::Debug + NativeChildOf<Self, StateC, Cocoa>,
     MenuC: ChildrenList + std::fmt::Debug + NativeChildOf<Self, StateC, Cocoa>,
+    ToolbarC: ChildrenList + std::fmt::Debug + NativeChildOf<Self, StateC, Cocoa>,
+    StatusBarC: ChildrenList + std::fmt::Debug + NativeChildOf<Self, StateC, Cocoa>,
 {
     fn setup(&mut self, _parent: &CocoaAppWidget<StateP, ChildrenP>) {}
 
-    fn connect(&self, _parent: &CocoaAppWidget<StateP, ChildrenP>) {
-        unsafe {
-            self.data.native_handle.makeKeyAndOrderFront_(nil);
-            self.data.native_handle.makeMainWindow();
+    fn connect(&self, _parent: &CocoaAppWidget<StateP, ChildrenP>) -> PlatingResult<()> {
+        if self.data.connected.replace(true) {
+            return Err(PlatingError::AlreadyConnected);
+        }
+
+        if self.data.visible.get() {
+            unsafe {
+                self.data.native_handle.makeKeyAndOrderFront_(nil);
+                self.data.native_handle.makeMainWindow();
+            }
         }
 
-        self.data.outlet.children.connect(self);
-        self.data.outlet.menu.connect(self);
+        self.data.outlet.children.connect(self)?;
+        self.data.outlet.menu.connect(self)?;
+        self.data.outlet.toolbar.connect(self)?;
+        self.data.outlet.status_bar.connect(self)
     }
 
     fn disconnect(&self) {
         //todo: invoke message handlers
+        self.data.connected.set(false);
         unsafe {
             self.data.native_handle.orderOut_(nil);
         }
@@ -273,5 +799,7 @@ where
 
         self.data.outlet.children.disconnect();
         self.data.outlet.menu.disconnect();
+        self.data.outlet.toolbar.disconnect();
+        self.data.outlet.status_bar.disconnect();
     }
 }
@@ -0,0 +1,552 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::Cell;
+
+use crate::native::{Native, NativeBuilder, NativeChildOf, NativeWidget, ToNative};
+use crate::prelude::WidgetBuilder;
+use crate::utils::{ChildOf, ChildrenList, InheritFrame, Property, SettingsList};
+use crate::widget::mock::Mock;
+use crate::widget::properties::{
+    AlwaysOnTop,
+    BackgroundColor,
+    Border,
+    Closable,
+    Label,
+    MaxSize,
+    MinSize,
+    Minimizable,
+    Position,
+    Resizable,
+    Size,
+    TitleBarHidden,
+};
+use crate::widget::{GeometryWidget, WidgetAbstractionLevel, WindowOutlet, WindowWidget};
+use crate::{PlatingError, PlatingResult};
+
+/// Size of the screen a [`MockWindowWidget`] resolves percentages against.
+pub const MOCK_SCREEN: (f64, f64) = (1920.0, 1080.0);
+
+/// Window of the [`Mock`] backend.
+///
+/// Records every applied property, so tests can read them back using the
+/// getters of [`WindowWidget`] and [`GeometryWidget`]. Its parent is a
+/// screen of [`MOCK_SCREEN`] until told otherwise using [`InheritFrame`].
+#[derive(Debug)]
+pub struct MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR = (), STATUSBAR = ()>
+where
+    CHILDREN: ChildrenList,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
+{
+    state:  STATE,
+    outlet: WindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>,
+
+    label: Label,
+    size: Size,
+    position: Position,
+    min_size: MinSize,
+    max_size: MaxSize,
+    background_color: Option<BackgroundColor>,
+    border: Border,
+    resizable: Resizable,
+    closable: Closable,
+    minimizable: Minimizable,
+    title_bar_hidden: TitleBarHidden,
+    always_on_top: AlwaysOnTop,
+
+    parent_frame: Cell<(f64, f64)>,
+    connected:    Cell<bool>,
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
+{
+    pub fn state(&self) -> &STATE {
+        &self.state
+    }
+
+    pub fn outlet(&self) -> &WindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR> {
+        &self.outlet
+    }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> Native<Mock>
+    for MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
+{
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> NativeWidget<STATE>
+    for MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
+{
+    type Backend = Mock;
+    type InternalHandle = ();
+
+    fn apply<SL>(&mut self, settings: &SL) -> PlatingResult<()>
+    where
+        SL: SettingsList + Property<STATE, Self, Mock> + Native<Mock>,
+    {
+        settings.validate()?;
+        settings.provide(self)
+    }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> NativeBuilder<STATE>
+    for MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    MENU: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    TOOLBAR: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    STATUSBAR: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+{
+    type OutletType = WindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>;
+
+    fn new_with_state<SL>(
+        state: STATE,
+        settings: &SL,
+        outlet: Self::OutletType,
+    ) -> PlatingResult<Self>
+    where
+        SL: SettingsList + Property<STATE, Self, Mock> + Native<Mock>,
+    {
+        let mut window = Self {
+            state,
+            outlet,
+            label: Label(String::new()),
+            size: Size::default(),
+            position: Position::default(),
+            min_size: MinSize::default(),
+            max_size: MaxSize::default(),
+            background_color: None,
+            border: Border::default(),
+            resizable: Resizable::default(),
+            closable: Closable::default(),
+            minimizable: Minimizable::default(),
+            title_bar_hidden: TitleBarHidden::default(),
+            always_on_top: AlwaysOnTop::default(),
+            parent_frame: Cell::new(MOCK_SCREEN),
+            connected: Cell::new(false),
+        };
+        <Self as NativeWidget<STATE>>::apply(&mut window, settings)?;
+
+        window.outlet.children.setup(&window);
+        window.outlet.menu.setup(&window);
+        window.outlet.toolbar.setup(&window);
+        window.outlet.status_bar.setup(&window);
+
+        Ok(window)
+    }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> WidgetBuilder<STATE, Mock>
+    for MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    MENU: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    TOOLBAR: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    STATUSBAR: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+{
+    type OutletType = WindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>;
+
+    fn new_with_state<SL>(
+        state: STATE,
+        settings: SL,
+        outlet: Self::OutletType,
+    ) -> PlatingResult<Self>
+    where
+        Self: NativeWidget<STATE, Backend = Mock>,
+        SL: SettingsList + WidgetAbstractionLevel + ToNative<Mock>,
+        <SL as ToNative<Mock>>::Result: SettingsList + Native<Mock> + Property<STATE, Self, Mock>,
+    {
+        <Self as NativeBuilder<_>>::new_with_state(state, &settings.to_native(), outlet)
+    }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> WindowWidget<STATE, Mock>
+    for MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
+{
+    fn apply<SL>(&mut self, settings: SL) -> PlatingResult<()>
+    where
+        Self: NativeWidget<STATE, Backend = Mock>,
+        SL: SettingsList + WidgetAbstractionLevel + ToNative<Mock>,
+        <SL as ToNative<Mock>>::Result: SettingsList + Native<Mock> + Property<STATE, Self, Mock>,
+    {
+        <Self as NativeWidget<STATE>>::apply(self, &settings.to_native())
+    }
+
+    fn set_label(&mut self, label: &Label) -> PlatingResult<()> {
+        self.label = label.clone();
+        Ok(())
+    }
+
+    fn set_border(&mut self, border: &Border) -> PlatingResult<()> {
+        self.border = *border;
+        Ok(())
+    }
+
+    fn set_resizable(&mut self, resizable: &Resizable) -> PlatingResult<()> {
+        self.resizable = *resizable;
+        Ok(())
+    }
+
+    fn set_closable(&mut self, closable: &Closable) -> PlatingResult<()> {
+        self.closable = *closable;
+        Ok(())
+    }
+
+    fn set_minimizable(&mut self, minimizable: &Minimizable) -> PlatingResult<()> {
+        self.minimizable = *minimizable;
+        Ok(())
+    }
+
+    fn set_title_bar_hidden(&mut self, title_bar_hidden: &TitleBarHidden) -> PlatingResult<()> {
+        self.title_bar_hidden = *title_bar_hidden;
+        Ok(())
+    }
+
+    fn set_always_on_top(&mut self, always_on_top: &AlwaysOnTop) -> PlatingResult<()> {
+        self.always_on_top = *always_on_top;
+        Ok(())
+    }
+
+    fn label(&self) -> PlatingResult<Label> {
+        Ok(self.label.clone())
+    }
+
+    fn border(&self) -> PlatingResult<Border> {
+        Ok(self.border)
+    }
+
+    fn resizable(&self) -> PlatingResult<Resizable> {
+        Ok(self.resizable)
+    }
+
+    fn closable(&self) -> PlatingResult<Closable> {
+        Ok(self.closable)
+    }
+
+    fn minimizable(&self) -> PlatingResult<Minimizable> {
+        Ok(self.minimizable)
+    }
+
+    fn title_bar_hidden(&self) -> PlatingResult<TitleBarHidden> {
+        Ok(self.title_bar_hidden)
+    }
+
+    fn always_on_top(&self) -> PlatingResult<AlwaysOnTop> {
+        Ok(self.always_on_top)
+    }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+    MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + InheritFrame,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList + InheritFrame,
+    STATUSBAR: ChildrenList + InheritFrame,
+{
+    /// Hands the resolved size of the window down to all children.
+    fn update_frame(&self) {
+        let (parent_width, parent_height) = self.parent_frame.get();
+        let (width, height) = self.size.resolve(parent_width, parent_height);
+
+        self.outlet.children.parent_frame(width, height);
+        self.outlet.toolbar.parent_frame(width, height);
+        self.outlet.status_bar.parent_frame(width, height);
+    }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> GeometryWidget<STATE, Mock>
+    for MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + InheritFrame,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList + InheritFrame,
+    STATUSBAR: ChildrenList + InheritFrame,
+{
+    fn set_size(&mut self, size: &Size) -> PlatingResult<()> {
+        self.size = *size;
+        self.update_frame();
+        Ok(())
+    }
+
+    fn set_position(&mut self, position: &Position) -> PlatingResult<()> {
+        self.position = *position;
+        Ok(())
+    }
+
+    fn set_min_size(&mut self, min_size: &MinSize) -> PlatingResult<()> {
+        self.min_size = *min_size;
+        Ok(())
+    }
+
+    fn set_max_size(&mut self, max_size: &MaxSize) -> PlatingResult<()> {
+        self.max_size = *max_size;
+        Ok(())
+    }
+
+    fn set_background_color(&mut self, color: &BackgroundColor) -> PlatingResult<()> {
+        self.background_color = Some(*color);
+        Ok(())
+    }
+
+    fn size(&self) -> PlatingResult<Size> {
+        Ok(self.size)
+    }
+
+    fn position(&self) -> PlatingResult<Position> {
+        Ok(self.position)
+    }
+
+    fn min_size(&self) -> PlatingResult<MinSize> {
+        Ok(self.min_size)
+    }
+
+    fn max_size(&self) -> PlatingResult<MaxSize> {
+        Ok(self.max_size)
+    }
+
+    fn background_color(&self) -> PlatingResult<BackgroundColor> {
+        self.background_color.ok_or(PlatingError::NotSet {
+            property: "BackgroundColor",
+        })
+    }
+}
+
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> InheritFrame
+    for MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + InheritFrame,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList + InheritFrame,
+    STATUSBAR: ChildrenList + InheritFrame,
+{
+    fn parent_frame(&self, width: f64, height: f64) {
+        self.parent_frame.set((width, height));
+        self.update_frame();
+    }
+}
+
+/// Connects to any parent, there is no app in the [`Mock`] backend.
+impl<T, STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> ChildOf<T>
+    for MockWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    MENU: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    TOOLBAR: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+    STATUSBAR: ChildrenList + NativeChildOf<Self, STATE, Mock>,
+{
+    fn setup(&mut self, _parent: &T) {}
+
+    fn connect(&self, _parent: &T) -> PlatingResult<()> {
+        if self.connected.replace(true) {
+            return Err(PlatingError::AlreadyConnected);
+        }
+
+        self.outlet.children.connect(self)?;
+        self.outlet.menu.connect(self)?;
+        self.outlet.toolbar.connect(self)?;
+        self.outlet.status_bar.connect(self)
+    }
+
+    fn disconnect(&self) {
+        self.connected.set(false);
+
+        self.outlet.children.disconnect();
+        self.outlet.menu.disconnect();
+        self.outlet.toolbar.disconnect();
+        self.outlet.status_bar.disconnect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::MockWindowWidget;
+    use crate::native::Native;
+    use crate::prelude::WidgetBuilder;
+    use crate::utils::data::{Border, Length};
+    use crate::utils::{children_list, setting_list, ChildOf, InheritFrame};
+    use crate::widget::mock::Mock;
+    use crate::widget::properties::{
+        AlwaysOnTop,
+        Closable,
+        Label,
+        Minimizable,
+        Resizable,
+        Size,
+        TitleBarHidden,
+    };
+    use crate::widget::{GeometryWidget, WindowOutlet, WindowWidget};
+    use crate::{PlatingError, PlatingResult};
+
+    type Window = MockWindowWidget<(), (), ()>;
+
+    /// Child counting how often it got connected to its parent, and
+    /// remembering the last frame of its parent.
+    #[derive(Debug, Default)]
+    struct Recorder {
+        connected: Cell<usize>,
+        frame:     Cell<(f64, f64)>,
+    }
+
+    impl Native<Mock> for Recorder {}
+
+    impl<T> ChildOf<T> for Recorder {
+        fn setup(&mut self, _parent: &T) {}
+
+        fn connect(&self, _parent: &T) -> PlatingResult<()> {
+            self.connected.set(self.connected.get() + 1);
+            Ok(())
+        }
+
+        fn disconnect(&self) {}
+    }
+
+    impl InheritFrame for Recorder {
+        fn parent_frame(&self, width: f64, height: f64) {
+            self.frame.set((width, height));
+        }
+    }
+
+    #[test]
+    fn records_window_style() -> PlatingResult<()> {
+        let window = Window::new_with_state(
+            (),
+            setting_list!(
+                Label(String::from("Mock")),
+                Border::BORDERLESS,
+                Resizable(false),
+                Closable(false),
+                Minimizable(false),
+                TitleBarHidden(true),
+                AlwaysOnTop(true)
+            ),
+            WindowOutlet::default(),
+        )?;
+
+        assert_eq!(window.label()?, Label(String::from("Mock")));
+        assert_eq!(window.border()?, Border::BORDERLESS);
+        assert_eq!(window.resizable()?, Resizable(false));
+        assert_eq!(window.closable()?, Closable(false));
+        assert_eq!(window.minimizable()?, Minimizable(false));
+        assert_eq!(window.title_bar_hidden()?, TitleBarHidden(true));
+        assert_eq!(window.always_on_top()?, AlwaysOnTop(true));
+        Ok(())
+    }
+
+    #[test]
+    fn apply_overrides_window_style() -> PlatingResult<()> {
+        let mut window = Window::new_with_state((), (), WindowOutlet::default())?;
+        assert_eq!(window.border()?, Border::BORDER);
+        assert_eq!(window.resizable()?, Resizable(true));
+        assert_eq!(window.always_on_top()?, AlwaysOnTop(false));
+
+        WindowWidget::apply(
+            &mut window,
+            setting_list!(Border::BORDERLESS, Resizable(false), AlwaysOnTop(true)),
+        )?;
+
+        assert_eq!(window.border()?, Border::BORDERLESS);
+        assert_eq!(window.resizable()?, Resizable(false));
+        assert_eq!(window.always_on_top()?, AlwaysOnTop(true));
+        Ok(())
+    }
+
+    #[test]
+    fn background_color_is_not_set_by_default() -> PlatingResult<()> {
+        let window = Window::new_with_state((), (), WindowOutlet::default())?;
+
+        assert!(matches!(
+            window.background_color(),
+            Err(PlatingError::NotSet {
+                property: "BackgroundColor",
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn connects_toolbar_and_status_bar() -> PlatingResult<()> {
+        let window = MockWindowWidget::<(), (), (), _, _>::new_with_state(
+            (),
+            (),
+            WindowOutlet {
+                children: (),
+                menu: (),
+                toolbar: children_list!(Recorder::default()),
+                status_bar: children_list!(Recorder::default()),
+            },
+        )?;
+        assert_eq!(window.outlet().toolbar.0.connected.get(), 0);
+
+        window.connect(&())?;
+
+        assert_eq!(window.outlet().toolbar.0.connected.get(), 1);
+        assert_eq!(window.outlet().status_bar.0.connected.get(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn resolves_percent_size_against_the_parent() -> PlatingResult<()> {
+        let window = MockWindowWidget::<(), _, ()>::new_with_state(
+            (),
+            setting_list!(Size::new(Length::Percent(50.0), Length::Percent(100.0))),
+            WindowOutlet {
+                children: children_list!(Recorder::default()),
+                menu: (),
+                toolbar: (),
+                status_bar: (),
+            },
+        )?;
+        assert_eq!(window.outlet().children.0.frame.get(), (960.0, 1080.0));
+
+        window.parent_frame(1000.0, 500.0);
+
+        assert_eq!(window.outlet().children.0.frame.get(), (500.0, 500.0));
+        assert_eq!(
+            window.size()?,
+            Size::new(Length::Percent(50.0), Length::Percent(100.0))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn connecting_twice_fails() -> PlatingResult<()> {
+        let window = Window::new_with_state((), (), WindowOutlet::default())?;
+        window.connect(&())?;
+
+        assert!(matches!(
+            window.connect(&()),
+            Err(PlatingError::AlreadyConnected)
+        ));
+
+        ChildOf::<()>::disconnect(&window);
+        window.connect(&())
+    }
+}
@@ -4,3 +4,4 @@
  */
 
 pub(super) mod cocoa;
+pub(super) mod mock;
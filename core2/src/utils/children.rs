@@ -5,6 +5,8 @@
 
 pub use tuple_list::{tuple_list as children_list, TupleList as ChildrenList};
 
+use crate::PlatingResult;
+
 /*
 pub trait HasChildren {
     type Children: ChildrenList;
@@ -30,6 +32,14 @@ impl OutletType for ChildrenOutlet {}
 pub struct MenuOutlet {}
 impl OutletType for MenuOutlet {}
 
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct ToolbarOutlet {}
+impl OutletType for ToolbarOutlet {}
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct StatusBarOutlet {}
+impl OutletType for StatusBarOutlet {}
+
 pub trait Outlet<OUTLET>
 where
     OUTLET: OutletType,
@@ -46,14 +56,18 @@ pub trait OutletHolder {}
 
 pub trait ChildOf<T>: Sized {
     fn setup(&mut self, parent: &T);
-    fn connect(&self, parent: &T);
+    /// Fails with [`AlreadyConnected`](crate::PlatingError::AlreadyConnected)
+    /// until the child got disconnected again.
+    fn connect(&self, parent: &T) -> PlatingResult<()>;
     fn disconnect(&self);
 }
 
 impl<T> ChildOf<T> for () {
     fn setup(&mut self, _parent: &T) {}
 
-    fn connect(&self, _parent: &T) {}
+    fn connect(&self, _parent: &T) -> PlatingResult<()> {
+        Ok(())
+    }
 
     fn disconnect(&self) {}
 }
@@ -68,9 +82,9 @@ where
         self.1.setup(parent);
     }
 
-    fn connect(&self, parent: &T) {
-        self.0.connect(parent);
-        self.1.connect(parent);
+    fn connect(&self, parent: &T) -> PlatingResult<()> {
+        self.0.connect(parent)?;
+        self.1.connect(parent)
     }
 
     fn disconnect(&self) {
@@ -78,3 +92,47 @@ where
         self.1.disconnect();
     }
 }
+
+/// Passes the enabled state of a parent down to its children.
+///
+/// A widget is only enabled if it is enabled itself and its parent is
+/// enabled, so disabling a group disables everything inside it.
+pub trait InheritEnabled {
+    fn parent_enabled(&self, enabled: bool);
+}
+
+impl InheritEnabled for () {
+    fn parent_enabled(&self, _enabled: bool) {}
+}
+
+impl<Head, Tail> InheritEnabled for (Head, Tail)
+where
+    Head: InheritEnabled,
+    Tail: InheritEnabled + ChildrenList,
+{
+    fn parent_enabled(&self, enabled: bool) {
+        self.0.parent_enabled(enabled);
+        self.1.parent_enabled(enabled);
+    }
+}
+
+/// Passes the size of a parent down to its children, so they can resolve
+/// [`Length::Percent`](crate::utils::data::Length::Percent) against it.
+pub trait InheritFrame {
+    fn parent_frame(&self, width: f64, height: f64);
+}
+
+impl InheritFrame for () {
+    fn parent_frame(&self, _width: f64, _height: f64) {}
+}
+
+impl<Head, Tail> InheritFrame for (Head, Tail)
+where
+    Head: InheritFrame,
+    Tail: InheritFrame + ChildrenList,
+{
+    fn parent_frame(&self, width: f64, height: f64) {
+        self.0.parent_frame(width, height);
+        self.1.parent_frame(width, height);
+    }
+}
@@ -0,0 +1,312 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::str::FromStr;
+
+use crate::utils::{Deserialize, Serialize};
+use crate::{PlatingError, PlatingResult};
+
+/// An sRGB color with alpha channel.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub struct Color {
+    pub red:   u8,
+    pub green: u8,
+    pub blue:  u8,
+    pub alpha: u8,
+}
+
+impl Color {
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 255)
+    }
+
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`. The `#` is optional.
+    pub fn from_hex(hex: &str) -> PlatingResult<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let invalid = || PlatingError::invalid("Color", format!("'{}' is not a hex color", hex));
+        if !digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let short = |index: usize| {
+            u8::from_str_radix(&digits[index..=index], 16)
+                .map(|v| v * 17)
+                .map_err(|_| invalid())
+        };
+        let long =
+            |index: usize| u8::from_str_radix(&digits[index..index + 2], 16).map_err(|_| invalid());
+
+        let color = match digits.len() {
+            3 => Self::rgb(short(0)?, short(1)?, short(2)?),
+            4 => Self::rgba(short(0)?, short(1)?, short(2)?, short(3)?),
+            6 => Self::rgb(long(0)?, long(2)?, long(4)?),
+            8 => Self::rgba(long(0)?, long(2)?, long(4)?, long(6)?),
+            _ => {
+                return Err(PlatingError::invalid(
+                    "Color",
+                    format!("'{}' is not a hex color, expected 3, 4, 6 or 8 digits", hex),
+                ))
+            },
+        };
+        Ok(color)
+    }
+
+    /// Looks up a CSS color name, ignoring case.
+    pub fn from_name(name: &str) -> PlatingResult<Self> {
+        let name = name.to_ascii_lowercase();
+        CSS_COLORS
+            .iter()
+            .find(|(css, _)| *css == name)
+            .map(|(_, color)| *color)
+            .ok_or_else(|| {
+                PlatingError::invalid("Color", format!("'{}' is not a CSS color name", name))
+            })
+    }
+
+    /// The channels in the range `0.0..=1.0`, as expected by most backends.
+    pub fn to_f64(&self) -> (f64, f64, f64, f64) {
+        (
+            f64::from(self.red) / 255.0,
+            f64::from(self.green) / 255.0,
+            f64::from(self.blue) / 255.0,
+            f64::from(self.alpha) / 255.0,
+        )
+    }
+}
+
+/// Parses hex notation or a CSS color name.
+impl FromStr for Color {
+    type Err = PlatingError;
+
+    fn from_str(color: &str) -> Result<Self, Self::Err> {
+        let color = color.trim();
+        if color.starts_with('#') {
+            Self::from_hex(color)
+        } else {
+            Self::from_name(color)
+        }
+    }
+}
+
+/// The named colors of CSS Color Module Level 4.
+const CSS_COLORS: &[(&str, Color)] = &[
+    ("transparent", Color::rgba(0, 0, 0, 0)),
+    ("aliceblue", Color::rgb(240, 248, 255)),
+    ("antiquewhite", Color::rgb(250, 235, 215)),
+    ("aqua", Color::rgb(0, 255, 255)),
+    ("aquamarine", Color::rgb(127, 255, 212)),
+    ("azure", Color::rgb(240, 255, 255)),
+    ("beige", Color::rgb(245, 245, 220)),
+    ("bisque", Color::rgb(255, 228, 196)),
+    ("black", Color::rgb(0, 0, 0)),
+    ("blanchedalmond", Color::rgb(255, 235, 205)),
+    ("blue", Color::rgb(0, 0, 255)),
+    ("blueviolet", Color::rgb(138, 43, 226)),
+    ("brown", Color::rgb(165, 42, 42)),
+    ("burlywood", Color::rgb(222, 184, 135)),
+    ("cadetblue", Color::rgb(95, 158, 160)),
+    ("chartreuse", Color::rgb(127, 255, 0)),
+    ("chocolate", Color::rgb(210, 105, 30)),
+    ("coral", Color::rgb(255, 127, 80)),
+    ("cornflowerblue", Color::rgb(100, 149, 237)),
+    ("cornsilk", Color::rgb(255, 248, 220)),
+    ("crimson", Color::rgb(220, 20, 60)),
+    ("cyan", Color::rgb(0, 255, 255)),
+    ("darkblue", Color::rgb(0, 0, 139)),
+    ("darkcyan", Color::rgb(0, 139, 139)),
+    ("darkgoldenrod", Color::rgb(184, 134, 11)),
+    ("darkgray", Color::rgb(169, 169, 169)),
+    ("darkgreen", Color::rgb(0, 100, 0)),
+    ("darkgrey", Color::rgb(169, 169, 169)),
+    ("darkkhaki", Color::rgb(189, 183, 107)),
+    ("darkmagenta", Color::rgb(139, 0, 139)),
+    ("darkolivegreen", Color::rgb(85, 107, 47)),
+    ("darkorange", Color::rgb(255, 140, 0)),
+    ("darkorchid", Color::rgb(153, 50, 204)),
+    ("darkred", Color::rgb(139, 0, 0)),
+    ("darksalmon", Color::rgb(233, 150, 122)),
+    ("darkseagreen", Color::rgb(143, 188, 143)),
+    ("darkslateblue", Color::rgb(72, 61, 139)),
+    ("darkslategray", Color::rgb(47, 79, 79)),
+    ("darkslategrey", Color::rgb(47, 79, 79)),
+    ("darkturquoise", Color::rgb(0, 206, 209)),
+    ("darkviolet", Color::rgb(148, 0, 211)),
+    ("deeppink", Color::rgb(255, 20, 147)),
+    ("deepskyblue", Color::rgb(0, 191, 255)),
+    ("dimgray", Color::rgb(105, 105, 105)),
+    ("dimgrey", Color::rgb(105, 105, 105)),
+    ("dodgerblue", Color::rgb(30, 144, 255)),
+    ("firebrick", Color::rgb(178, 34, 34)),
+    ("floralwhite", Color::rgb(255, 250, 240)),
+    ("forestgreen", Color::rgb(34, 139, 34)),
+    ("fuchsia", Color::rgb(255, 0, 255)),
+    ("gainsboro", Color::rgb(220, 220, 220)),
+    ("ghostwhite", Color::rgb(248, 248, 255)),
+    ("gold", Color::rgb(255, 215, 0)),
+    ("goldenrod", Color::rgb(218, 165, 32)),
+    ("gray", Color::rgb(128, 128, 128)),
+    ("green", Color::rgb(0, 128, 0)),
+    ("greenyellow", Color::rgb(173, 255, 47)),
+    ("grey", Color::rgb(128, 128, 128)),
+    ("honeydew", Color::rgb(240, 255, 240)),
+    ("hotpink", Color::rgb(255, 105, 180)),
+    ("indianred", Color::rgb(205, 92, 92)),
+    ("indigo", Color::rgb(75, 0, 130)),
+    ("ivory", Color::rgb(255, 255, 240)),
+    ("khaki", Color::rgb(240, 230, 140)),
+    ("lavender", Color::rgb(230, 230, 250)),
+    ("lavenderblush", Color::rgb(255, 240, 245)),
+    ("lawngreen", Color::rgb(124, 252, 0)),
+    ("lemonchiffon", Color::rgb(255, 250, 205)),
+    ("lightblue", Color::rgb(173, 216, 230)),
+    ("lightcoral", Color::rgb(240, 128, 128)),
+    ("lightcyan", Color::rgb(224, 255, 255)),
+    ("lightgoldenrodyellow", Color::rgb(250, 250, 210)),
+    ("lightgray", Color::rgb(211, 211, 211)),
+    ("lightgreen", Color::rgb(144, 238, 144)),
+    ("lightgrey", Color::rgb(211, 211, 211)),
+    ("lightpink", Color::rgb(255, 182, 193)),
+    ("lightsalmon", Color::rgb(255, 160, 122)),
+    ("lightseagreen", Color::rgb(32, 178, 170)),
+    ("lightskyblue", Color::rgb(135, 206, 250)),
+    ("lightslategray", Color::rgb(119, 136, 153)),
+    ("lightslategrey", Color::rgb(119, 136, 153)),
+    ("lightsteelblue", Color::rgb(176, 196, 222)),
+    ("lightyellow", Color::rgb(255, 255, 224)),
+    ("lime", Color::rgb(0, 255, 0)),
+    ("limegreen", Color::rgb(50, 205, 50)),
+    ("linen", Color::rgb(250, 240, 230)),
+    ("magenta", Color::rgb(255, 0, 255)),
+    ("maroon", Color::rgb(128, 0, 0)),
+    ("mediumaquamarine", Color::rgb(102, 205, 170)),
+    ("mediumblue", Color::rgb(0, 0, 205)),
+    ("mediumorchid", Color::rgb(186, 85, 211)),
+    ("mediumpurple", Color::rgb(147, 112, 219)),
+    ("mediumseagreen", Color::rgb(60, 179, 113)),
+    ("mediumslateblue", Color::rgb(123, 104, 238)),
+    ("mediumspringgreen", Color::rgb(0, 250, 154)),
+    ("mediumturquoise", Color::rgb(72, 209, 204)),
+    ("mediumvioletred", Color::rgb(199, 21, 133)),
+    ("midnightblue", Color::rgb(25, 25, 112)),
+    ("mintcream", Color::rgb(245, 255, 250)),
+    ("mistyrose", Color::rgb(255, 228, 225)),
+    ("moccasin", Color::rgb(255, 228, 181)),
+    ("navajowhite", Color::rgb(255, 222, 173)),
+    ("navy", Color::rgb(0, 0, 128)),
+    ("oldlace", Color::rgb(253, 245, 230)),
+    ("olive", Color::rgb(128, 128, 0)),
+    ("olivedrab", Color::rgb(107, 142, 35)),
+    ("orange", Color::rgb(255, 165, 0)),
+    ("orangered", Color::rgb(255, 69, 0)),
+    ("orchid", Color::rgb(218, 112, 214)),
+    ("palegoldenrod", Color::rgb(238, 232, 170)),
+    ("palegreen", Color::rgb(152, 251, 152)),
+    ("paleturquoise", Color::rgb(175, 238, 238)),
+    ("palevioletred", Color::rgb(219, 112, 147)),
+    ("papayawhip", Color::rgb(255, 239, 213)),
+    ("peachpuff", Color::rgb(255, 218, 185)),
+    ("peru", Color::rgb(205, 133, 63)),
+    ("pink", Color::rgb(255, 192, 203)),
+    ("plum", Color::rgb(221, 160, 221)),
+    ("powderblue", Color::rgb(176, 224, 230)),
+    ("purple", Color::rgb(128, 0, 128)),
+    ("rebeccapurple", Color::rgb(102, 51, 153)),
+    ("red", Color::rgb(255, 0, 0)),
+    ("rosybrown", Color::rgb(188, 143, 143)),
+    ("royalblue", Color::rgb(65, 105, 225)),
+    ("saddlebrown", Color::rgb(139, 69, 19)),
+    ("salmon", Color::rgb(250, 128, 114)),
+    ("sandybrown", Color::rgb(244, 164, 96)),
+    ("seagreen", Color::rgb(46, 139, 87)),
+    ("seashell", Color::rgb(255, 245, 238)),
+    ("sienna", Color::rgb(160, 82, 45)),
+    ("silver", Color::rgb(192, 192, 192)),
+    ("skyblue", Color::rgb(135, 206, 235)),
+    ("slateblue", Color::rgb(106, 90, 205)),
+    ("slategray", Color::rgb(112, 128, 144)),
+    ("slategrey", Color::rgb(112, 128, 144)),
+    ("snow", Color::rgb(255, 250, 250)),
+    ("springgreen", Color::rgb(0, 255, 127)),
+    ("steelblue", Color::rgb(70, 130, 180)),
+    ("tan", Color::rgb(210, 180, 140)),
+    ("teal", Color::rgb(0, 128, 128)),
+    ("thistle", Color::rgb(216, 191, 216)),
+    ("tomato", Color::rgb(255, 99, 71)),
+    ("turquoise", Color::rgb(64, 224, 208)),
+    ("violet", Color::rgb(238, 130, 238)),
+    ("wheat", Color::rgb(245, 222, 179)),
+    ("white", Color::rgb(255, 255, 255)),
+    ("whitesmoke", Color::rgb(245, 245, 245)),
+    ("yellow", Color::rgb(255, 255, 0)),
+    ("yellowgreen", Color::rgb(154, 205, 50)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::Color;
+    use crate::{PlatingError, PlatingResult};
+
+    #[test]
+    fn from_hex_parses_all_notations() -> PlatingResult<()> {
+        assert_eq!(Color::from_hex("#f80")?, Color::rgb(255, 136, 0));
+        assert_eq!(Color::from_hex("f808")?, Color::rgba(255, 136, 0, 136));
+        assert_eq!(Color::from_hex("#1E90FF")?, Color::rgb(30, 144, 255));
+        assert_eq!(
+            Color::from_hex("#1e90ff80")?,
+            Color::rgba(30, 144, 255, 128)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_hex_rejects_invalid_colors() {
+        for hex in &["", "#", "#12", "#12345", "#1234567", "#ggg", "#äbc"] {
+            assert!(
+                matches!(
+                    Color::from_hex(hex),
+                    Err(PlatingError::InvalidValue {
+                        property: "Color",
+                        ..
+                    })
+                ),
+                "{} should be rejected",
+                hex
+            );
+        }
+    }
+
+    #[test]
+    fn from_name_ignores_case() -> PlatingResult<()> {
+        assert_eq!(Color::from_name("DodgerBlue")?, Color::rgb(30, 144, 255));
+        assert_eq!(Color::from_name("transparent")?, Color::rgba(0, 0, 0, 0));
+        assert!(Color::from_name("blurple").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn from_str_accepts_hex_and_names() -> PlatingResult<()> {
+        assert_eq!(" #fff ".parse::<Color>()?, Color::rgb(255, 255, 255));
+        assert_eq!("red".parse::<Color>()?, Color::rgb(255, 0, 0));
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::utils::{Deserialize, Serialize};
+
+/// Thickness of a font, from 100 (thin) to 900 (black).
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+#[repr(transparent)]
+pub struct FontWeight(pub u16);
+
+impl FontWeight {
+    pub const BLACK: FontWeight = FontWeight(900);
+    pub const BOLD: FontWeight = FontWeight(700);
+    pub const LIGHT: FontWeight = FontWeight(300);
+    pub const MEDIUM: FontWeight = FontWeight(500);
+    pub const REGULAR: FontWeight = FontWeight(400);
+    pub const SEMI_BOLD: FontWeight = FontWeight(600);
+    pub const THIN: FontWeight = FontWeight(100);
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        Self::REGULAR
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub enum FontStyle {
+    #[default]
+    Normal,
+    Italic,
+    Oblique,
+}
@@ -4,25 +4,96 @@
  */
 
 use crate::utils::{Deserialize, Serialize};
+use crate::{PlatingError, PlatingResult};
+
+mod color;
+pub use color::*;
+
+mod font;
+pub use font::*;
 
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Stateless {}
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+/// Whether a window has a title bar and frame.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Border {
     BORDER     = 1,
     BORDERLESS = 0,
 }
 
-//Color
+impl Default for Border {
+    fn default() -> Self {
+        Border::BORDER
+    }
+}
+
+/// Identifies a widget within an app, see
+/// [`Registry`](crate::utils::registry::Registry).
+#[derive(
+    Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+#[repr(transparent)]
+pub struct Id(pub String);
 
-//Font
+impl Id {
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        Self(id.into())
+    }
 
-//ID
+    /// Ids must not be empty.
+    pub fn validate(&self) -> PlatingResult<()> {
+        if self.0.is_empty() {
+            return Err(PlatingError::invalid("Id", "must not be empty"));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
 
-//Size
-//Percentage / Pixel
-//Width / height
+/// A length, either absolute or relative to the parent.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Length {
+    /// Logical pixels.
+    Pixel(f64),
+    /// Percent of the same dimension of the parent. `100.0` fills the parent.
+    Percent(f64),
+}
+
+impl Length {
+    /// Resolves to logical pixels, given the length of the parent.
+    pub fn resolve(&self, parent: f64) -> f64 {
+        match self {
+            Length::Pixel(pixel) => *pixel,
+            Length::Percent(percent) => parent * percent / 100.0,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        match self {
+            Length::Pixel(length) | Length::Percent(length) => *length < 0.0,
+        }
+    }
+}
+
+impl Default for Length {
+    fn default() -> Self {
+        Length::Pixel(0.0)
+    }
+}
 
 //Label
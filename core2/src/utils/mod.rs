@@ -13,9 +13,20 @@ pub(crate) use crate::utils::serde::{
 };
 
 pub mod data;
+pub mod registry;
+pub mod theme;
 
 mod properties;
-pub use properties::{setting_list, Property, SettingsList};
+pub use properties::{
+    setting_list,
+    Property,
+    ProvideChanged,
+    Queryable,
+    SettingsDiff,
+    SettingsList,
+};
+#[cfg(feature = "apply_possible")]
+pub use properties::{ApplyReport, PossibleSettings, ProvideOpt};
 
 mod children;
 pub use children::{
@@ -23,7 +34,11 @@ pub use children::{
     ChildOf,
     ChildrenList,
     ChildrenOutlet,
+    InheritEnabled,
+    InheritFrame,
     MenuOutlet,
     Outlet,
     OutletHolder,
+    StatusBarOutlet,
+    ToolbarOutlet,
 };
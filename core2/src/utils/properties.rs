@@ -20,9 +20,24 @@ where
     BACKEND: Backend,
 {
     fn provide(&self, target: &mut TARGET) -> PlatingResult<()>;
+
+    /// Rejects invalid values before they reach the backend.
+    fn validate(&self) -> PlatingResult<()> {
+        Ok(())
+    }
 }
 
 
+/// Read side of a [`Property`]: queries the current value from a live
+/// widget, including changes done by the user, like resizing a window.
+pub trait Queryable<STATE, TARGET, BACKEND>
+where
+    Self: Sized,
+    BACKEND: Backend,
+{
+    fn query(target: &TARGET) -> PlatingResult<Self>;
+}
+
 //todo: some generic system!{} macro
 impl<STATE, TARGET, BACKEND> Property<STATE, TARGET, BACKEND> for ()
 where
@@ -48,4 +63,286 @@ where
 
         Ok(())
     }
+
+    fn validate(&self) -> PlatingResult<()> {
+        self.0.validate()?;
+        self.1.validate()
+    }
+}
+
+
+/// Compares two settings lists of the same shape entry by entry.
+pub trait SettingsDiff {
+    /// For every entry, in order, whether it differs from `previous`.
+    fn changes(&self, previous: &Self) -> Vec<bool> {
+        let mut changes = Vec::new();
+        self.collect_changes(previous, &mut changes);
+        changes
+    }
+
+    fn collect_changes(&self, previous: &Self, changes: &mut Vec<bool>);
+}
+
+impl SettingsDiff for () {
+    fn collect_changes(&self, _previous: &Self, _changes: &mut Vec<bool>) {}
+}
+
+impl<Head, Tail> SettingsDiff for (Head, Tail)
+where
+    Head: PartialEq,
+    Tail: SettingsDiff + SettingsList,
+{
+    fn collect_changes(&self, previous: &Self, changes: &mut Vec<bool>) {
+        changes.push(self.0 != previous.0);
+        self.1.collect_changes(&previous.1, changes);
+    }
+}
+
+/// Applies only the entries of a settings list which changed, see
+/// [`apply_changed`](crate::native::NativeWidget::apply_changed).
+pub trait ProvideChanged<STATE, TARGET, BACKEND>
+where
+    Self: SettingsDiff + Property<STATE, TARGET, BACKEND>,
+    BACKEND: Backend,
+{
+    /// Provides every entry which differs from `previous` and returns how
+    /// many got provided.
+    fn provide_changed(&self, previous: &Self, target: &mut TARGET) -> PlatingResult<usize>;
+}
+
+impl<STATE, TARGET, BACKEND> ProvideChanged<STATE, TARGET, BACKEND> for ()
+where
+    TARGET: NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide_changed(&self, _previous: &Self, _target: &mut TARGET) -> PlatingResult<usize> {
+        Ok(0)
+    }
+}
+
+impl<STATE, TARGET, BACKEND, Head, Tail> ProvideChanged<STATE, TARGET, BACKEND> for (Head, Tail)
+where
+    BACKEND: Backend,
+    Head: Property<STATE, TARGET, BACKEND>,
+    TARGET: NativeWidget<STATE, Backend = BACKEND>,
+    Tail: ProvideChanged<STATE, TARGET, BACKEND> + SettingsList,
+{
+    fn provide_changed(&self, previous: &Self, target: &mut TARGET) -> PlatingResult<usize> {
+        let mut provided = 0;
+        if self.0 != previous.0 {
+            self.0.provide(target)?;
+            provided += 1;
+        }
+        Ok(provided + self.1.provide_changed(&previous.1, target)?)
+    }
+}
+
+/// Outcome of [`apply_possible`](crate::native::NativeWidget::apply_possible).
+#[cfg(feature = "apply_possible")]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ApplyReport {
+    /// Type names of the settings the widget does not understand.
+    pub ignored: Vec<&'static str>,
+}
+
+#[cfg(feature = "apply_possible")]
+impl ApplyReport {
+    /// Whether every setting got applied.
+    pub fn is_complete(&self) -> bool {
+        self.ignored.is_empty()
+    }
+}
+
+/// Applies a single setting if it is a [`Property`] of `TARGET`.
+///
+/// Returns `None` for settings `TARGET` does not understand.
+#[cfg(feature = "apply_possible")]
+pub trait ProvideOpt<STATE, TARGET, BACKEND>
+where
+    BACKEND: Backend,
+{
+    fn provide_opt(&self, target: &mut TARGET) -> Option<PlatingResult<()>>;
+}
+
+#[cfg(feature = "apply_possible")]
+impl<STATE, TARGET, BACKEND, T> ProvideOpt<STATE, TARGET, BACKEND> for T
+where
+    BACKEND: Backend,
+{
+    default fn provide_opt(&self, _target: &mut TARGET) -> Option<PlatingResult<()>> {
+        None
+    }
+}
+
+#[cfg(feature = "apply_possible")]
+impl<STATE, TARGET, BACKEND, T> ProvideOpt<STATE, TARGET, BACKEND> for T
+where
+    BACKEND: Backend,
+    T: Property<STATE, TARGET, BACKEND>,
+{
+    fn provide_opt(&self, target: &mut TARGET) -> Option<PlatingResult<()>> {
+        Some(self.validate().and_then(|()| self.provide(target)))
+    }
+}
+
+/// A settings list where only some settings need to be understood by
+/// `TARGET`, see
+/// [`apply_possible`](crate::native::NativeWidget::apply_possible).
+#[cfg(feature = "apply_possible")]
+pub trait PossibleSettings<STATE, TARGET, BACKEND>
+where
+    BACKEND: Backend,
+{
+    fn provide_possible(&self, target: &mut TARGET, report: &mut ApplyReport) -> PlatingResult<()>;
+}
+
+#[cfg(feature = "apply_possible")]
+impl<STATE, TARGET, BACKEND> PossibleSettings<STATE, TARGET, BACKEND> for ()
+where
+    BACKEND: Backend,
+{
+    fn provide_possible(
+        &self,
+        _target: &mut TARGET,
+        _report: &mut ApplyReport,
+    ) -> PlatingResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "apply_possible")]
+impl<STATE, TARGET, BACKEND, Head, Tail> PossibleSettings<STATE, TARGET, BACKEND> for (Head, Tail)
+where
+    BACKEND: Backend,
+    Tail: PossibleSettings<STATE, TARGET, BACKEND> + SettingsList,
+{
+    fn provide_possible(&self, target: &mut TARGET, report: &mut ApplyReport) -> PlatingResult<()> {
+        match <Head as ProvideOpt<STATE, TARGET, BACKEND>>::provide_opt(&self.0, target) {
+            Some(result) => result?,
+            None => report.ignored.push(std::any::type_name::<Head>()),
+        }
+        self.1.provide_possible(target, report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "apply_possible")]
+    use std::any::type_name;
+
+    #[cfg(feature = "apply_possible")]
+    use super::{ApplyReport, PossibleSettings};
+    use super::{setting_list, Property, ProvideChanged, SettingsDiff, SettingsList};
+    use crate::native::{Native, NativeWidget};
+    use crate::widget::properties::{Enabled, Label, Visible};
+    use crate::widget::Backend;
+    use crate::PlatingResult;
+
+    #[derive(Debug)]
+    struct Test;
+
+    impl Backend for Test {}
+
+    impl Native<Test> for Label {}
+    impl Native<Test> for Enabled {}
+
+    /// Understands [`Label`] and [`Enabled`] and counts how often they got
+    /// applied.
+    #[derive(Debug, Default)]
+    struct Recorder {
+        label:    Option<Label>,
+        enabled:  Option<Enabled>,
+        provided: usize,
+    }
+
+    impl NativeWidget<()> for Recorder {
+        type Backend = Test;
+        type InternalHandle = ();
+
+        fn apply<SL>(&mut self, settings: &SL) -> PlatingResult<()>
+        where
+            SL: SettingsList + Property<(), Self, Self::Backend> + Native<Self::Backend>,
+        {
+            settings.provide(self)
+        }
+    }
+
+    impl Property<(), Recorder, Test> for Label {
+        fn provide(&self, target: &mut Recorder) -> PlatingResult<()> {
+            target.label = Some(self.clone());
+            target.provided += 1;
+            Ok(())
+        }
+    }
+
+    impl Property<(), Recorder, Test> for Enabled {
+        fn provide(&self, target: &mut Recorder) -> PlatingResult<()> {
+            target.enabled = Some(*self);
+            target.provided += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn changes_marks_differing_entries() {
+        let previous = setting_list!(Label(String::from("a")), Enabled(true), Visible(true));
+        let settings = setting_list!(Label(String::from("a")), Enabled(false), Visible(false));
+
+        assert_eq!(settings.changes(&previous), vec![false, true, true]);
+        assert_eq!(settings.changes(&settings), vec![false, false, false]);
+        assert!(().changes(&()).is_empty());
+    }
+
+    #[test]
+    fn provide_changed_skips_unchanged_entries() -> PlatingResult<()> {
+        let mut recorder = Recorder::default();
+
+        let previous = setting_list!(Label(String::from("a")), Enabled(true));
+        let settings = setting_list!(Label(String::from("b")), Enabled(true));
+        let provided = <_ as ProvideChanged<(), Recorder, Test>>::provide_changed(
+            &settings,
+            &previous,
+            &mut recorder,
+        )?;
+
+        assert_eq!(provided, 1);
+        assert_eq!(recorder.provided, 1);
+        assert_eq!(recorder.label, Some(Label(String::from("b"))));
+        // unchanged, so not applied again
+        assert_eq!(recorder.enabled, None);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_changed_returns_number_of_applied_settings() -> PlatingResult<()> {
+        let mut recorder = Recorder::default();
+
+        let previous = setting_list!(Label(String::from("a")), Enabled(true));
+        let settings = setting_list!(Label(String::from("b")), Enabled(false));
+
+        assert_eq!(recorder.apply_changed(&settings, &previous)?, 2);
+        assert_eq!(recorder.apply_changed(&settings, &settings)?, 0);
+        assert_eq!(recorder.label, Some(Label(String::from("b"))));
+        assert_eq!(recorder.enabled, Some(Enabled(false)));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "apply_possible")]
+    fn provide_possible_reports_ignored_settings() -> PlatingResult<()> {
+        let mut recorder = Recorder::default();
+        let mut report = ApplyReport::default();
+
+        let settings = setting_list!(Label(String::from("a")), Visible(false));
+        <_ as PossibleSettings<(), Recorder, Test>>::provide_possible(
+            &settings,
+            &mut recorder,
+            &mut report,
+        )?;
+
+        assert_eq!(recorder.label, Some(Label(String::from("a"))));
+        assert!(!report.is_complete());
+        assert_eq!(report.ignored, vec![type_name::<Visible>()]);
+        Ok(())
+    }
 }
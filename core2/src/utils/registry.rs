@@ -0,0 +1,321 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::any::{type_name, Any, TypeId};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+use crate::utils::data::Id;
+use crate::{PlatingError, PlatingResult};
+
+/// Widgets which can carry an [`Id`].
+pub trait Identifiable {
+    fn id(&self) -> Option<Id>;
+}
+
+#[derive(Debug)]
+struct Entry {
+    type_name: &'static str,
+    /// Token of the [`Reservation`] holding the id.
+    owner:     u64,
+    /// `None` while the widget is built but not registered yet.
+    widget:    Option<(TypeId, Weak<dyn Any>)>,
+}
+
+impl Entry {
+    fn is_alive(&self) -> bool {
+        match &self.widget {
+            None => true,
+            Some((_, widget)) => widget.strong_count() > 0,
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: Rc<Registry> = Rc::new(Registry::new());
+}
+
+/// Maps [`Id`]s to widgets, to look them up from anywhere in the app.
+///
+/// Ids get reserved when the [`Id`](crate::widget::properties::Id) setting
+/// is applied, so building two widgets with the same id fails. Widgets built
+/// using [`new_shared`](crate::widget::WidgetBuilder::new_shared) get
+/// [`register`](Self::register)ed as well, which makes them available for
+/// [`get`](Self::get). Ids are free again once their [`Reservation`] is
+/// dropped, i.e. the widget changed its id or got dropped.
+#[derive(Debug, Default)]
+pub struct Registry {
+    entries:    RefCell<HashMap<Id, Entry>>,
+    next_owner: Cell<u64>,
+}
+
+/// Keeps an [`Id`] reserved in the [`Registry`] until dropped.
+pub struct Reservation {
+    registry: Rc<Registry>,
+    id: Id,
+    owner: u64,
+}
+
+impl Reservation {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.registry.release(&self.id, self.owner);
+    }
+}
+
+impl std::fmt::Debug for Reservation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reservation")
+            .field("id", &self.id)
+            .field("owner", &self.owner)
+            .finish()
+    }
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The registry of the current (UI) thread.
+    pub fn current() -> Rc<Registry> {
+        CURRENT.with(Rc::clone)
+    }
+
+    /// Claims `id` for a widget of type `WIDGET` being built, until the
+    /// returned [`Reservation`] is dropped.
+    pub fn reserve<WIDGET>(self: &Rc<Self>, id: &Id) -> PlatingResult<Reservation> {
+        let mut entries = self.entries.borrow_mut();
+        if let Some(entry) = entries.get(id).filter(|entry| entry.is_alive()) {
+            return Err(PlatingError::DuplicateId {
+                id:      id.clone(),
+                used_by: entry.type_name,
+            });
+        }
+
+        let owner = self.next_owner.get();
+        self.next_owner.set(owner + 1);
+        entries.insert(
+            id.clone(),
+            Entry {
+                type_name: type_name::<WIDGET>(),
+                owner,
+                widget: None,
+            },
+        );
+        Ok(Reservation {
+            registry: Rc::clone(self),
+            id: id.clone(),
+            owner,
+        })
+    }
+
+    /// Frees `id` again, if it still belongs to `owner`.
+    fn release(&self, id: &Id, owner: u64) {
+        let mut entries = self.entries.borrow_mut();
+        if entries.get(id).is_some_and(|entry| entry.owner == owner) {
+            entries.remove(id);
+        }
+    }
+
+    /// Makes `widget` available for lookup by its id. Widgets without an id
+    /// are ignored.
+    ///
+    /// The registry does not keep the widget alive. Fails if the id of
+    /// `widget` is not reserved.
+    pub fn register<WIDGET>(&self, widget: &Rc<RefCell<WIDGET>>) -> PlatingResult<()>
+    where
+        WIDGET: Identifiable + 'static,
+    {
+        let id = match widget.borrow().id() {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+
+        let mut entries = self.entries.borrow_mut();
+        // only the widget holding the reservation carries the id
+        let entry = entries
+            .get_mut(&id)
+            .ok_or_else(|| PlatingError::UnknownId(id.clone()))?;
+
+        let weak: Weak<RefCell<WIDGET>> = Rc::downgrade(widget);
+        entry.type_name = type_name::<WIDGET>();
+        entry.widget = Some((TypeId::of::<RefCell<WIDGET>>(), weak));
+        Ok(())
+    }
+
+    /// Looks up the widget with `id`.
+    ///
+    /// Fails if there is no such (registered) widget or it is not a `WIDGET`.
+    pub fn get<WIDGET>(&self, id: &Id) -> PlatingResult<Rc<RefCell<WIDGET>>>
+    where
+        WIDGET: 'static,
+    {
+        let entries = self.entries.borrow();
+        let entry = entries
+            .get(id)
+            .filter(|entry| entry.is_alive())
+            .ok_or_else(|| PlatingError::UnknownId(id.clone()))?;
+        let (type_id, widget) = entry
+            .widget
+            .as_ref()
+            .ok_or_else(|| PlatingError::UnknownId(id.clone()))?;
+
+        if *type_id != TypeId::of::<RefCell<WIDGET>>() {
+            return Err(PlatingError::WrongType {
+                id: id.clone(),
+                expected: type_name::<WIDGET>(),
+                found: entry.type_name,
+            });
+        }
+
+        widget
+            .upgrade()
+            .and_then(|widget| widget.downcast::<RefCell<WIDGET>>().ok())
+            .ok_or_else(|| PlatingError::UnknownId(id.clone()))
+    }
+
+    /// All ids in use, e.g. for test automation.
+    pub fn ids(&self) -> Vec<Id> {
+        let mut ids: Vec<Id> = self
+            .entries
+            .borrow()
+            .iter()
+            .filter(|(_, entry)| entry.is_alive())
+            .map(|(id, _)| id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::type_name;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::{Identifiable, Registry, Reservation};
+    use crate::utils::data::Id;
+    use crate::{PlatingError, PlatingResult};
+
+    #[derive(Debug, Default)]
+    struct Button {
+        id: Option<Reservation>,
+    }
+
+    impl Identifiable for Button {
+        fn id(&self) -> Option<Id> {
+            self.id.as_ref().map(|reservation| reservation.id().clone())
+        }
+    }
+
+    #[derive(Debug)]
+    struct Label;
+
+    fn id(id: &str) -> Id {
+        Id(String::from(id))
+    }
+
+    fn button(registry: &Rc<Registry>, name: &str) -> PlatingResult<Rc<RefCell<Button>>> {
+        let button = Rc::new(RefCell::new(Button {
+            id: Some(registry.reserve::<Button>(&id(name))?),
+        }));
+        registry.register(&button)?;
+        Ok(button)
+    }
+
+    #[test]
+    fn duplicate_ids_are_rejected() -> PlatingResult<()> {
+        let registry = Rc::new(Registry::new());
+        let _first = registry.reserve::<Button>(&id("ok"))?;
+
+        assert!(matches!(
+            registry.reserve::<Label>(&id("ok")),
+            Err(PlatingError::DuplicateId { used_by, .. }) if used_by == type_name::<Button>()
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn get_returns_registered_widget() -> PlatingResult<()> {
+        let registry = Rc::new(Registry::new());
+        let button = button(&registry, "ok")?;
+
+        assert!(Rc::ptr_eq(&registry.get::<Button>(&id("ok"))?, &button));
+        assert_eq!(registry.ids(), vec![id("ok")]);
+        Ok(())
+    }
+
+    #[test]
+    fn get_with_wrong_type_fails() -> PlatingResult<()> {
+        let registry = Rc::new(Registry::new());
+        let _button = button(&registry, "ok")?;
+
+        assert!(matches!(
+            registry.get::<Label>(&id("ok")),
+            Err(PlatingError::WrongType { found, .. }) if found == type_name::<Button>()
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_but_unregistered_ids_are_unknown() -> PlatingResult<()> {
+        let registry = Rc::new(Registry::new());
+        let _reservation = registry.reserve::<Button>(&id("ok"))?;
+
+        assert!(matches!(
+            registry.get::<Button>(&id("ok")),
+            Err(PlatingError::UnknownId(_))
+        ));
+        assert!(matches!(
+            registry.get::<Button>(&id("cancel")),
+            Err(PlatingError::UnknownId(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn ids_are_freed_on_drop() -> PlatingResult<()> {
+        let registry = Rc::new(Registry::new());
+        drop(registry.reserve::<Button>(&id("ok"))?);
+        assert!(registry.ids().is_empty());
+
+        let button = button(&registry, "ok")?;
+        drop(button);
+
+        assert!(registry.ids().is_empty());
+        assert!(matches!(
+            registry.get::<Button>(&id("ok")),
+            Err(PlatingError::UnknownId(_))
+        ));
+        let _reservation = registry.reserve::<Label>(&id("ok"))?;
+        Ok(())
+    }
+
+    #[test]
+    fn changing_the_id_frees_the_old_one() -> PlatingResult<()> {
+        let registry = Rc::new(Registry::new());
+        let button = button(&registry, "ok")?;
+
+        // drops the old reservation
+        button.borrow_mut().id = Some(registry.reserve::<Button>(&id("cancel"))?);
+        registry.register(&button)?;
+
+        assert!(matches!(
+            registry.get::<Button>(&id("ok")),
+            Err(PlatingError::UnknownId(_))
+        ));
+        assert!(Rc::ptr_eq(&registry.get::<Button>(&id("cancel"))?, &button));
+        assert_eq!(registry.ids(), vec![id("cancel")]);
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use crate::native::events::SystemEvent;
+use crate::utils::data::Color;
+use crate::utils::{Deserialize, Serialize};
+
+/// The semantic meaning of a color, looked up in the current [`Palette`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ColorRole {
+    Accent,
+    Background,
+    Foreground,
+    Error,
+    Warning,
+    Success,
+}
+
+/// Maps every [`ColorRole`] to a color.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Palette {
+    pub accent:     Color,
+    pub background: Color,
+    pub foreground: Color,
+    pub error:      Color,
+    pub warning:    Color,
+    pub success:    Color,
+}
+
+impl Palette {
+    pub const fn light() -> Self {
+        Self {
+            accent:     Color::rgb(0, 122, 255),
+            background: Color::rgb(255, 255, 255),
+            foreground: Color::rgb(0, 0, 0),
+            error:      Color::rgb(215, 0, 21),
+            warning:    Color::rgb(255, 149, 0),
+            success:    Color::rgb(36, 138, 61),
+        }
+    }
+
+    pub const fn dark() -> Self {
+        Self {
+            accent:     Color::rgb(10, 132, 255),
+            background: Color::rgb(30, 30, 30),
+            foreground: Color::rgb(255, 255, 255),
+            error:      Color::rgb(255, 69, 58),
+            warning:    Color::rgb(255, 159, 10),
+            success:    Color::rgb(48, 209, 88),
+        }
+    }
+
+    pub fn get(&self, role: ColorRole) -> Color {
+        match role {
+            ColorRole::Accent => self.accent,
+            ColorRole::Background => self.background,
+            ColorRole::Foreground => self.foreground,
+            ColorRole::Error => self.error,
+            ColorRole::Warning => self.warning,
+            ColorRole::Success => self.success,
+        }
+    }
+
+    pub fn with(mut self, role: ColorRole, color: Color) -> Self {
+        match role {
+            ColorRole::Accent => self.accent = color,
+            ColorRole::Background => self.background = color,
+            ColorRole::Foreground => self.foreground = color,
+            ColorRole::Error => self.error = color,
+            ColorRole::Warning => self.warning = color,
+            ColorRole::Success => self.success = color,
+        }
+        self
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// Either a fixed color or a role that follows the current [`Theme`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ColorValue {
+    Fixed(Color),
+    Role(ColorRole),
+}
+
+impl ColorValue {
+    pub fn resolve(&self, palette: &Palette) -> Color {
+        match self {
+            ColorValue::Fixed(color) => *color,
+            ColorValue::Role(role) => palette.get(*role),
+        }
+    }
+}
+
+impl From<Color> for ColorValue {
+    fn from(color: Color) -> Self {
+        ColorValue::Fixed(color)
+    }
+}
+
+impl From<ColorRole> for ColorValue {
+    fn from(role: ColorRole) -> Self {
+        ColorValue::Role(role)
+    }
+}
+
+thread_local! {
+    static CURRENT: Rc<Theme> = Rc::new(Theme::new(Palette::default()));
+}
+
+type Subscriber = Rc<dyn Fn(&SystemEvent)>;
+
+/// The palette used to resolve [`ColorRole`]s.
+///
+/// Widgets using roles subscribe to the theme and get a
+/// [`SystemEvent::ThemeChange`] whenever the palette gets swapped.
+pub struct Theme {
+    palette:     RefCell<Palette>,
+    subscribers: RefCell<Vec<(u64, Subscriber)>>,
+    next_id:     Cell<u64>,
+}
+
+/// Keeps a subscriber of a [`Theme`] alive until dropped.
+///
+/// Does not keep the theme itself alive.
+#[derive(Debug)]
+#[must_use = "the subscriber is removed again once the subscription is dropped"]
+pub struct Subscription {
+    theme: Weak<Theme>,
+    id:    u64,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(theme) = self.theme.upgrade() {
+            let mut subscribers = theme.subscribers.borrow_mut();
+            let removed = subscribers
+                .iter()
+                .position(|(id, _)| *id == self.id)
+                .map(|index| subscribers.remove(index));
+            // the subscriber might own subscriptions itself
+            drop(subscribers);
+            drop(removed);
+        }
+    }
+}
+
+impl Theme {
+    pub fn new(palette: Palette) -> Self {
+        Self {
+            palette:     RefCell::new(palette),
+            subscribers: RefCell::new(Vec::new()),
+            next_id:     Cell::new(0),
+        }
+    }
+
+    /// The theme of the current (UI) thread.
+    pub fn current() -> Rc<Theme> {
+        CURRENT.with(Rc::clone)
+    }
+
+    pub fn palette(&self) -> Palette {
+        self.palette.borrow().clone()
+    }
+
+    pub fn resolve(&self, color: &ColorValue) -> Color {
+        color.resolve(&self.palette.borrow())
+    }
+
+    /// Calls `subscriber` on every theme change, until the returned
+    /// [`Subscription`] is dropped.
+    pub fn subscribe<F>(self: &Rc<Self>, subscriber: F) -> Subscription
+    where
+        F: Fn(&SystemEvent) + 'static,
+    {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.subscribers
+            .borrow_mut()
+            .push((id, Rc::new(subscriber)));
+        Subscription {
+            theme: Rc::downgrade(self),
+            id,
+        }
+    }
+
+    /// Swaps the palette at runtime and sends a [`SystemEvent::ThemeChange`]
+    /// to all subscribers.
+    ///
+    /// Subscribers may (un)subscribe while being called, changes take effect
+    /// with the next theme change.
+    pub fn change(&self, palette: Palette) {
+        *self.palette.borrow_mut() = palette.clone();
+
+        let subscribers: Vec<Subscriber> = self
+            .subscribers
+            .borrow()
+            .iter()
+            .map(|(_, subscriber)| Rc::clone(subscriber))
+            .collect();
+        let event = SystemEvent::ThemeChange(palette);
+        for subscriber in subscribers {
+            subscriber(&event);
+        }
+    }
+}
+
+impl std::fmt::Debug for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Theme")
+            .field("palette", &self.palette.borrow())
+            .field("subscribers", &self.subscribers.borrow().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::{ColorRole, ColorValue, Palette, Theme};
+    use crate::native::events::SystemEvent;
+    use crate::utils::data::Color;
+
+    fn theme() -> Rc<Theme> {
+        Rc::new(Theme::new(Palette::light()))
+    }
+
+    #[test]
+    fn resolve_follows_the_palette() {
+        let theme = theme();
+        let accent = ColorValue::Role(ColorRole::Accent);
+        let fixed = ColorValue::Fixed(Color::rgb(1, 2, 3));
+        assert_eq!(theme.resolve(&accent), Palette::light().accent);
+
+        theme.change(Palette::dark());
+
+        assert_eq!(theme.resolve(&accent), Palette::dark().accent);
+        assert_eq!(theme.resolve(&fixed), Color::rgb(1, 2, 3));
+    }
+
+    #[test]
+    fn change_notifies_subscribers() {
+        let theme = theme();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        let log = Rc::clone(&received);
+        let subscription = theme.subscribe(move |event| {
+            if let SystemEvent::ThemeChange(palette) = event {
+                log.borrow_mut().push(palette.background);
+            }
+        });
+        theme.change(Palette::dark());
+        drop(subscription);
+        theme.change(Palette::light());
+
+        assert_eq!(*received.borrow(), vec![Palette::dark().background]);
+    }
+
+    #[test]
+    fn subscribers_may_subscribe_while_notified() {
+        let theme = theme();
+        let nested = Rc::new(RefCell::new(Vec::new()));
+
+        let inner_theme = Rc::clone(&theme);
+        let inner = Rc::clone(&nested);
+        let _subscription = theme.subscribe(move |_| {
+            inner.borrow_mut().push(inner_theme.subscribe(|_| {}));
+        });
+        theme.change(Palette::dark());
+
+        assert_eq!(nested.borrow().len(), 1);
+        // the nested subscriber is only called with the next change
+        theme.change(Palette::light());
+        assert_eq!(nested.borrow().len(), 2);
+    }
+}
@@ -3,48 +3,30 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+use plating_systems_macros::Outlets;
+
+#[cfg(target_os = "macos")]
 use crate::native::cocoa::{CocoaApp, CocoaAppWidget};
 use crate::native::{Native, NativeWidget, ToNative};
+#[cfg(target_os = "macos")]
 use crate::prelude::WidgetBuilder;
-use crate::utils::{
-    ChildOf,
-    ChildrenList,
-    ChildrenOutlet,
-    Outlet,
-    OutletHolder,
-    Property,
-    SettingsList,
-};
+use crate::utils::{ChildOf, ChildrenList, ChildrenOutlet, Property, SettingsList};
 use crate::widget::properties::Label;
+#[cfg(target_os = "macos")]
 use crate::widget::system::cocoa::Cocoa;
 use crate::widget::{Backend, WidgetAbstractionLevel};
 use crate::PlatingResult;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Outlets)]
+#[outlets(path = crate::utils)]
 pub struct AppOutlet<CHILDREN>
 where
     CHILDREN: ChildrenList,
 {
+    #[outlet(ChildrenOutlet)]
     pub children: CHILDREN,
 }
 
-impl<CHILDREN> OutletHolder for AppOutlet<CHILDREN> where CHILDREN: ChildrenList {}
-
-impl<CHILDREN> Outlet<ChildrenOutlet> for AppOutlet<CHILDREN>
-where
-    CHILDREN: ChildrenList,
-{
-    type Children = CHILDREN;
-
-    fn get(&self) -> &Self::Children {
-        &self.children
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.children
-    }
-}
-
 pub trait AppWidget<STATE, CHILDREN, BACKEND>
 where
     CHILDREN: ChildrenList,
@@ -68,6 +50,7 @@ where
         CHILDREN: ChildOf<Self> + Native<BACKEND>;
 }
 
+#[cfg(target_os = "macos")]
 impl<STATE, CHILDREN> WidgetBuilder<STATE, Cocoa> for CocoaAppWidget<STATE, CHILDREN>
 where
     CHILDREN: ChildrenList + ChildOf<Self> + Native<Cocoa>,
@@ -94,6 +77,7 @@ where
     }
 }
 
+#[cfg(target_os = "macos")]
 impl<STATE, CHILDREN> AppWidget<STATE, CHILDREN, Cocoa> for CocoaAppWidget<STATE, CHILDREN>
 where
     CHILDREN: ChildrenList,
@@ -111,7 +95,7 @@ where
     }
 
     fn set_label(&mut self, label: &Label) -> PlatingResult<()> {
-        <Self as CocoaApp<STATE, CHILDREN>>::set_label(self, &label.to_native())
+        <Self as CocoaApp<STATE, CHILDREN>>::set_label(self, &ToNative::<Cocoa>::to_native(label))
     }
 
     fn run(&mut self) -> PlatingResult<()>
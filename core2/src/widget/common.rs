@@ -0,0 +1,130 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+#[cfg(target_os = "macos")]
+use crate::native::cocoa::{CocoaCommon, CocoaMenuWidget, CocoaWindowWidget};
+#[cfg(target_os = "macos")]
+use crate::native::{NativeChildOf, ToNative};
+#[cfg(target_os = "macos")]
+use crate::utils::registry::Identifiable;
+#[cfg(target_os = "macos")]
+use crate::utils::{ChildrenList, InheritEnabled};
+#[cfg(target_os = "macos")]
+use crate::widget::cocoa::Cocoa;
+use crate::widget::properties::{Enabled, Id, Tooltip, Visible};
+use crate::widget::Backend;
+use crate::PlatingResult;
+
+/// Settings every widget supports, no matter its type.
+pub trait CommonWidget<STATE, BACKEND>
+where
+    Self: Sized,
+    BACKEND: Backend,
+{
+    fn set_visible(&mut self, visible: &Visible) -> PlatingResult<()>;
+    fn set_enabled(&mut self, enabled: &Enabled) -> PlatingResult<()>;
+    fn set_tooltip(&mut self, tooltip: &Tooltip) -> PlatingResult<()>;
+
+    /// Fails if another widget already uses `id`.
+    fn set_id(&mut self, id: &Id) -> PlatingResult<()>;
+
+    /// The widgets own setting, see [`is_enabled`](Self::is_enabled) for
+    /// the effective state.
+    fn enabled(&self) -> PlatingResult<Enabled>;
+    fn visible(&self) -> PlatingResult<Visible>;
+    fn tooltip(&self) -> PlatingResult<Tooltip>;
+    fn id(&self) -> PlatingResult<Option<Id>>;
+
+    /// Whether the widget and all its ancestors are enabled.
+    fn is_enabled(&self) -> bool;
+}
+
+#[cfg(target_os = "macos")]
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> CommonWidget<STATE, Cocoa>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + InheritEnabled,
+    MENU: ChildrenList + InheritEnabled,
+    TOOLBAR: ChildrenList + InheritEnabled,
+    STATUSBAR: ChildrenList + InheritEnabled,
+{
+    fn set_visible(&mut self, visible: &Visible) -> PlatingResult<()> {
+        <Self as CocoaCommon<STATE>>::set_visible(self, &ToNative::<Cocoa>::to_native(visible))
+    }
+
+    fn set_enabled(&mut self, enabled: &Enabled) -> PlatingResult<()> {
+        <Self as CocoaCommon<STATE>>::set_enabled(self, &ToNative::<Cocoa>::to_native(enabled))
+    }
+
+    fn set_tooltip(&mut self, tooltip: &Tooltip) -> PlatingResult<()> {
+        <Self as CocoaCommon<STATE>>::set_tooltip(self, &ToNative::<Cocoa>::to_native(tooltip))
+    }
+
+    fn set_id(&mut self, id: &Id) -> PlatingResult<()> {
+        <Self as CocoaCommon<STATE>>::set_id(self, &ToNative::<Cocoa>::to_native(id))
+    }
+
+    fn enabled(&self) -> PlatingResult<Enabled> {
+        <Self as CocoaCommon<STATE>>::enabled(self).map(|enabled| Enabled(enabled.0))
+    }
+
+    fn visible(&self) -> PlatingResult<Visible> {
+        <Self as CocoaCommon<STATE>>::visible(self).map(|visible| Visible(visible.0))
+    }
+
+    fn tooltip(&self) -> PlatingResult<Tooltip> {
+        <Self as CocoaCommon<STATE>>::tooltip(self).map(|tooltip| Tooltip(String::from(&tooltip.0)))
+    }
+
+    fn id(&self) -> PlatingResult<Option<Id>> {
+        Ok(<Self as Identifiable>::id(self))
+    }
+
+    fn is_enabled(&self) -> bool {
+        <Self as CocoaCommon<STATE>>::is_enabled(self)
+    }
+}
+
+#[cfg(target_os = "macos")]
+impl<STATE, CHILDREN> CommonWidget<STATE, Cocoa> for CocoaMenuWidget<STATE, CHILDREN>
+where
+    CHILDREN: ChildrenList + InheritEnabled + NativeChildOf<Self, STATE, Cocoa>,
+{
+    fn set_visible(&mut self, visible: &Visible) -> PlatingResult<()> {
+        <Self as CocoaCommon<STATE>>::set_visible(self, &ToNative::<Cocoa>::to_native(visible))
+    }
+
+    fn set_enabled(&mut self, enabled: &Enabled) -> PlatingResult<()> {
+        <Self as CocoaCommon<STATE>>::set_enabled(self, &ToNative::<Cocoa>::to_native(enabled))
+    }
+
+    fn set_tooltip(&mut self, tooltip: &Tooltip) -> PlatingResult<()> {
+        <Self as CocoaCommon<STATE>>::set_tooltip(self, &ToNative::<Cocoa>::to_native(tooltip))
+    }
+
+    fn set_id(&mut self, id: &Id) -> PlatingResult<()> {
+        <Self as CocoaCommon<STATE>>::set_id(self, &ToNative::<Cocoa>::to_native(id))
+    }
+
+    fn enabled(&self) -> PlatingResult<Enabled> {
+        <Self as CocoaCommon<STATE>>::enabled(self).map(|enabled| Enabled(enabled.0))
+    }
+
+    fn visible(&self) -> PlatingResult<Visible> {
+        <Self as CocoaCommon<STATE>>::visible(self).map(|visible| Visible(visible.0))
+    }
+
+    fn tooltip(&self) -> PlatingResult<Tooltip> {
+        <Self as CocoaCommon<STATE>>::tooltip(self).map(|tooltip| Tooltip(String::from(&tooltip.0)))
+    }
+
+    fn id(&self) -> PlatingResult<Option<Id>> {
+        Ok(<Self as Identifiable>::id(self))
+    }
+
+    fn is_enabled(&self) -> bool {
+        <Self as CocoaCommon<STATE>>::is_enabled(self)
+    }
+}
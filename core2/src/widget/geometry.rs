@@ -0,0 +1,93 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+#[cfg(target_os = "macos")]
+use crate::native::cocoa::{CocoaGeometry, CocoaWindowWidget};
+#[cfg(target_os = "macos")]
+use crate::native::ToNative;
+#[cfg(target_os = "macos")]
+use crate::utils::{ChildrenList, InheritFrame};
+#[cfg(target_os = "macos")]
+use crate::widget::cocoa::Cocoa;
+use crate::widget::properties::{BackgroundColor, MaxSize, MinSize, Position, Size};
+use crate::widget::Backend;
+use crate::PlatingResult;
+
+/// Settings of widgets taking up space on the screen.
+///
+/// Percentages are resolved against the frame of the parent, see
+/// [`InheritFrame`](crate::utils::InheritFrame).
+pub trait GeometryWidget<STATE, BACKEND>
+where
+    Self: Sized,
+    BACKEND: Backend,
+{
+    fn set_size(&mut self, size: &Size) -> PlatingResult<()>;
+    fn set_position(&mut self, position: &Position) -> PlatingResult<()>;
+    fn set_min_size(&mut self, min_size: &MinSize) -> PlatingResult<()>;
+    fn set_max_size(&mut self, max_size: &MaxSize) -> PlatingResult<()>;
+    fn set_background_color(&mut self, color: &BackgroundColor) -> PlatingResult<()>;
+
+    /// The current size in pixels, including changes by the user.
+    fn size(&self) -> PlatingResult<Size>;
+    /// The current position in pixels, including changes by the user.
+    fn position(&self) -> PlatingResult<Position>;
+    fn min_size(&self) -> PlatingResult<MinSize>;
+    fn max_size(&self) -> PlatingResult<MaxSize>;
+    fn background_color(&self) -> PlatingResult<BackgroundColor>;
+}
+
+#[cfg(target_os = "macos")]
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> GeometryWidget<STATE, Cocoa>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
+where
+    CHILDREN: ChildrenList + InheritFrame,
+    MENU: ChildrenList,
+    TOOLBAR: ChildrenList + InheritFrame,
+    STATUSBAR: ChildrenList + InheritFrame,
+{
+    fn set_size(&mut self, size: &Size) -> PlatingResult<()> {
+        <Self as CocoaGeometry<STATE>>::set_size(self, &ToNative::<Cocoa>::to_native(size))
+    }
+
+    fn set_position(&mut self, position: &Position) -> PlatingResult<()> {
+        <Self as CocoaGeometry<STATE>>::set_position(self, &ToNative::<Cocoa>::to_native(position))
+    }
+
+    fn set_min_size(&mut self, min_size: &MinSize) -> PlatingResult<()> {
+        <Self as CocoaGeometry<STATE>>::set_min_size(self, &ToNative::<Cocoa>::to_native(min_size))
+    }
+
+    fn set_max_size(&mut self, max_size: &MaxSize) -> PlatingResult<()> {
+        <Self as CocoaGeometry<STATE>>::set_max_size(self, &ToNative::<Cocoa>::to_native(max_size))
+    }
+
+    fn set_background_color(&mut self, color: &BackgroundColor) -> PlatingResult<()> {
+        <Self as CocoaGeometry<STATE>>::set_background_color(
+            self,
+            &ToNative::<Cocoa>::to_native(color),
+        )
+    }
+
+    fn size(&self) -> PlatingResult<Size> {
+        <Self as CocoaGeometry<STATE>>::size(self).map(|size| size.0)
+    }
+
+    fn position(&self) -> PlatingResult<Position> {
+        <Self as CocoaGeometry<STATE>>::position(self).map(|position| position.0)
+    }
+
+    fn min_size(&self) -> PlatingResult<MinSize> {
+        <Self as CocoaGeometry<STATE>>::min_size(self).map(|min_size| min_size.0)
+    }
+
+    fn max_size(&self) -> PlatingResult<MaxSize> {
+        <Self as CocoaGeometry<STATE>>::max_size(self).map(|max_size| max_size.0)
+    }
+
+    fn background_color(&self) -> PlatingResult<BackgroundColor> {
+        <Self as CocoaGeometry<STATE>>::background_color(self).map(|color| color.0)
+    }
+}
@@ -3,38 +3,24 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+use plating_systems_macros::Outlets;
+
 use crate::native::{Native, NativeWidget, ToNative};
-use crate::utils::{ChildrenList, ChildrenOutlet, Outlet, OutletHolder, Property, SettingsList};
-use crate::widget::properties::Label;
+use crate::utils::{ChildrenList, ChildrenOutlet, Property, SettingsList};
+use crate::widget::properties::{Font, Label};
 use crate::widget::{Backend, WidgetAbstractionLevel};
 use crate::PlatingResult;
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Outlets)]
+#[outlets(path = crate::utils)]
 pub struct MenuOutlet<CHILDREN>
 where
     CHILDREN: ChildrenList,
 {
+    #[outlet(ChildrenOutlet)]
     pub children: CHILDREN,
 }
 
-impl<CHILDREN> OutletHolder for MenuOutlet<CHILDREN> where CHILDREN: ChildrenList {}
-
-//todo: use makro
-impl<CHILDREN> Outlet<ChildrenOutlet> for MenuOutlet<CHILDREN>
-where
-    CHILDREN: ChildrenList,
-{
-    type Children = CHILDREN;
-
-    fn get(&self) -> &Self::Children {
-        &self.children
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.children
-    }
-}
-
 pub trait MenuWidget<STATE, BACKEND>
 where
     Self: Sized,
@@ -48,10 +34,11 @@ where
             SettingsList + Native<BACKEND> + Property<STATE, Self, BACKEND>;
 
     fn set_label(&mut self, label: &Label) -> PlatingResult<()>;
-    //todo: Disabled
-    //todo: visible
 
-    //todo: font
+    fn set_font(&mut self, font: &Font) -> PlatingResult<()>;
+
+    fn label(&self) -> PlatingResult<Label>;
+    fn font(&self) -> PlatingResult<Font>;
     //todo: hotkey
 
     //todo: minimum width, height
@@ -18,6 +18,12 @@ pub use menu::{MenuOutlet, MenuWidget};
 mod widget;
 pub use widget::{WidgetAbstractionLevel, WidgetBuilder};
 
+mod common;
+pub use common::CommonWidget;
+
+mod geometry;
+pub use geometry::GeometryWidget;
+
 
 pub mod properties;
 //pub use parameters::Parameters;
@@ -30,8 +36,14 @@ pub mod cocoa {
     pub use super::system::cocoa::Cocoa;
 }
 
+pub mod mock {
+    pub use super::system::mock::Mock;
+}
+
 pub mod prelude {
     pub use super::app::AppWidget;
+    pub use super::common::CommonWidget;
+    pub use super::geometry::GeometryWidget;
     pub use super::menu::MenuWidget;
     pub use super::system::prelude::*;
     pub use super::window::WindowWidget;
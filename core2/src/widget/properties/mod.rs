@@ -2,11 +2,14 @@
  * Copyright (2021) by Marcel Lambert.
  * This project is dual licensed under either MIT or Apache-2.0.
  */
-use super::{Backend, WindowWidget};
+use super::{Backend, CommonWidget, GeometryWidget, MenuWidget, WindowWidget};
 use crate::native::NativeWidget;
-use crate::utils::{Deserialize, Property, Serialize};
+pub use crate::utils::data::{Border, Id};
+use crate::utils::data::{FontStyle, FontWeight, Length};
+use crate::utils::theme::ColorValue;
+use crate::utils::{Deserialize, Property, Queryable, Serialize};
 use crate::widget::WidgetAbstractionLevel;
-use crate::PlatingResult;
+use crate::{PlatingError, PlatingResult};
 
 pub trait AssociatedType {
     type ASSOC;
@@ -30,3 +33,600 @@ where
         target.set_label(self)
     }
 }
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Label
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.label()
+    }
+}
+
+/// Size of a widget. For windows this is the size of the content area.
+///
+/// Percentages are relative to the parent, which is the screen for windows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Size {
+    pub width:  Length,
+    pub height: Length,
+}
+
+impl Size {
+    pub const fn new(width: Length, height: Length) -> Self {
+        Self { width, height }
+    }
+
+    /// Resolves width and height to logical pixels, given the parents size.
+    pub fn resolve(&self, parent_width: f64, parent_height: f64) -> (f64, f64) {
+        (
+            self.width.resolve(parent_width),
+            self.height.resolve(parent_height),
+        )
+    }
+
+    /// Sizes must not be negative.
+    pub fn validate(&self) -> PlatingResult<()> {
+        if self.width.is_negative() || self.height.is_negative() {
+            return Err(PlatingError::invalid(
+                "Size",
+                format!("{:?} x {:?} is negative", self.width, self.height),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl WidgetAbstractionLevel for Size {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Size
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_size(self)
+    }
+
+    fn validate(&self) -> PlatingResult<()> {
+        Size::validate(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Size
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.size()
+    }
+}
+
+/// Position of the top left corner of a widget, relative to the top left
+/// corner of its parent.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Position {
+    pub x: Length,
+    pub y: Length,
+}
+
+impl Position {
+    pub const fn new(x: Length, y: Length) -> Self {
+        Self { x, y }
+    }
+
+    /// Resolves x and y to logical pixels, given the parents size.
+    pub fn resolve(&self, parent_width: f64, parent_height: f64) -> (f64, f64) {
+        (self.x.resolve(parent_width), self.y.resolve(parent_height))
+    }
+}
+
+impl WidgetAbstractionLevel for Position {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Position
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_position(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Position
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.position()
+    }
+}
+
+/// The size the user cannot shrink a widget below.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct MinSize(pub Size);
+
+impl WidgetAbstractionLevel for MinSize {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for MinSize
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_min_size(self)
+    }
+
+    fn validate(&self) -> PlatingResult<()> {
+        self.0.validate()
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for MinSize
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.min_size()
+    }
+}
+
+/// The size the user cannot grow a widget beyond.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct MaxSize(pub Size);
+
+impl WidgetAbstractionLevel for MaxSize {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for MaxSize
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_max_size(self)
+    }
+
+    fn validate(&self) -> PlatingResult<()> {
+        self.0.validate()
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for MaxSize
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.max_size()
+    }
+}
+
+/// Background color of a widget. Colors given as a
+/// [`ColorRole`](crate::utils::theme::ColorRole) follow theme changes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct BackgroundColor(pub ColorValue);
+
+impl WidgetAbstractionLevel for BackgroundColor {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for BackgroundColor
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_background_color(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for BackgroundColor
+where
+    T: GeometryWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.background_color()
+    }
+}
+
+/// Font used for the text of a widget.
+///
+/// `families` are tried in order, the system font is used if none of them is
+/// installed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Font {
+    pub families: Vec<String>,
+    /// Size in points.
+    pub size:     f64,
+    pub weight:   FontWeight,
+    pub style:    FontStyle,
+}
+
+impl Font {
+    pub fn new(families: &[&str], size: f64) -> Self {
+        Self {
+            families: families
+                .iter()
+                .map(|family| String::from(*family))
+                .collect(),
+            size,
+            weight: FontWeight::default(),
+            style: FontStyle::default(),
+        }
+    }
+
+    pub fn with_weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn with_style(mut self, style: FontStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The size must be positive.
+    pub fn validate(&self) -> PlatingResult<()> {
+        if self.size.is_nan() || self.size <= 0.0 {
+            return Err(PlatingError::invalid(
+                "Font",
+                format!("size {} is not positive", self.size),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl WidgetAbstractionLevel for Font {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Font
+where
+    T: MenuWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_font(self)
+    }
+
+    fn validate(&self) -> PlatingResult<()> {
+        Font::validate(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Font
+where
+    T: MenuWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.font()
+    }
+}
+
+/// Whether a widget is shown. Hidden widgets keep their state and children.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct Visible(pub bool);
+
+impl Default for Visible {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl WidgetAbstractionLevel for Visible {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Visible
+where
+    T: CommonWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_visible(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Visible
+where
+    T: CommonWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.visible()
+    }
+}
+
+/// Whether a widget reacts to user input.
+///
+/// Disabling a widget disables all of its descendants as well, no matter
+/// their own setting. How a disabled widget behaves is up to the backend,
+/// e.g. a cocoa window ignores mouse events.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct Enabled(pub bool);
+
+impl Default for Enabled {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl WidgetAbstractionLevel for Enabled {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Enabled
+where
+    T: CommonWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_enabled(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Enabled
+where
+    T: CommonWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.enabled()
+    }
+}
+
+/// Text shown when hovering over a widget. An empty text removes the
+/// tooltip.
+#[derive(Debug, Default, Hash, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct Tooltip(pub String);
+
+impl WidgetAbstractionLevel for Tooltip {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Tooltip
+where
+    T: CommonWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_tooltip(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Tooltip
+where
+    T: CommonWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.tooltip()
+    }
+}
+
+impl WidgetAbstractionLevel for Id {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Id
+where
+    T: CommonWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_id(self)
+    }
+
+    fn validate(&self) -> PlatingResult<()> {
+        Id::validate(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Id
+where
+    T: CommonWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target
+            .id()?
+            .ok_or_else(|| PlatingError::invalid("Id", "widget has no id"))
+    }
+}
+
+impl WidgetAbstractionLevel for Border {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Border
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_border(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Border
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.border()
+    }
+}
+
+/// Whether the user can resize the window.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct Resizable(pub bool);
+
+impl Default for Resizable {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl WidgetAbstractionLevel for Resizable {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Resizable
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_resizable(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Resizable
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.resizable()
+    }
+}
+
+/// Whether the window has a close button.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct Closable(pub bool);
+
+impl Default for Closable {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl WidgetAbstractionLevel for Closable {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Closable
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_closable(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Closable
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.closable()
+    }
+}
+
+/// Whether the window has a minimize button.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct Minimizable(pub bool);
+
+impl Default for Minimizable {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl WidgetAbstractionLevel for Minimizable {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for Minimizable
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_minimizable(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for Minimizable
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.minimizable()
+    }
+}
+
+/// Hides the title bar, letting the content extend to the top edge of
+/// the window. The window buttons stay visible.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+#[repr(transparent)]
+pub struct TitleBarHidden(pub bool);
+
+impl WidgetAbstractionLevel for TitleBarHidden {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for TitleBarHidden
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_title_bar_hidden(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for TitleBarHidden
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.title_bar_hidden()
+    }
+}
+
+/// Keeps the window above all normal windows.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+#[repr(transparent)]
+pub struct AlwaysOnTop(pub bool);
+
+impl WidgetAbstractionLevel for AlwaysOnTop {}
+
+impl<STATE, T, BACKEND> Property<STATE, T, BACKEND> for AlwaysOnTop
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn provide(&self, target: &mut T) -> PlatingResult<()> {
+        target.set_always_on_top(self)
+    }
+}
+
+impl<STATE, T, BACKEND> Queryable<STATE, T, BACKEND> for AlwaysOnTop
+where
+    T: WindowWidget<STATE, BACKEND> + NativeWidget<STATE, Backend = BACKEND>,
+    BACKEND: Backend,
+{
+    fn query(target: &T) -> PlatingResult<Self> {
+        target.always_on_top()
+    }
+}
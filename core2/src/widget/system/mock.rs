@@ -0,0 +1,13 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use crate::prelude::Backend;
+
+/// Backend without any native toolkit. Its widgets only record the applied
+/// properties, so behavior can be tested on every platform.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Mock {}
+
+impl Backend for Mock {}
@@ -4,6 +4,7 @@
  */
 
 pub mod cocoa;
+pub mod mock;
 
 pub trait Backend: std::fmt::Debug + Sized {
     /*type Window<T, C: ChildrenList, M: ChildrenList>: NativeWidget<T, System = Self>
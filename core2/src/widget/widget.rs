@@ -3,8 +3,12 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use crate::native::{Native, NativeBuilder, ToNative};
 use crate::prelude::Backend;
+use crate::utils::registry::{Identifiable, Registry};
 use crate::utils::{OutletHolder, Property, SettingsList};
 use crate::PlatingResult;
 
@@ -54,4 +58,39 @@ where
         SL: SettingsList + WidgetAbstractionLevel + ToNative<BACKEND>,
         <SL as ToNative<BACKEND>>::Result:
             SettingsList + Native<BACKEND> + Property<STATE, Self, BACKEND>;
+
+    /// Builds the widget and [`register`](Registry::register)s it, so it can
+    /// be looked up by its [`Id`](crate::widget::properties::Id).
+    fn new_shared<SL>(
+        settings: SL,
+        outlet: <Self as WidgetBuilder<STATE, BACKEND>>::OutletType,
+    ) -> PlatingResult<Rc<RefCell<Self>>>
+    where
+        Self: NativeBuilder<STATE, Backend = BACKEND> + Identifiable + 'static,
+        STATE: Default,
+        SL: SettingsList + WidgetAbstractionLevel + ToNative<BACKEND>,
+        <SL as ToNative<BACKEND>>::Result:
+            SettingsList + Native<BACKEND> + Property<STATE, Self, BACKEND>,
+    {
+        WidgetBuilder::new_shared_with_state(STATE::default(), settings, outlet)
+    }
+
+    /// See [`new_shared`](Self::new_shared).
+    fn new_shared_with_state<SL>(
+        state: STATE,
+        settings: SL,
+        outlet: <Self as WidgetBuilder<STATE, BACKEND>>::OutletType,
+    ) -> PlatingResult<Rc<RefCell<Self>>>
+    where
+        Self: NativeBuilder<STATE, Backend = BACKEND> + Identifiable + 'static,
+        SL: SettingsList + WidgetAbstractionLevel + ToNative<BACKEND>,
+        <SL as ToNative<BACKEND>>::Result:
+            SettingsList + Native<BACKEND> + Property<STATE, Self, BACKEND>,
+    {
+        let widget = Rc::new(RefCell::new(WidgetBuilder::new_with_state(
+            state, settings, outlet,
+        )?));
+        Registry::current().register(&widget)?;
+        Ok(widget)
+    }
 }
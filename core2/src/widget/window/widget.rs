@@ -3,76 +3,57 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
+use plating_systems_macros::Outlets;
+
+#[cfg(target_os = "macos")]
 use crate::native::cocoa::{CocoaWindow, CocoaWindowWidget};
 use crate::native::{Native, NativeWidget, ToNative};
+#[cfg(target_os = "macos")]
 use crate::prelude::WidgetBuilder;
+#[cfg(target_os = "macos")]
+use crate::utils::ChildOf;
 use crate::utils::{
-    ChildOf,
     ChildrenList,
     ChildrenOutlet,
     MenuOutlet,
-    Outlet,
-    OutletHolder,
     Property,
     SettingsList,
+    StatusBarOutlet,
+    ToolbarOutlet,
 };
+#[cfg(target_os = "macos")]
 use crate::widget::cocoa::Cocoa;
-use crate::widget::properties::Label;
+use crate::widget::properties::{
+    AlwaysOnTop,
+    Border,
+    Closable,
+    Label,
+    Minimizable,
+    Resizable,
+    TitleBarHidden,
+};
 use crate::widget::{Backend, WidgetAbstractionLevel};
 use crate::PlatingResult;
 
-#[derive(Debug, Default)]
-pub struct WindowOutlet<CHILDREN, MENU>
+#[derive(Debug, Default, Outlets)]
+#[outlets(path = crate::utils)]
+pub struct WindowOutlet<CHILDREN, MENU, TOOLBAR = (), STATUSBAR = ()>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
+    #[outlet(ChildrenOutlet)]
     pub children: CHILDREN,
-    pub menu:     MENU,
-}
-
-impl<CHILDREN, MENU> OutletHolder for WindowOutlet<CHILDREN, MENU>
-where
-    CHILDREN: ChildrenList,
-    MENU: ChildrenList,
-{
-}
-
-impl<CHILDREN, MENU> Outlet<MenuOutlet> for WindowOutlet<CHILDREN, MENU>
-where
-    CHILDREN: ChildrenList,
-    MENU: ChildrenList,
-{
-    type Children = MENU;
-
-    fn get(&self) -> &Self::Children {
-        &self.menu
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.menu
-    }
-}
-
-
-impl<CHILDREN, MENU> Outlet<ChildrenOutlet> for WindowOutlet<CHILDREN, MENU>
-where
-    CHILDREN: ChildrenList,
-    MENU: ChildrenList,
-{
-    type Children = CHILDREN;
-
-    fn get(&self) -> &Self::Children {
-        &self.children
-    }
-
-    fn get_mut(&mut self) -> &mut Self::Children {
-        &mut self.children
-    }
+    #[outlet(MenuOutlet)]
+    pub menu: MENU,
+    #[outlet(ToolbarOutlet)]
+    pub toolbar: TOOLBAR,
+    #[outlet(StatusBarOutlet)]
+    pub status_bar: STATUSBAR,
 }
 
-
-
 pub trait WindowWidget<STATE, BACKEND>
 where
     Self: Sized,
@@ -86,14 +67,32 @@ where
             SettingsList + Native<BACKEND> + Property<STATE, Self, BACKEND>;
 
     fn set_label(&mut self, label: &Label) -> PlatingResult<()>;
+    fn set_border(&mut self, border: &Border) -> PlatingResult<()>;
+    fn set_resizable(&mut self, resizable: &Resizable) -> PlatingResult<()>;
+    fn set_closable(&mut self, closable: &Closable) -> PlatingResult<()>;
+    fn set_minimizable(&mut self, minimizable: &Minimizable) -> PlatingResult<()>;
+    fn set_title_bar_hidden(&mut self, title_bar_hidden: &TitleBarHidden) -> PlatingResult<()>;
+    fn set_always_on_top(&mut self, always_on_top: &AlwaysOnTop) -> PlatingResult<()>;
+
+    fn label(&self) -> PlatingResult<Label>;
+    fn border(&self) -> PlatingResult<Border>;
+    fn resizable(&self) -> PlatingResult<Resizable>;
+    fn closable(&self) -> PlatingResult<Closable>;
+    fn minimizable(&self) -> PlatingResult<Minimizable>;
+    fn title_bar_hidden(&self) -> PlatingResult<TitleBarHidden>;
+    fn always_on_top(&self) -> PlatingResult<AlwaysOnTop>;
 }
 
-impl<STATE, CHILDREN, MENU> WidgetBuilder<STATE, Cocoa> for CocoaWindowWidget<STATE, CHILDREN, MENU>
+#[cfg(target_os = "macos")]
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> WidgetBuilder<STATE, Cocoa>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList + ChildOf<Self> + Native<Cocoa>,
     MENU: ChildrenList + ChildOf<Self> + Native<Cocoa>,
+    TOOLBAR: ChildrenList + ChildOf<Self> + Native<Cocoa>,
+    STATUSBAR: ChildrenList + ChildOf<Self> + Native<Cocoa>,
 {
-    type OutletType = WindowOutlet<CHILDREN, MENU>;
+    type OutletType = WindowOutlet<CHILDREN, MENU, TOOLBAR, STATUSBAR>;
 
     fn new_with_state<SL>(
         state: STATE,
@@ -115,10 +114,14 @@ where
     }
 }
 
-impl<STATE, CHILDREN, MENU> WindowWidget<STATE, Cocoa> for CocoaWindowWidget<STATE, CHILDREN, MENU>
+#[cfg(target_os = "macos")]
+impl<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR> WindowWidget<STATE, Cocoa>
+    for CocoaWindowWidget<STATE, CHILDREN, MENU, TOOLBAR, STATUSBAR>
 where
     CHILDREN: ChildrenList,
     MENU: ChildrenList,
+    TOOLBAR: ChildrenList,
+    STATUSBAR: ChildrenList,
 {
     fn apply<SL>(&mut self, settings: SL) -> PlatingResult<()>
     where
@@ -132,6 +135,59 @@ where
     }
 
     fn set_label(&mut self, label: &Label) -> PlatingResult<()> {
-        <Self as CocoaWindow<STATE>>::set_label(self, &label.to_native())
+        <Self as CocoaWindow<STATE>>::set_label(self, &ToNative::<Cocoa>::to_native(label))
+    }
+
+    fn label(&self) -> PlatingResult<Label> {
+        <Self as CocoaWindow<STATE>>::label(self).map(Into::into)
+    }
+
+    fn set_border(&mut self, border: &Border) -> PlatingResult<()> {
+        <Self as CocoaWindow<STATE>>::set_border(self, &ToNative::<Cocoa>::to_native(border))
+    }
+
+    fn set_resizable(&mut self, resizable: &Resizable) -> PlatingResult<()> {
+        <Self as CocoaWindow<STATE>>::set_resizable(self, &ToNative::<Cocoa>::to_native(resizable))
+    }
+
+    fn set_closable(&mut self, closable: &Closable) -> PlatingResult<()> {
+        <Self as CocoaWindow<STATE>>::set_closable(self, &ToNative::<Cocoa>::to_native(closable))
+    }
+
+    fn set_minimizable(&mut self, minimizable: &Minimizable) -> PlatingResult<()> {
+        <Self as CocoaWindow<STATE>>::set_minimizable(self, &ToNative::<Cocoa>::to_native(minimizable))
+    }
+
+    fn set_title_bar_hidden(&mut self, title_bar_hidden: &TitleBarHidden) -> PlatingResult<()> {
+        <Self as CocoaWindow<STATE>>::set_title_bar_hidden(self, &ToNative::<Cocoa>::to_native(title_bar_hidden))
+    }
+
+    fn set_always_on_top(&mut self, always_on_top: &AlwaysOnTop) -> PlatingResult<()> {
+        <Self as CocoaWindow<STATE>>::set_always_on_top(self, &ToNative::<Cocoa>::to_native(always_on_top))
+    }
+
+    fn border(&self) -> PlatingResult<Border> {
+        <Self as CocoaWindow<STATE>>::border(self).map(|border| border.0)
+    }
+
+    fn resizable(&self) -> PlatingResult<Resizable> {
+        <Self as CocoaWindow<STATE>>::resizable(self).map(|resizable| resizable.0)
+    }
+
+    fn closable(&self) -> PlatingResult<Closable> {
+        <Self as CocoaWindow<STATE>>::closable(self).map(|closable| closable.0)
+    }
+
+    fn minimizable(&self) -> PlatingResult<Minimizable> {
+        <Self as CocoaWindow<STATE>>::minimizable(self).map(|minimizable| minimizable.0)
+    }
+
+    fn title_bar_hidden(&self) -> PlatingResult<TitleBarHidden> {
+        <Self as CocoaWindow<STATE>>::title_bar_hidden(self)
+            .map(|title_bar_hidden| title_bar_hidden.0)
+    }
+
+    fn always_on_top(&self) -> PlatingResult<AlwaysOnTop> {
+        <Self as CocoaWindow<STATE>>::always_on_top(self).map(|always_on_top| always_on_top.0)
     }
 }
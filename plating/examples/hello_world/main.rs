@@ -14,8 +14,10 @@ fn main() -> PlatingResult<()> {
     let window = <DefaultSystem as System>::Window::<(), _, _>::new(
         (),
         WindowOutlet {
-            children: (),
-            menu:     (),
+            children:   (),
+            menu:       (),
+            toolbar:    (),
+            status_bar: (),
         },
     )?;
     let mut app = <DefaultSystem as System>::App::new_with_state(
@@ -26,8 +26,46 @@ build_widget_list! {
         /// The Window type used by plating
         type Window<OUTLET: OutletHolder
                           + Outlet<MenuOutlet>
-                          + Outlet<ChildrenOutlet>>: Window<OUTLET>;
+                          + Outlet<ChildrenOutlet>
+                          + Outlet<ToolbarOutlet>
+                          + Outlet<StatusBarOutlet>>: Window<OUTLET>;
         /// The button type used by plating
         type Button<OUTLET: OutletHolder>: Button<OUTLET>;
+        /// The tab bar type used by plating
+        type Tabs<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: Tabs<OUTLET>;
+        /// The tab page type used by plating
+        type Tab<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: Tab<OUTLET>;
+        /// The virtualized list view type used by plating
+        type ListView<OUTLET: OutletHolder, SOURCE: DataSource>: ListView<OUTLET, SOURCE>;
+        /// The virtualized table view type used by plating
+        type TableView<OUTLET: OutletHolder, SOURCE: DataSource>: TableView<OUTLET, SOURCE>;
+        /// The hierarchical tree view type used by plating
+        type TreeView<OUTLET: OutletHolder, SOURCE: TreeDataSource>: TreeView<OUTLET, SOURCE>;
+        /// The scroll view type used by plating
+        type ScrollView<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: ScrollView<OUTLET>;
+        /// The split view type used by plating
+        type SplitView<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: SplitView<OUTLET>;
+        /// The grid type used by plating
+        type Grid<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: Grid<OUTLET>;
+        /// The group box type used by plating
+        type GroupBox<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: GroupBox<OUTLET>;
+        /// The menu type used by plating
+        type Menu<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: Menu<OUTLET>;
+        /// The menu item type used by plating
+        type MenuItem<OUTLET: OutletHolder>: MenuItem<OUTLET>;
+        /// The checkable menu item type used by plating
+        type CheckMenuItem<OUTLET: OutletHolder>: CheckMenuItem<OUTLET>;
+        /// The menu separator type used by plating
+        type MenuSeparator<OUTLET: OutletHolder>: MenuSeparator<OUTLET>;
+        /// The custom dialog type used by plating
+        type Dialog<OUTLET: OutletHolder
+                          + Outlet<MenuOutlet>
+                          + Outlet<ChildrenOutlet>>: Dialog<OUTLET>;
+        /// The file dialog type used by plating
+        type FileDialog: FileDialog;
+        /// The tray icon type used by plating
+        type TrayIcon<OUTLET: OutletHolder + Outlet<MenuOutlet>>: TrayIcon<OUTLET>;
+        /// The desktop notifications used by plating
+        type Notifications: Notifications;
     };
 }
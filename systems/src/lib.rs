@@ -80,7 +80,7 @@ pub mod tags;
 pub mod types;
 
 #[doc(inline)]
-pub use plating_systems_macros::{build_widget_list, marker, tag};
+pub use plating_systems_macros::{build_widget_list, marker, tag, widget, Native, Outlets};
 
 
 #[cfg(doc)]
@@ -224,6 +224,7 @@ compile_error!(
 mod tests {
     use plating_core::mock::MockButtonOutlet;
     use plating_core::prelude::*;
+    use plating_core::widgets::TabsOutlet;
 
     use super::types::HasButton;
     //use super::setup::system::CocoaSpecific;
@@ -235,13 +236,14 @@ mod tests {
         let _button_b = <Native as HasButton>::Button::new(MockButtonOutlet {});
 
         //let button_c = <Native as CocoaSpecific>::Button::new(5);
-        /*#[cfg(any(
+        #[cfg(any(
             all(feature = "android", target_os = "android"),
             all(feature = "ios", target_os = "ios"),
             all(feature = "mock", test)
         ))]
-        let button_a = <Native as crate::tags::Mobile>::Tabs::new(5);*/
-        //let b = <Native as Desktop>::Tabs::new(5);
+        let _tabs_a = <Native as crate::tags::Mobile>::Tabs::new(TabsOutlet { tabs: () });
+        let _tabs_b = <Native as Desktop>::Tabs::new(TabsOutlet { tabs: () });
+        let _file_dialog = <Native as Desktop>::FileDialog::new();
     }
 }
 
@@ -260,6 +262,7 @@ pub fn it_works() -> plating_core::PlatingResult<()> {
     use plating_core::mock::{MockOnly, MockOnlyWidget};
     use plating_core::prelude::*;
     use plating_core::utils::children::children_list;
+    use plating_core::widgets::{TabOutlet, TabsOutlet};
 
     use crate::tags::Desktop;
     use crate::types::HasButton;
@@ -267,17 +270,33 @@ pub fn it_works() -> plating_core::PlatingResult<()> {
     let button_a = <Native as Desktop>::Button::new(plating_core::mock::MockButtonOutlet {})?;
     let button_b = <Native as HasButton>::Button::new(plating_core::mock::MockButtonOutlet {})?;
     let mock_only = MockOnlyWidget::new(plating_core::mock::MockOnlyOutlet {})?;
+    let first_tab = <Native as Desktop>::Tab::new(
+        TabOutlet {
+            children: children_list!(button_b),
+        },
+        "First",
+    )?;
+    let second_tab = <Native as Desktop>::Tab::new(
+        TabOutlet {
+            children: children_list!(mock_only),
+        },
+        "Second",
+    )?;
+    let tabs = <Native as Desktop>::Tabs::new(TabsOutlet {
+        tabs: children_list!(first_tab, second_tab),
+    })?;
     let _wind = <Native as Desktop>::Window::new(plating_core::mock::MockWindowOutlet {
-        children: children_list!(button_a, button_b, mock_only),
-        menu:     children_list!(),
+        children: children_list!(button_a, tabs),
+        menu: children_list!(),
+        toolbar: (),
+        status_bar: (),
     })?;
     //let button_c = <Native as CocoaSpecific>::Button::new(5);
-    /*#[cfg(any(
+    #[cfg(any(
         all(feature = "android", target_os = "android"),
         all(feature = "ios", target_os = "ios"),
         all(feature = "mock", test)
     ))]
-    let button_a = <Native as Mobile>::Tabs::new(5);*/
-    //let b = <Native as Desktop>::Tabs::new(5);
+    let _mobile_tabs = <Native as tags::Mobile>::Tabs::new(TabsOutlet { tabs: () })?;
     Ok(())
 }
@@ -8,7 +8,21 @@
 use plating_core::utils::{Deserialize, Serialize};
 
 use crate::systems::SystemDefinition;
-use crate::types::{ButtonAvailable, WindowAvailable};
+use crate::types::{
+    ButtonAvailable,
+    CheckMenuItemAvailable,
+    DialogAvailable,
+    GridAvailable,
+    ListViewAvailable,
+    MenuAvailable,
+    MenuItemAvailable,
+    MenuSeparatorAvailable,
+    NotificationsAvailable,
+    ScrollViewAvailable,
+    TabAvailable,
+    TabsAvailable,
+    WindowAvailable,
+};
 
 /// [System Definition](`SystemDefinition`) for the Android OS.
 ///
@@ -23,6 +37,17 @@ impl SystemDefinition for AndroidDefinition {}
 
 impl ButtonAvailable for AndroidDefinition {}
 impl WindowAvailable for AndroidDefinition {}
+impl TabsAvailable for AndroidDefinition {}
+impl TabAvailable for AndroidDefinition {}
+impl ListViewAvailable for AndroidDefinition {}
+impl ScrollViewAvailable for AndroidDefinition {}
+impl MenuAvailable for AndroidDefinition {}
+impl MenuItemAvailable for AndroidDefinition {}
+impl CheckMenuItemAvailable for AndroidDefinition {}
+impl MenuSeparatorAvailable for AndroidDefinition {}
+impl DialogAvailable for AndroidDefinition {}
+impl NotificationsAvailable for AndroidDefinition {}
+impl GridAvailable for AndroidDefinition {}
 
 /// The Android [System](`crate::systems::System`)  .
 ///
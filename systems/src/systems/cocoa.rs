@@ -8,13 +8,62 @@
 //TODO; use real ones
 
 #[cfg(target_os = "macos")]
-use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+use plating_core::utils::outlet::{
+    ChildrenOutlet,
+    MenuOutlet,
+    Outlet,
+    OutletHolder,
+    StatusBarOutlet,
+    ToolbarOutlet,
+};
 use plating_core::utils::{Deserialize, Serialize};
+#[cfg(target_os = "macos")]
+use plating_core::widgets::{DataSource, TreeDataSource};
 
 use crate::systems::SystemDefinition;
-use crate::types::{ButtonAvailable, WindowAvailable};
+use crate::types::{
+    ButtonAvailable,
+    CheckMenuItemAvailable,
+    DialogAvailable,
+    FileDialogAvailable,
+    GridAvailable,
+    GroupBoxAvailable,
+    ListViewAvailable,
+    MenuAvailable,
+    MenuItemAvailable,
+    MenuSeparatorAvailable,
+    NotificationsAvailable,
+    ScrollViewAvailable,
+    SplitViewAvailable,
+    TabAvailable,
+    TableViewAvailable,
+    TabsAvailable,
+    TrayIconAvailable,
+    TreeViewAvailable,
+    WindowAvailable,
+};
 #[cfg(target_os = "macos")]
-use crate::types::{HasButton, HasWindow};
+use crate::types::{
+    HasButton,
+    HasCheckMenuItem,
+    HasDialog,
+    HasFileDialog,
+    HasGrid,
+    HasGroupBox,
+    HasListView,
+    HasMenu,
+    HasMenuItem,
+    HasMenuSeparator,
+    HasNotifications,
+    HasScrollView,
+    HasSplitView,
+    HasTab,
+    HasTableView,
+    HasTabs,
+    HasTrayIcon,
+    HasTreeView,
+    HasWindow,
+};
 
 /// [System Definition](`SystemDefinition`) for the Cocoa UI System.
 ///
@@ -29,6 +78,23 @@ pub struct CocoaDefinition {}
 impl SystemDefinition for CocoaDefinition {}
 impl ButtonAvailable for CocoaDefinition {}
 impl WindowAvailable for CocoaDefinition {}
+impl TabsAvailable for CocoaDefinition {}
+impl TabAvailable for CocoaDefinition {}
+impl ListViewAvailable for CocoaDefinition {}
+impl TableViewAvailable for CocoaDefinition {}
+impl TreeViewAvailable for CocoaDefinition {}
+impl ScrollViewAvailable for CocoaDefinition {}
+impl SplitViewAvailable for CocoaDefinition {}
+impl GroupBoxAvailable for CocoaDefinition {}
+impl MenuAvailable for CocoaDefinition {}
+impl MenuItemAvailable for CocoaDefinition {}
+impl CheckMenuItemAvailable for CocoaDefinition {}
+impl MenuSeparatorAvailable for CocoaDefinition {}
+impl DialogAvailable for CocoaDefinition {}
+impl FileDialogAvailable for CocoaDefinition {}
+impl TrayIconAvailable for CocoaDefinition {}
+impl NotificationsAvailable for CocoaDefinition {}
+impl GridAvailable for CocoaDefinition {}
 
 
 /// The Cocoa [System](`crate::systems::System`).
@@ -62,19 +128,125 @@ impl HasButton for Cocoa {
 
 #[cfg(target_os = "macos")]
 impl HasWindow for Cocoa {
-    type Window<OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>> =
-        plating_core::mock::MockWindowWidget<OUTLET>;
+    type Window<
+        OUTLET: OutletHolder
+            + Outlet<MenuOutlet>
+            + Outlet<ChildrenOutlet>
+            + Outlet<ToolbarOutlet>
+            + Outlet<StatusBarOutlet>,
+    > = plating_core::mock::MockWindowWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasTabs for Cocoa {
+    type Tabs<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> =
+        plating_core::mock::MockTabsWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasTab for Cocoa {
+    type Tab<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> =
+        plating_core::mock::MockTabWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasListView for Cocoa {
+    type ListView<OUTLET: OutletHolder, SOURCE: DataSource> =
+        plating_core::mock::MockListViewWidget<OUTLET, SOURCE>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasTableView for Cocoa {
+    type TableView<OUTLET: OutletHolder, SOURCE: DataSource> =
+        plating_core::mock::MockTableViewWidget<OUTLET, SOURCE>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasTreeView for Cocoa {
+    type TreeView<OUTLET: OutletHolder, SOURCE: TreeDataSource> =
+        plating_core::mock::MockTreeViewWidget<OUTLET, SOURCE>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasScrollView for Cocoa {
+    type ScrollView<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> =
+        plating_core::mock::MockScrollViewWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasSplitView for Cocoa {
+    type SplitView<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> =
+        plating_core::mock::MockSplitViewWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasGrid for Cocoa {
+    type Grid<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> =
+        plating_core::mock::MockGridWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasGroupBox for Cocoa {
+    type GroupBox<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> =
+        plating_core::mock::MockGroupBoxWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasMenu for Cocoa {
+    type Menu<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> =
+        plating_core::mock::MockMenuWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasMenuItem for Cocoa {
+    type MenuItem<OUTLET: OutletHolder> = plating_core::mock::MockMenuItemWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasCheckMenuItem for Cocoa {
+    type CheckMenuItem<OUTLET: OutletHolder> = plating_core::mock::MockCheckMenuItemWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasMenuSeparator for Cocoa {
+    type MenuSeparator<OUTLET: OutletHolder> = plating_core::mock::MockMenuSeparatorWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasDialog for Cocoa {
+    type Dialog<OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>> =
+        plating_core::mock::MockDialogWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasFileDialog for Cocoa {
+    type FileDialog = plating_core::mock::MockFileDialog;
+}
+
+#[cfg(target_os = "macos")]
+impl HasTrayIcon for Cocoa {
+    type TrayIcon<OUTLET: OutletHolder + Outlet<MenuOutlet>> =
+        plating_core::mock::MockTrayIconWidget<OUTLET>;
+}
+
+#[cfg(target_os = "macos")]
+impl HasNotifications for Cocoa {
+    type Notifications = plating_core::mock::MockNotifications;
 }
 /*
 pub trait CocoaSpecific {
     type Button<OUTLET: OutletHolder>: Button<OUTLET>;
     type Window<OUTLET: OutletHolder
                       + Outlet<MenuOutlet>
-                      + Outlet<ChildrenOutlet>>: Window<OUTLET>;
+                      + Outlet<ChildrenOutlet>
+                      + Outlet<ToolbarOutlet>
+                      + Outlet<StatusBarOutlet>>: Window<OUTLET>;
 }
 impl CocoaSpecific for Cocoa {
     type Button<OUTLET: OutletHolder> = MockButtonWidget<OUTLET>;
     type Window<OUTLET: OutletHolder
                       + Outlet<MenuOutlet>
-                      + Outlet<ChildrenOutlet>> = MockWindowWidget<OUTLET>;
+                      + Outlet<ChildrenOutlet>
+                      + Outlet<ToolbarOutlet>
+                      + Outlet<StatusBarOutlet>> = MockWindowWidget<OUTLET>;
 }*/
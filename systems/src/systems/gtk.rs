@@ -8,7 +8,27 @@
 use plating_core::utils::{Deserialize, Serialize};
 
 use crate::systems::SystemDefinition;
-use crate::types::{ButtonAvailable, WindowAvailable};
+use crate::types::{
+    ButtonAvailable,
+    CheckMenuItemAvailable,
+    DialogAvailable,
+    FileDialogAvailable,
+    GridAvailable,
+    GroupBoxAvailable,
+    ListViewAvailable,
+    MenuAvailable,
+    MenuItemAvailable,
+    MenuSeparatorAvailable,
+    NotificationsAvailable,
+    ScrollViewAvailable,
+    SplitViewAvailable,
+    TabAvailable,
+    TableViewAvailable,
+    TabsAvailable,
+    TrayIconAvailable,
+    TreeViewAvailable,
+    WindowAvailable,
+};
 
 /// [System Definition](`SystemDefinition`) for the GTK UI System.
 ///
@@ -24,6 +44,23 @@ impl SystemDefinition for GTKDefinition {}
 
 impl ButtonAvailable for GTKDefinition {}
 impl WindowAvailable for GTKDefinition {}
+impl TabsAvailable for GTKDefinition {}
+impl TabAvailable for GTKDefinition {}
+impl ListViewAvailable for GTKDefinition {}
+impl TableViewAvailable for GTKDefinition {}
+impl TreeViewAvailable for GTKDefinition {}
+impl ScrollViewAvailable for GTKDefinition {}
+impl SplitViewAvailable for GTKDefinition {}
+impl GroupBoxAvailable for GTKDefinition {}
+impl MenuAvailable for GTKDefinition {}
+impl MenuItemAvailable for GTKDefinition {}
+impl CheckMenuItemAvailable for GTKDefinition {}
+impl MenuSeparatorAvailable for GTKDefinition {}
+impl DialogAvailable for GTKDefinition {}
+impl FileDialogAvailable for GTKDefinition {}
+impl TrayIconAvailable for GTKDefinition {}
+impl NotificationsAvailable for GTKDefinition {}
+impl GridAvailable for GTKDefinition {}
 
 /// The GTK [System](`crate::systems::System`).
 ///
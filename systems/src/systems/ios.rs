@@ -8,7 +8,20 @@
 use plating_core::utils::{Deserialize, Serialize};
 
 use crate::systems::SystemDefinition;
-use crate::types::ButtonAvailable;
+use crate::types::{
+    ButtonAvailable,
+    CheckMenuItemAvailable,
+    DialogAvailable,
+    GridAvailable,
+    ListViewAvailable,
+    MenuAvailable,
+    MenuItemAvailable,
+    MenuSeparatorAvailable,
+    NotificationsAvailable,
+    ScrollViewAvailable,
+    TabAvailable,
+    TabsAvailable,
+};
 
 /// [System Definition](`SystemDefinition`) for IOS.
 ///
@@ -22,6 +35,17 @@ pub struct IOSDefinition {}
 impl SystemDefinition for IOSDefinition {}
 
 impl ButtonAvailable for IOSDefinition {}
+impl TabsAvailable for IOSDefinition {}
+impl TabAvailable for IOSDefinition {}
+impl ListViewAvailable for IOSDefinition {}
+impl ScrollViewAvailable for IOSDefinition {}
+impl MenuAvailable for IOSDefinition {}
+impl MenuItemAvailable for IOSDefinition {}
+impl CheckMenuItemAvailable for IOSDefinition {}
+impl MenuSeparatorAvailable for IOSDefinition {}
+impl DialogAvailable for IOSDefinition {}
+impl NotificationsAvailable for IOSDefinition {}
+impl GridAvailable for IOSDefinition {}
 
 /// The IOS [System](`crate::systems::System`).
 ///
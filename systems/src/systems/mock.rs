@@ -5,12 +5,79 @@
 
 #![cfg(any(feature = "mock", doc))]
 
-use plating_core::mock::{MockButtonWidget, MockWindowWidget};
-use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+use plating_core::mock::{
+    MockButtonWidget,
+    MockCheckMenuItemWidget,
+    MockDialogWidget,
+    MockFileDialog,
+    MockGridWidget,
+    MockGroupBoxWidget,
+    MockListViewWidget,
+    MockMenuItemWidget,
+    MockMenuSeparatorWidget,
+    MockMenuWidget,
+    MockNotifications,
+    MockScrollViewWidget,
+    MockSplitViewWidget,
+    MockTabWidget,
+    MockTableViewWidget,
+    MockTabsWidget,
+    MockTrayIconWidget,
+    MockTreeViewWidget,
+    MockWindowWidget,
+};
+use plating_core::utils::outlet::{
+    ChildrenOutlet,
+    MenuOutlet,
+    Outlet,
+    OutletHolder,
+    StatusBarOutlet,
+    ToolbarOutlet,
+};
 use plating_core::utils::{Deserialize, Serialize};
+use plating_core::widgets::{DataSource, TreeDataSource};
 
 use crate::systems::{System, SystemDefinition};
-use crate::types::{ButtonAvailable, HasButton, HasWindow, WindowAvailable};
+use crate::types::{
+    ButtonAvailable,
+    CheckMenuItemAvailable,
+    DialogAvailable,
+    FileDialogAvailable,
+    GridAvailable,
+    GroupBoxAvailable,
+    HasButton,
+    HasCheckMenuItem,
+    HasDialog,
+    HasFileDialog,
+    HasGrid,
+    HasGroupBox,
+    HasListView,
+    HasMenu,
+    HasMenuItem,
+    HasMenuSeparator,
+    HasNotifications,
+    HasScrollView,
+    HasSplitView,
+    HasTab,
+    HasTableView,
+    HasTabs,
+    HasTrayIcon,
+    HasTreeView,
+    HasWindow,
+    ListViewAvailable,
+    MenuAvailable,
+    MenuItemAvailable,
+    MenuSeparatorAvailable,
+    NotificationsAvailable,
+    ScrollViewAvailable,
+    SplitViewAvailable,
+    TabAvailable,
+    TableViewAvailable,
+    TabsAvailable,
+    TrayIconAvailable,
+    TreeViewAvailable,
+    WindowAvailable,
+};
 
 /// [System Definition](`SystemDefinition`) for the Mock System .
 ///
@@ -24,9 +91,29 @@ impl SystemDefinition for MockDefinition {}
 //TODO: autogenerate from HasButton
 impl ButtonAvailable for MockDefinition {}
 
-//TODO: autogenerate from HasTabs
+//TODO: autogenerate from HasWindow
 impl WindowAvailable for MockDefinition {}
 
+//TODO: autogenerate from HasTabs
+impl TabsAvailable for MockDefinition {}
+impl TabAvailable for MockDefinition {}
+
+impl ListViewAvailable for MockDefinition {}
+impl TableViewAvailable for MockDefinition {}
+impl TreeViewAvailable for MockDefinition {}
+impl ScrollViewAvailable for MockDefinition {}
+impl SplitViewAvailable for MockDefinition {}
+impl GroupBoxAvailable for MockDefinition {}
+impl MenuAvailable for MockDefinition {}
+impl MenuItemAvailable for MockDefinition {}
+impl CheckMenuItemAvailable for MockDefinition {}
+impl MenuSeparatorAvailable for MockDefinition {}
+impl DialogAvailable for MockDefinition {}
+impl FileDialogAvailable for MockDefinition {}
+impl TrayIconAvailable for MockDefinition {}
+impl NotificationsAvailable for MockDefinition {}
+impl GridAvailable for MockDefinition {}
+
 /// The Mock [System](`crate::systems::System`).
 ///
 /// # Availability
@@ -45,6 +132,73 @@ impl HasButton for Mock {
     type Button<OUTLET: OutletHolder> = MockButtonWidget<OUTLET>;
 }
 impl HasWindow for Mock {
-    type Window<OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>> =
-        MockWindowWidget<OUTLET>;
+    type Window<
+        OUTLET: OutletHolder
+            + Outlet<MenuOutlet>
+            + Outlet<ChildrenOutlet>
+            + Outlet<ToolbarOutlet>
+            + Outlet<StatusBarOutlet>,
+    > = MockWindowWidget<OUTLET>;
+}
+impl HasTabs for Mock {
+    type Tabs<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> = MockTabsWidget<OUTLET>;
+}
+impl HasTab for Mock {
+    type Tab<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> = MockTabWidget<OUTLET>;
+}
+impl HasListView for Mock {
+    type ListView<OUTLET: OutletHolder, SOURCE: DataSource> = MockListViewWidget<OUTLET, SOURCE>;
+}
+impl HasTableView for Mock {
+    type TableView<OUTLET: OutletHolder, SOURCE: DataSource> = MockTableViewWidget<OUTLET, SOURCE>;
+}
+impl HasTreeView for Mock {
+    type TreeView<OUTLET: OutletHolder, SOURCE: TreeDataSource> =
+        MockTreeViewWidget<OUTLET, SOURCE>;
+}
+impl HasScrollView for Mock {
+    type ScrollView<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> = MockScrollViewWidget<OUTLET>;
+}
+impl HasSplitView for Mock {
+    type SplitView<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> = MockSplitViewWidget<OUTLET>;
+}
+impl HasGrid for Mock {
+    type Grid<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> = MockGridWidget<OUTLET>;
+}
+
+impl HasGroupBox for Mock {
+    type GroupBox<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> = MockGroupBoxWidget<OUTLET>;
+}
+
+impl HasMenu for Mock {
+    type Menu<OUTLET: OutletHolder + Outlet<ChildrenOutlet>> = MockMenuWidget<OUTLET>;
+}
+
+impl HasMenuItem for Mock {
+    type MenuItem<OUTLET: OutletHolder> = MockMenuItemWidget<OUTLET>;
+}
+
+impl HasCheckMenuItem for Mock {
+    type CheckMenuItem<OUTLET: OutletHolder> = MockCheckMenuItemWidget<OUTLET>;
+}
+
+impl HasMenuSeparator for Mock {
+    type MenuSeparator<OUTLET: OutletHolder> = MockMenuSeparatorWidget<OUTLET>;
+}
+
+impl HasDialog for Mock {
+    type Dialog<OUTLET: OutletHolder + Outlet<MenuOutlet> + Outlet<ChildrenOutlet>> =
+        MockDialogWidget<OUTLET>;
+}
+
+impl HasFileDialog for Mock {
+    type FileDialog = MockFileDialog;
+}
+
+impl HasTrayIcon for Mock {
+    type TrayIcon<OUTLET: OutletHolder + Outlet<MenuOutlet>> = MockTrayIconWidget<OUTLET>;
+}
+
+impl HasNotifications for Mock {
+    type Notifications = MockNotifications;
 }
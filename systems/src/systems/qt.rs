@@ -8,7 +8,27 @@
 use plating_core::utils::{Deserialize, Serialize};
 
 use crate::systems::SystemDefinition;
-use crate::types::{ButtonAvailable, WindowAvailable};
+use crate::types::{
+    ButtonAvailable,
+    CheckMenuItemAvailable,
+    DialogAvailable,
+    FileDialogAvailable,
+    GridAvailable,
+    GroupBoxAvailable,
+    ListViewAvailable,
+    MenuAvailable,
+    MenuItemAvailable,
+    MenuSeparatorAvailable,
+    NotificationsAvailable,
+    ScrollViewAvailable,
+    SplitViewAvailable,
+    TabAvailable,
+    TableViewAvailable,
+    TabsAvailable,
+    TrayIconAvailable,
+    TreeViewAvailable,
+    WindowAvailable,
+};
 
 /// [System Definition](`SystemDefinition`) for the QT UI System.
 ///
@@ -24,6 +44,23 @@ impl SystemDefinition for QTDefinition {}
 
 impl ButtonAvailable for QTDefinition {}
 impl WindowAvailable for QTDefinition {}
+impl TabsAvailable for QTDefinition {}
+impl TabAvailable for QTDefinition {}
+impl ListViewAvailable for QTDefinition {}
+impl TableViewAvailable for QTDefinition {}
+impl TreeViewAvailable for QTDefinition {}
+impl ScrollViewAvailable for QTDefinition {}
+impl SplitViewAvailable for QTDefinition {}
+impl GroupBoxAvailable for QTDefinition {}
+impl MenuAvailable for QTDefinition {}
+impl MenuItemAvailable for QTDefinition {}
+impl CheckMenuItemAvailable for QTDefinition {}
+impl MenuSeparatorAvailable for QTDefinition {}
+impl DialogAvailable for QTDefinition {}
+impl FileDialogAvailable for QTDefinition {}
+impl TrayIconAvailable for QTDefinition {}
+impl NotificationsAvailable for QTDefinition {}
+impl GridAvailable for QTDefinition {}
 
 
 /// The QT [System](`crate::systems::System`).
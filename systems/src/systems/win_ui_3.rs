@@ -8,7 +8,27 @@
 use plating_core::utils::{Deserialize, Serialize};
 
 use crate::systems::SystemDefinition;
-use crate::types::{ButtonAvailable, WindowAvailable};
+use crate::types::{
+    ButtonAvailable,
+    CheckMenuItemAvailable,
+    DialogAvailable,
+    FileDialogAvailable,
+    GridAvailable,
+    GroupBoxAvailable,
+    ListViewAvailable,
+    MenuAvailable,
+    MenuItemAvailable,
+    MenuSeparatorAvailable,
+    NotificationsAvailable,
+    ScrollViewAvailable,
+    SplitViewAvailable,
+    TabAvailable,
+    TableViewAvailable,
+    TabsAvailable,
+    TrayIconAvailable,
+    TreeViewAvailable,
+    WindowAvailable,
+};
 
 /// [System Definition](`SystemDefinition`) for the WinUI3 System.
 ///
@@ -23,6 +43,23 @@ impl SystemDefinition for WinUI3Definition {}
 
 impl ButtonAvailable for WinUI3Definition {}
 impl WindowAvailable for WinUI3Definition {}
+impl TabsAvailable for WinUI3Definition {}
+impl TabAvailable for WinUI3Definition {}
+impl ListViewAvailable for WinUI3Definition {}
+impl TableViewAvailable for WinUI3Definition {}
+impl TreeViewAvailable for WinUI3Definition {}
+impl ScrollViewAvailable for WinUI3Definition {}
+impl SplitViewAvailable for WinUI3Definition {}
+impl GroupBoxAvailable for WinUI3Definition {}
+impl MenuAvailable for WinUI3Definition {}
+impl MenuItemAvailable for WinUI3Definition {}
+impl CheckMenuItemAvailable for WinUI3Definition {}
+impl MenuSeparatorAvailable for WinUI3Definition {}
+impl DialogAvailable for WinUI3Definition {}
+impl FileDialogAvailable for WinUI3Definition {}
+impl TrayIconAvailable for WinUI3Definition {}
+impl NotificationsAvailable for WinUI3Definition {}
+impl GridAvailable for WinUI3Definition {}
 
 /// The WinUI3 [System](`crate::systems::System`).
 ///
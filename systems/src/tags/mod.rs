@@ -5,12 +5,80 @@
 
 use std::marker::PhantomData;
 
-use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+use plating_core::utils::outlet::{
+    ChildrenOutlet,
+    MenuOutlet,
+    Outlet,
+    OutletHolder,
+    StatusBarOutlet,
+    ToolbarOutlet,
+};
 use plating_core::utils::{Deserialize, Serialize};
-use plating_core::widgets::{Button, Window};
+use plating_core::widgets::{
+    Button,
+    CheckMenuItem,
+    DataSource,
+    Dialog,
+    FileDialog,
+    Grid,
+    GroupBox,
+    ListView,
+    Menu,
+    MenuItem,
+    MenuSeparator,
+    Notifications,
+    ScrollView,
+    SplitView,
+    Tab,
+    TableView,
+    Tabs,
+    TrayIcon,
+    TreeDataSource,
+    TreeView,
+    Window,
+};
 
 use crate::systems::{SystemDefinition, SystemsList};
-use crate::types::{ButtonAvailable, HasButton, HasWindow, WindowAvailable};
+use crate::types::{
+    ButtonAvailable,
+    CheckMenuItemAvailable,
+    DialogAvailable,
+    FileDialogAvailable,
+    GridAvailable,
+    GroupBoxAvailable,
+    HasButton,
+    HasCheckMenuItem,
+    HasDialog,
+    HasFileDialog,
+    HasGrid,
+    HasGroupBox,
+    HasListView,
+    HasMenu,
+    HasMenuItem,
+    HasMenuSeparator,
+    HasNotifications,
+    HasScrollView,
+    HasSplitView,
+    HasTab,
+    HasTableView,
+    HasTabs,
+    HasTrayIcon,
+    HasTreeView,
+    HasWindow,
+    ListViewAvailable,
+    MenuAvailable,
+    MenuItemAvailable,
+    MenuSeparatorAvailable,
+    NotificationsAvailable,
+    ScrollViewAvailable,
+    SplitViewAvailable,
+    TabAvailable,
+    TableViewAvailable,
+    TabsAvailable,
+    TrayIconAvailable,
+    TreeViewAvailable,
+    WindowAvailable,
+};
 use crate::{default_widgets, tag};
 
 /// A marker Trait for Tags
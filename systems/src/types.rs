@@ -3,8 +3,37 @@
  * This project is dual licensed under either MIT or Apache-2.0.
  */
 
-use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
-use plating_core::widgets::{Button, Window};
+use plating_core::utils::outlet::{
+    ChildrenOutlet,
+    MenuOutlet,
+    Outlet,
+    OutletHolder,
+    StatusBarOutlet,
+    ToolbarOutlet,
+};
+use plating_core::widgets::{
+    Button,
+    CheckMenuItem,
+    DataSource,
+    Dialog,
+    FileDialog,
+    Grid,
+    GroupBox,
+    ListView,
+    Menu,
+    MenuItem,
+    MenuSeparator,
+    Notifications,
+    ScrollView,
+    SplitView,
+    Tab,
+    TableView,
+    Tabs,
+    TrayIcon,
+    TreeDataSource,
+    TreeView,
+    Window,
+};
 
 use crate::marker;
 use crate::systems::{System, SystemDefinition, SystemsList};
@@ -14,9 +43,81 @@ marker! {
     /// The window type for Systems supporting them.
     pub type Window<OUTLET: OutletHolder
                           + Outlet<MenuOutlet>
-                          + Outlet<ChildrenOutlet>>: Window<OUTLET>;
+                          + Outlet<ChildrenOutlet>
+                          + Outlet<ToolbarOutlet>
+                          + Outlet<StatusBarOutlet>>: Window<OUTLET>;
 }
 marker! {
     /// The button type for Systems supporting them.
     pub type Button<OUTLET: OutletHolder>: Button<OUTLET>;
 }
+marker! {
+    /// The tab bar type for Systems supporting them.
+    pub type Tabs<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: Tabs<OUTLET>;
+}
+marker! {
+    /// The tab page type for Systems supporting them.
+    pub type Tab<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: Tab<OUTLET>;
+}
+marker! {
+    /// The virtualized list view type for Systems supporting them.
+    pub type ListView<OUTLET: OutletHolder, SOURCE: DataSource>: ListView<OUTLET, SOURCE>;
+}
+marker! {
+    /// The virtualized table view type for Systems supporting them.
+    pub type TableView<OUTLET: OutletHolder, SOURCE: DataSource>: TableView<OUTLET, SOURCE>;
+}
+marker! {
+    /// The hierarchical tree view type for Systems supporting them.
+    pub type TreeView<OUTLET: OutletHolder, SOURCE: TreeDataSource>: TreeView<OUTLET, SOURCE>;
+}
+marker! {
+    /// The scroll view type for Systems supporting them.
+    pub type ScrollView<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: ScrollView<OUTLET>;
+}
+marker! {
+    /// The split view type for Systems supporting them.
+    pub type SplitView<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: SplitView<OUTLET>;
+}
+marker! {
+    /// The grid type for Systems supporting them.
+    pub type Grid<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: Grid<OUTLET>;
+}
+marker! {
+    /// The group box type for Systems supporting them.
+    pub type GroupBox<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: GroupBox<OUTLET>;
+}
+marker! {
+    /// The menu type for Systems supporting them.
+    pub type Menu<OUTLET: OutletHolder + Outlet<ChildrenOutlet>>: Menu<OUTLET>;
+}
+marker! {
+    /// The menu item type for Systems supporting them.
+    pub type MenuItem<OUTLET: OutletHolder>: MenuItem<OUTLET>;
+}
+marker! {
+    /// The checkable menu item type for Systems supporting them.
+    pub type CheckMenuItem<OUTLET: OutletHolder>: CheckMenuItem<OUTLET>;
+}
+marker! {
+    /// The menu separator type for Systems supporting them.
+    pub type MenuSeparator<OUTLET: OutletHolder>: MenuSeparator<OUTLET>;
+}
+marker! {
+    /// The custom dialog type for Systems supporting them.
+    pub type Dialog<OUTLET: OutletHolder
+                          + Outlet<MenuOutlet>
+                          + Outlet<ChildrenOutlet>>: Dialog<OUTLET>;
+}
+marker! {
+    /// The open/save file and choose folder dialogs for Systems supporting them.
+    pub type FileDialog: FileDialog;
+}
+marker! {
+    /// The tray icon type for Systems supporting them.
+    pub type TrayIcon<OUTLET: OutletHolder + Outlet<MenuOutlet>>: TrayIcon<OUTLET>;
+}
+marker! {
+    /// The desktop notifications for Systems supporting them.
+    pub type Notifications: Notifications;
+}
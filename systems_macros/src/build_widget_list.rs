@@ -52,23 +52,83 @@ pub fn build_widget_list(item: TokenStream) -> TokenStream {
         fields,
     } = syn::parse_macro_input!(item as WidgetListInput);
 
+    let names: Vec<_> = fields.iter().map(|field| field.ident.to_string()).collect();
+    let known = names.join("`, `");
+
     let push_fields = fields.iter().map(|field| {
+        let name = field.ident.to_string();
         quote! {
-            input.items.push(
-                syn::parse_quote! {
+            if !skip.iter().any(|skipped| skipped == #name) {
+                let mut field: syn::TraitItemType = syn::parse_quote! {
                     #field
+                };
+                if let Some((_, new_name)) = rename.iter().find(|(old_name, _)| old_name == #name) {
+                    field.ident = new_name.clone();
                 }
-            );
+                input.items.push(syn::TraitItem::Type(field));
+            }
         }
     });
 
     TokenStream::from(quote! {
-        //TODO: implement option to 'skip' specific widgets
         #[automatically_derived]
         #[proc_macro_attribute]
         #(#attrs)*
         pub fn #ident(attr: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
             use quote::ToTokens;
+            use syn::parse::Parser;
+
+            const WIDGETS: &[&str] = &[#(#names),*];
+
+            let mut skip: Vec<syn::Ident> = Vec::new();
+            let mut rename: Vec<(syn::Ident, syn::Ident)> = Vec::new();
+            // skip(Foo, Bar), rename(Baz = Qux)
+            let options = |input: syn::parse::ParseStream| -> syn::Result<()> {
+                while !input.is_empty() {
+                    let key: syn::Ident = input.parse()?;
+                    let content;
+                    syn::parenthesized!(content in input);
+                    match key.to_string().as_str() {
+                        "skip" => {
+                            skip.extend(content.parse_terminated::<_, syn::Token![,]>(<syn::Ident as syn::parse::Parse>::parse)?);
+                        },
+                        "rename" => {
+                            let pairs = content.parse_terminated::<_, syn::Token![,]>(
+                                |pair: syn::parse::ParseStream| {
+                                    let old_name: syn::Ident = pair.parse()?;
+                                    pair.parse::<syn::Token![=]>()?;
+                                    Ok((old_name, pair.parse()?))
+                                },
+                            )?;
+                            rename.extend(pairs);
+                        },
+                        _ => {
+                            return Err(syn::Error::new(key.span(), "expected `skip(...)` or `rename(...)`"));
+                        },
+                    }
+                    if !input.is_empty() {
+                        input.parse::<syn::Token![,]>()?;
+                    }
+                }
+                Ok(())
+            };
+            if let Err(error) = options.parse(attr) {
+                return error.to_compile_error().into();
+            }
+
+            let unknown = skip
+                .iter()
+                .chain(rename.iter().map(|(old_name, _)| old_name))
+                .find(|name| !WIDGETS.iter().any(|widget| name == widget));
+            if let Some(name) = unknown {
+                return syn::Error::new(
+                    name.span(),
+                    format!("unknown widget `{}`, expected one of `{}`", name, #known),
+                )
+                .to_compile_error()
+                .into();
+            }
+
             let mut input = syn::parse_macro_input!(item as syn::ItemTrait);
 
             #(#push_fields)*
@@ -34,10 +34,10 @@
 //! None for this crate.
 //!
 //! ## Use via plating_systems
-//! All 3 macros are also exported through plating_systems.
+//! All 6 macros are also exported through plating_systems.
 //! ```
 //! // Instead of using this crate directly, prefer using it through plating_systems
-//! pub use plating_systems::{build_widget_list, marker, tag};
+//! pub use plating_systems::{build_widget_list, marker, tag, widget, Native, Outlets};
 //! ```
 //!
 //! ## Where are the macros created by 'build_widget_list'
@@ -48,8 +48,11 @@ use proc_macro::TokenStream;
 
 mod build_widget_list;
 mod marker_impl;
+mod native;
+mod outlets;
 mod tag;
 mod utils;
+mod widget;
 
 /// Macro to create helper traits used internally by the systems logic.
 /// These Helper traits need to be created for each widget type you want the
@@ -182,8 +185,8 @@ pub fn tag(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// #![feature(generic_associated_types)] // Required for default_widgets
 ///
 /// use plating_systems::default_widgets;
-/// # use plating_core::widgets::{Button, Window};
-/// # use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder};
+/// # use plating_core::widgets::{Button, CheckMenuItem, DataSource, Dialog, FileDialog, Grid, GroupBox, ListView, Menu, MenuItem, MenuSeparator, Notifications, ScrollView, SplitView, Tab, TableView, Tabs, TrayIcon, TreeDataSource, TreeView, Window};
+/// # use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder, StatusBarOutlet, ToolbarOutlet};
 /// //This macro is auto generated by `build_widget_list`
 /// #[default_widgets]
 /// pub trait SomeTrait {}
@@ -202,6 +205,7 @@ pub fn tag(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///                          + Outlet<MenuOutlet>
 ///                          + Outlet<ChildrenOutlet>>: Window<OUTLET>;
 ///        type Button<OUTLET: OutletHolder>: Button<OUTLET>;
+///        type ListView<OUTLET: OutletHolder, SOURCE: DataSource>: ListView<OUTLET, SOURCE>;
 ///        // add as many widgets as you like
 ///        // ...
 ///    };
@@ -210,6 +214,33 @@ pub fn tag(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// When the types defined require Template parameters, the resulting macro
 /// requires ```generic_associated_types```.
 ///
+/// The generated macro accepts `skip(...)` to leave out some of the widgets and
+/// `rename(Old = New)` to expose a widget under a different name. Naming a
+/// widget that is not part of the list is a compile error.
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_associated_types)]
+/// use plating_systems::default_widgets;
+/// # use plating_core::widgets::{Button, CheckMenuItem, DataSource, Dialog, FileDialog, Grid, GroupBox, ListView, Menu, MenuItem, MenuSeparator, Notifications, ScrollView, SplitView, Tab, TableView, Tabs, TrayIcon, TreeDataSource, TreeView, Window};
+/// # use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder, StatusBarOutlet, ToolbarOutlet};
+///
+/// #[default_widgets(skip(Tabs, Tab, TreeView), rename(Notifications = Toasts))]
+/// pub trait RestrictedTrait {}
+///
+/// fn notify<T: RestrictedTrait>(_toasts: &T::Toasts) {}
+/// ```
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_associated_types)]
+/// use plating_systems::default_widgets;
+/// # use plating_core::widgets::{Button, CheckMenuItem, DataSource, Dialog, FileDialog, Grid, GroupBox, ListView, Menu, MenuItem, MenuSeparator, Notifications, ScrollView, SplitView, Tab, TableView, Tabs, TrayIcon, TreeDataSource, TreeView, Window};
+/// # use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet, OutletHolder, StatusBarOutlet, ToolbarOutlet};
+///
+/// // error: unknown widget `Slider`
+/// #[default_widgets(skip(Slider))]
+/// pub trait RestrictedTrait {}
+/// ```
+///
 /// NOTE: since this defines a `proc_macro_attribute`, this macro requires the
 /// following in `Cargo.toml`
 /// ```toml
@@ -221,6 +252,209 @@ pub fn build_widget_list(item: TokenStream) -> TokenStream {
     build_widget_list::build_widget_list(item)
 }
 
+/// Derives `OutletHolder` for an outlet struct, as well as one `Outlet<X>`
+/// implementation for every field annotated with `#[outlet(X)]`.
+///
+/// `#[outlets(from = OtherOutlet<...>)]` additionally generates a `From`
+/// conversion from an outlet struct with (at least) the same fields, e.g. from
+/// the cross-platform outlet to the outlet of a backend.
+/// ```
+/// use plating_core::utils::children::ChildrenList;
+/// use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet, Outlet};
+/// use plating_systems_macros::Outlets;
+///
+/// #[derive(Outlets)]
+/// pub struct CrossOutlet<CHILDREN, MENU>
+/// where
+///     CHILDREN: ChildrenList,
+///     MENU: ChildrenList,
+/// {
+///     #[outlet(ChildrenOutlet)]
+///     pub children: CHILDREN,
+///     #[outlet(MenuOutlet)]
+///     pub menu:     MENU,
+/// }
+///
+/// #[derive(Outlets)]
+/// #[outlets(from = CrossOutlet<CHILDREN, MENU>)]
+/// pub struct NativeOutlet<CHILDREN, MENU>
+/// where
+///     CHILDREN: ChildrenList,
+///     MENU: ChildrenList,
+/// {
+///     #[outlet(ChildrenOutlet)]
+///     pub children: CHILDREN,
+///     #[outlet(MenuOutlet)]
+///     pub menu:     MENU,
+/// }
+///
+/// let native: NativeOutlet<(), ()> = CrossOutlet {
+///     children: (),
+///     menu:     (),
+/// }
+/// .into();
+/// let _children: &() = <NativeOutlet<(), ()> as Outlet<ChildrenOutlet>>::get(&native);
+/// ```
+///
+/// The generated code expects the outlet traits in
+/// `plating_core::utils::outlet`. Use `#[outlets(path = some::module)]` if
+/// they live somewhere else, e.g. within `plating_core` itself.
+#[proc_macro_derive(Outlets, attributes(outlets, outlet))]
+pub fn derive_outlets(input: TokenStream) -> TokenStream {
+    outlets::derive_outlets(input)
+}
+
+/// Derives the plumbing between a cross-platform property and its native
+/// counterpart of one backend:
+/// - `Native<BACKEND>` for the native property,
+/// - `ToNative<BACKEND>` for the cross-platform property,
+/// - `Property` for every widget given via `widget`, calling its setter.
+///
+/// ```
+/// use plating_systems_macros::Native;
+///
+/// # /// Stand-in for `plating_core`, see the `crate` key.
+/// # mod stub {
+/// #     pub type PlatingResult<T> = Result<T, String>;
+/// #     pub mod native {
+/// #         pub trait Native<BACKEND> {}
+/// #         pub trait ToNative<BACKEND> {
+/// #             type Result;
+/// #             fn to_native(&self) -> Self::Result;
+/// #         }
+/// #     }
+/// #     pub mod utils {
+/// #         pub trait Property<STATE, TARGET, BACKEND> {
+/// #             fn provide(&self, target: &mut TARGET) -> crate::stub::PlatingResult<()>;
+/// #             fn validate(&self) -> crate::stub::PlatingResult<()> {
+/// #                 Ok(())
+/// #             }
+/// #         }
+/// #     }
+/// # }
+/// use crate::stub::native::{Native, ToNative};
+/// use crate::stub::utils::Property;
+/// use crate::stub::PlatingResult;
+///
+/// pub struct Cocoa;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// pub struct Size(pub f64);
+///
+/// pub trait CocoaCommon<STATE> {
+///     fn set_size(&mut self, size: &CocoaSize) -> PlatingResult<()>;
+/// }
+///
+/// #[derive(Default)]
+/// pub struct CocoaWindow {
+///     size: f64,
+/// }
+///
+/// impl<STATE> CocoaCommon<STATE> for CocoaWindow {
+///     fn set_size(&mut self, size: &CocoaSize) -> PlatingResult<()> {
+///         self.size = (size.0).0;
+///         Ok(())
+///     }
+/// }
+///
+/// #[repr(transparent)]
+/// #[derive(Debug, Clone, Copy, PartialEq, Native)]
+/// #[native(crate = crate::stub, backend = Cocoa, property = Size, setter = set_size)]
+/// #[native(widget(impl<STATE, T> T where T: CocoaCommon<STATE>), validate = {
+///     if (self.0).0 < 0.0 {
+///         Err(String::from("negative size"))
+///     } else {
+///         Ok(())
+///     }
+/// })]
+/// pub struct CocoaSize(pub Size);
+///
+/// # fn main() -> PlatingResult<()> {
+/// fn assert_native<T: Native<Cocoa>>() {}
+/// assert_native::<CocoaSize>();
+///
+/// let size: CocoaSize = Size(42.0).to_native();
+/// let mut window = CocoaWindow::default();
+/// <CocoaSize as Property<(), CocoaWindow, Cocoa>>::provide(&size, &mut window)?;
+/// assert_eq!(window.size, 42.0);
+///
+/// let negative = CocoaSize(Size(-1.0));
+/// assert!(<CocoaSize as Property<(), CocoaWindow, Cocoa>>::validate(&negative).is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Available keys:
+/// - `backend`: the backend the native property belongs to. Required.
+/// - `property`: the cross-platform property, skips `ToNative` if missing.
+/// - `to_native`: expression converting `self` (the cross-platform property)
+///   into the native one. Defaults to wrapping a clone of it.
+/// - `setter`: the method of the widgets to call.
+/// - `validate`: expression validating `self` (the native property), see
+///   `Property::validate`.
+/// - `widget(impl<STATE, ...> Widget<...> where ...)`: a widget to implement
+///   `Property` for. Can be given multiple times.
+/// - `crate`: path to `plating_core`, e.g. `crate` within plating_core itself.
+#[proc_macro_derive(Native, attributes(native))]
+pub fn derive_native(input: TokenStream) -> TokenStream {
+    native::derive_native(input)
+}
+
+/// Defines a new widget in one go. Given the name of the widget and its
+/// outlets, this generates
+/// - the widget trait with a `new` constructor,
+/// - the outlet struct `FooOutlet`, with one template parameter per outlet,
+/// - the `HasFoo` and `FooAvailable` marker traits (see [`marker!`]),
+/// - the mock widget `MockFooWidget`, including its `ChildOf` impls,
+/// - `HasFoo` for every System listed in `for (...)`, using the mock widget, as
+///   well as `FooAvailable` for their definitions. Only the Mock System is
+///   supported if the list is missing.
+///
+/// Outlets holding menus have to be marked with `#[menu]`, so their children
+/// can connect to the mock widget.
+/// ```
+/// # #![allow(incomplete_features)]
+/// #![feature(generic_associated_types)]
+/// use plating_core::mock::MockButtonOutlet;
+/// use plating_core::prelude::*;
+/// use plating_core::utils::children::children_list;
+/// use plating_core::utils::outlet::{ChildrenOutlet, MenuOutlet};
+/// use plating_systems::systems::Mock;
+/// use plating_systems_macros::widget;
+///
+/// widget! {
+///     /// A panel with a caption.
+///     pub widget Panel for (plating_systems::systems::Mock) {
+///         ChildrenOutlet => children,
+///         #[menu] MenuOutlet => menu,
+///     }
+/// }
+///
+/// # fn main() -> plating_core::PlatingResult<()> {
+/// let button = <Mock as HasButton>::Button::new(MockButtonOutlet {})?;
+/// let _panel = <Mock as HasPanel>::Panel::new(PanelOutlet {
+///     children: children_list!(button),
+///     menu:     (),
+/// })?;
+/// # Ok(())
+/// # }
+/// # use plating_systems::types::HasButton;
+/// ```
+///
+/// Like for [`tag`](macro@tag), systems can be conditional, e.g.
+/// `#[cfg(feature = "mock")] plating_systems::systems::Mock`.
+///
+/// The generated code refers to `plating_core` and `plating_systems`, both
+/// need to be dependencies of your crate.
+///
+/// NOTE: Tags only pick up widgets listed in their widget list. Add
+/// `type Foo<OUTLET: ...>: Foo<OUTLET>;` to your tag in order to use the new
+/// widget through it.
+#[proc_macro]
+pub fn widget(input: TokenStream) -> TokenStream {
+    widget::widget(input)
+}
+
 ///We use them in doc tests, does not stop
 ///rust to complain that we are not using the dev-dependencies
 #[cfg(test)]
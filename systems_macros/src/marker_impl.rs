@@ -54,8 +54,46 @@ pub fn marker(input: TokenStream) -> TokenStream {
         trait_type,
     } = parse_macro_input!(input as MarkerData);
 
-    let has_trait = has_name(&name);
-    let available_trait = available_name(&name);
+    TokenStream::from(marker_tokens(
+        &attrs,
+        &vis,
+        &name,
+        &type_params,
+        &quote! {#trait_type},
+        &quote! {},
+    ))
+}
+
+/// Creates the 'HasFoo', 'FooAvailable' and 'AllHaveFoo' traits.
+///
+/// `systems` is prepended to the paths of plating_systems items, e.g.
+/// `plating_systems::` if they are not in scope.
+pub(crate) fn marker_tokens(
+    attrs: &[Attribute],
+    vis: &Visibility,
+    name: &Ident,
+    type_params: &Generics,
+    trait_type: &proc_macro2::TokenStream,
+    systems: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (system, system_definition, systems_list, tag) = if systems.is_empty() {
+        (
+            quote! {System},
+            quote! {SystemDefinition},
+            quote! {SystemsList},
+            quote! {Tag},
+        )
+    } else {
+        (
+            quote! {#systems systems::System},
+            quote! {#systems systems::SystemDefinition},
+            quote! {#systems systems::SystemsList},
+            quote! {#systems tags::Tag},
+        )
+    };
+
+    let has_trait = has_name(name);
+    let available_trait = available_name(name);
 
     let has_docs = [
         &format!(
@@ -84,13 +122,13 @@ pub fn marker(input: TokenStream) -> TokenStream {
         "This trait is autogenerated by the [`marker`] macro",
     ];
 
-    TokenStream::from(quote! {
+    quote! {
 
         #(#[doc=#has_docs])*
         #vis trait #has_trait
         where
-            Self: System,
-            <Self as System>::Definition: #available_trait {
+            Self: #system,
+            <Self as #system>::Definition: #available_trait {
             #(#attrs)*
             type #name#type_params: #trait_type;
         }
@@ -100,21 +138,21 @@ pub fn marker(input: TokenStream) -> TokenStream {
 
         #[automatically_derived]
         #[allow(unused_qualifications)]
-        impl<Sys> #available_trait for Tag<Sys>
+        impl<Sys> #available_trait for #tag<Sys>
         where
-            Sys: SystemsList + SystemDefinition + #available_trait
+            Sys: #systems_list + #system_definition + #available_trait
         {}
         #[automatically_derived]
         #[allow(unused_qualifications)]
         impl<Head, Tail> #available_trait for (Head, Tail) where
-            Head: SystemDefinition + #available_trait,
-            Tail: #available_trait + SystemDefinition + SystemsList,
+            Head: #system_definition + #available_trait,
+            Tail: #available_trait + #system_definition + #systems_list,
         {}
         #[automatically_derived]
         #[allow(unused_qualifications)]
         impl<Head> #available_trait for (Head, ()) where
-            Head: SystemDefinition + #available_trait
+            Head: #system_definition + #available_trait
         {}
 
-    })
+    }
 }
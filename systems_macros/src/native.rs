@@ -0,0 +1,200 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    parenthesized,
+    parse_quote,
+    Attribute,
+    DeriveInput,
+    Expr,
+    Generics,
+    Ident,
+    Path,
+    Token,
+    Type,
+};
+
+/// A widget to generate a `Property` impl for, e.g.
+/// `impl<STATE, T> T where T: CocoaCommon<STATE>`.
+struct NativeWidget {
+    generics: Generics,
+    ty: Type,
+}
+
+impl Parse for NativeWidget {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![impl]>()?;
+        let mut generics: Generics = input.parse()?;
+        let ty = input.parse()?;
+        generics.where_clause = input.parse()?;
+        Ok(Self { generics, ty })
+    }
+}
+
+/// Content of the `#[native(...)]` attributes on the struct.
+struct NativeOptions {
+    /// Path to the crate containing the `Native`, `ToNative` and `Property`
+    /// traits.
+    root:      Path,
+    backend:   Option<Type>,
+    property:  Option<Type>,
+    to_native: Option<Expr>,
+    setter:    Option<Ident>,
+    validate:  Option<Expr>,
+    widgets:   Vec<NativeWidget>,
+}
+
+impl Default for NativeOptions {
+    fn default() -> Self {
+        Self {
+            root:      parse_quote!(plating_core),
+            backend:   None,
+            property:  None,
+            to_native: None,
+            setter:    None,
+            validate:  None,
+            widgets:   Vec::new(),
+        }
+    }
+}
+
+impl NativeOptions {
+    fn parse_into(&mut self, input: ParseStream) -> syn::Result<()> {
+        while !input.is_empty() {
+            // `crate` is a keyword, but a valid key
+            let key = input.call(Ident::parse_any)?;
+
+            if key == "widget" {
+                // parenthesized, as the where clause would swallow any
+                // following key
+                let content;
+                parenthesized!(content in input);
+                self.widgets.push(content.call(NativeWidget::parse)?);
+            } else {
+                input.parse::<Token![=]>()?;
+                match key.to_string().as_str() {
+                    "crate" => self.root = input.parse()?,
+                    "backend" => self.backend = Some(input.parse()?),
+                    "property" => self.property = Some(input.parse()?),
+                    "to_native" => self.to_native = Some(input.parse()?),
+                    "setter" => self.setter = Some(input.parse()?),
+                    "validate" => self.validate = Some(input.parse()?),
+                    _ => {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "expected one of `crate`, `backend`, `property`, `to_native`, \
+                             `setter`, `validate` or `widget(...)`",
+                        ))
+                    },
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("native")) {
+            attr.parse_args_with(|input: ParseStream| options.parse_into(input))?;
+        }
+        Ok(options)
+    }
+}
+
+pub fn derive_native(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    match native(input) {
+        Ok(result) => result.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn native(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let NativeOptions {
+        root,
+        backend,
+        property,
+        to_native,
+        setter,
+        validate,
+        widgets,
+    } = NativeOptions::from_attrs(&input.attrs)?;
+
+    let backend = backend.ok_or_else(|| {
+        syn::Error::new_spanned(name, "missing `#[native(backend = ...)]` attribute")
+    })?;
+
+    let to_native = property.map(|property| {
+        let body =
+            to_native.unwrap_or_else(|| parse_quote!(#name(::core::clone::Clone::clone(self))));
+        quote! {
+            #[automatically_derived]
+            impl #root::native::ToNative<#backend> for #property {
+                type Result = #name;
+
+                fn to_native(&self) -> Self::Result {
+                    #body
+                }
+            }
+        }
+    });
+
+    if !widgets.is_empty() && setter.is_none() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "missing `#[native(setter = ...)]` attribute",
+        ));
+    }
+    let validate = validate.map(|validate| {
+        quote! {
+            fn validate(&self) -> #root::PlatingResult<()> {
+                #validate
+            }
+        }
+    });
+    if let Some(widget) = widgets.iter().find(|widget| {
+        !widget
+            .generics
+            .type_params()
+            .any(|param| param.ident == "STATE")
+    }) {
+        return Err(syn::Error::new_spanned(
+            &widget.ty,
+            "the generics of a `widget` have to declare `STATE`",
+        ));
+    }
+    let properties = widgets.iter().map(|NativeWidget { generics, ty }| {
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #root::utils::Property<STATE, #ty, #backend> for #name #where_clause {
+                fn provide(&self, target: &mut #ty) -> #root::PlatingResult<()> {
+                    target.#setter(self)
+                }
+
+                #validate
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #root::native::Native<#backend> for #name {}
+
+        #to_native
+
+        #(#properties)*
+    })
+}
@@ -0,0 +1,155 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::{parse_quote, Attribute, Data, DeriveInput, Fields, Ident, Path, Token, Type};
+
+/// Content of the `#[outlets(...)]` attributes on the struct.
+struct OutletsOptions {
+    /// Module containing the `Outlet` and `OutletHolder` traits.
+    path: Path,
+    /// Outlet structs with the same fields to generate `From` impls for.
+    from: Vec<Type>,
+}
+
+impl Default for OutletsOptions {
+    fn default() -> Self {
+        Self {
+            path: parse_quote!(plating_core::utils::outlet),
+            from: Vec::new(),
+        }
+    }
+}
+
+impl OutletsOptions {
+    fn parse_into(&mut self, input: ParseStream) -> syn::Result<()> {
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match key.to_string().as_str() {
+                "path" => self.path = input.parse()?,
+                "from" => self.from.push(input.parse()?),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "expected `path = ...` or `from = ...`",
+                    ))
+                },
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("outlets")) {
+            attr.parse_args_with(|input: ParseStream| options.parse_into(input))?;
+        }
+        Ok(options)
+    }
+}
+
+fn outlet_type(attrs: &[Attribute]) -> syn::Result<Option<Path>> {
+    attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("outlet"))
+        .map(|attr| attr.parse_args())
+        .transpose()
+}
+
+pub fn derive_outlets(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    match outlets(input) {
+        Ok(result) => result.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn outlets(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            Fields::Unit => return outlet_holder(&input, &Punctuated::new()),
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Outlets can only be derived for structs with named fields",
+                ))
+            },
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Outlets can only be derived for structs",
+            ))
+        },
+    };
+
+    outlet_holder(&input, fields)
+}
+
+fn outlet_holder(
+    input: &DeriveInput,
+    fields: &Punctuated<syn::Field, Token![,]>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let OutletsOptions { path, from } = OutletsOptions::from_attrs(&input.attrs)?;
+
+    let mut outlets = Vec::new();
+    for field in fields {
+        if let Some(outlet) = outlet_type(&field.attrs)? {
+            let ident = &field.ident;
+            let ty = &field.ty;
+            outlets.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics #path::Outlet<#outlet> for #name #ty_generics #where_clause {
+                    type Children = #ty;
+
+                    fn get(&self) -> &Self::Children {
+                        &self.#ident
+                    }
+
+                    fn get_mut(&mut self) -> &mut Self::Children {
+                        &mut self.#ident
+                    }
+                }
+            });
+        }
+    }
+
+    let field_names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let conversions = from.iter().map(|from| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#from> for #name #ty_generics #where_clause {
+                fn from(outlet: #from) -> Self {
+                    Self {
+                        #(#field_names: outlet.#field_names),*
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #path::OutletHolder for #name #ty_generics #where_clause {}
+
+        #(#outlets)*
+
+        #(#conversions)*
+    })
+}
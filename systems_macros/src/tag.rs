@@ -12,16 +12,13 @@ use syn::token::{Eq, Semi};
 use syn::{
     parenthesized,
     parse_quote,
-    AngleBracketedGenericArguments,
     Attribute,
-    GenericArgument,
     Ident,
     ImplItemType,
     Path,
     Token,
     TraitItem,
     TraitItemType,
-    TypePath,
     Visibility,
 };
 
@@ -41,9 +38,9 @@ fn adjust_trait_item(mut ty: TraitItemType, tag_name: Path) -> TraitItemType {
     ty
 }
 
-struct TagSystem {
-    attrs: Vec<Attribute>,
-    name:  Path,
+pub(crate) struct TagSystem {
+    pub(crate) attrs: Vec<Attribute>,
+    pub(crate) name:  Path,
 }
 impl TagSystem {
     fn always_available(&self) -> bool {
@@ -140,26 +137,7 @@ pub fn tag(attr: TokenStream, item: TokenStream) -> TokenStream {
                     let has_req = has_name(ident);
 
 
-                    let gen = AngleBracketedGenericArguments {
-                        colon2_token: None,
-                        lt_token: ty.generics.lt_token?,
-                        args: ty
-                            .generics
-                            .params
-                            .iter()
-                            .map(|a| match a {
-                                syn::GenericParam::Type(t) => {
-                                    GenericArgument::Type(syn::Type::Path(TypePath {
-                                        qself: None,
-                                        path:  t.ident.clone().into(),
-                                    }))
-                                },
-                                syn::GenericParam::Lifetime(_) => todo!(),
-                                syn::GenericParam::Const(_) => todo!(),
-                            })
-                            .collect(),
-                        gt_token: ty.generics.gt_token?,
-                    };
+                    let (_, gen, _) = ty.generics.split_for_impl();
                     Some(ImplItemType {
                         attrs: ty.attrs.clone(),
                         vis: Visibility::Inherited,
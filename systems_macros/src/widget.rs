@@ -0,0 +1,285 @@
+/*
+ * Copyright (2021) by Marcel Lambert.
+ * This project is dual licensed under either MIT or Apache-2.0.
+ */
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    braced,
+    parenthesized,
+    parse_macro_input,
+    parse_quote,
+    Attribute,
+    Ident,
+    Path,
+    Token,
+    Visibility,
+};
+
+use crate::marker_impl::marker_tokens;
+use crate::tag::TagSystem;
+use crate::utils::definition_name_for_path;
+
+/// `ChildrenOutlet => children` or `#[menu] MenuOutlet => menu`
+struct OutletField {
+    /// Children are menus, so the mock widget becomes a `MockMenuHost`
+    /// instead of a `MockContainer` for this outlet.
+    menu:   bool,
+    outlet: Path,
+    field:  Ident,
+}
+
+impl OutletField {
+    /// Name of the template parameter of the outlet struct, e.g. `CHILDREN`.
+    fn param(&self) -> Ident {
+        Ident::new(&self.field.to_string().to_uppercase(), self.field.span())
+    }
+}
+
+impl Parse for OutletField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut menu = false;
+        for attr in input.call(Attribute::parse_outer)? {
+            if !attr.path.is_ident("menu") || !attr.tokens.is_empty() {
+                return Err(syn::Error::new_spanned(attr, "expected `#[menu]`"));
+            }
+            menu = true;
+        }
+        let outlet = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let field = input.parse()?;
+        Ok(Self {
+            menu,
+            outlet,
+            field,
+        })
+    }
+}
+
+struct WidgetData {
+    attrs:   Vec<Attribute>,
+    vis:     Visibility,
+    name:    Ident,
+    /// `None` if only the Mock System supports the widget.
+    systems: Option<Punctuated<TagSystem, Token![,]>>,
+    outlets: Punctuated<OutletField, Token![,]>,
+}
+
+impl Parse for WidgetData {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+
+        let keyword: Ident = input.parse()?;
+        if keyword != "widget" {
+            return Err(syn::Error::new(keyword.span(), "expected `widget`"));
+        }
+        let name = input.parse()?;
+
+        let systems = if input.parse::<Option<Token![for]>>()?.is_some() {
+            let content;
+            parenthesized!(content in input);
+            Some(content.parse_terminated(TagSystem::parse)?)
+        } else {
+            None
+        };
+
+        let content;
+        braced!(content in input);
+        let outlets = content.parse_terminated(OutletField::parse)?;
+
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            systems,
+            outlets,
+        })
+    }
+}
+
+pub fn widget(input: TokenStream) -> TokenStream {
+    let WidgetData {
+        attrs,
+        vis,
+        name,
+        systems,
+        outlets,
+    } = parse_macro_input!(input as WidgetData);
+    let systems: Vec<TagSystem> = match systems {
+        Some(systems) => systems.into_iter().collect(),
+        None => vec![TagSystem {
+            attrs: Vec::new(),
+            name:  parse_quote!(plating_systems::systems::Mock),
+        }],
+    };
+
+    let outlet_name = Ident::new(&format!("{}Outlet", name), Span::call_site());
+    let mock_name = Ident::new(&format!("Mock{}Widget", name), Span::call_site());
+    let available_name = crate::utils::available_name(&name);
+    let has_name = crate::utils::has_name(&name);
+
+    let outlet_types: Vec<_> = outlets.iter().map(|outlet| &outlet.outlet).collect();
+    let outlet_fields: Vec<_> = outlets.iter().map(|outlet| &outlet.field).collect();
+    let outlet_params: Vec<_> = outlets.iter().map(OutletField::param).collect();
+
+    // OutletHolder + Outlet<ChildrenOutlet> + ...
+    let bounds = quote! {
+        plating_core::utils::outlet::OutletHolder
+            #(+ plating_core::utils::outlet::Outlet<#outlet_types>)*
+    };
+    let children_of = |ty: proc_macro2::TokenStream| {
+        quote! {
+            #(<OUTLET as plating_core::utils::outlet::Outlet<#outlet_types>>::Children:
+                plating_core::utils::children::ChildOf<#ty, #outlet_types>,)*
+        }
+    };
+    let children_of_self = children_of(quote! {Self});
+
+    let outlet_docs = format!("Outlets of a [`{}`].", name);
+    let mock_docs = format!(
+        "Mock implementation of [`{}`], used by the Mock System.",
+        name
+    );
+    let type_docs = format!("The {} type for Systems supporting them.", name);
+
+    let markers = marker_tokens(
+        &[parse_quote!(#[doc = #type_docs])],
+        &vis,
+        &name,
+        &parse_quote!(<OUTLET: #bounds>),
+        &quote! {#name<OUTLET>},
+        &quote! {plating_systems::},
+    );
+
+    let containers = outlets.iter().map(|outlet| {
+        let outlet_type = &outlet.outlet;
+        let container = if outlet.menu {
+            quote! {plating_core::mock::traits::MockMenuHost}
+        } else {
+            quote! {plating_core::mock::traits::MockContainer<#outlet_type>}
+        };
+        quote! {
+            #[automatically_derived]
+            impl<OUTLET> #container for #mock_name<OUTLET> where OUTLET: #bounds {}
+        }
+    });
+
+    // every listed System uses the mock widget, until it gets a native one
+    let system_impls = systems.iter().map(|system| {
+        let attrs = &system.attrs;
+        let system = &system.name;
+        let mut definition = system.clone();
+        definition_name_for_path(&mut definition);
+        quote! {
+            #(#attrs)*
+            #[automatically_derived]
+            impl #available_name for #definition {}
+
+            #(#attrs)*
+            #[automatically_derived]
+            impl #has_name for #system {
+                type #name<OUTLET: #bounds> = #mock_name<OUTLET>;
+            }
+        }
+    });
+
+    TokenStream::from(quote! {
+        #[doc = #outlet_docs]
+        #[derive(Debug, Default, plating_systems::Outlets)]
+        #vis struct #outlet_name<#(#outlet_params),*>
+        where
+            #(#outlet_params: plating_core::utils::children::ChildrenList,)*
+        {
+            #(
+                #[outlet(#outlet_types)]
+                pub #outlet_fields: #outlet_params,
+            )*
+        }
+
+        #(#attrs)*
+        #vis trait #name<OUTLET>
+        where
+            OUTLET: #bounds,
+            Self: Sized + plating_core::widgets::Widget<OUTLET>,
+        {
+            fn new(outlet: OUTLET) -> plating_core::PlatingResult<Self>
+            where
+                #children_of_self;
+        }
+
+        #markers
+
+        #[doc = #mock_docs]
+        #vis struct #mock_name<OUTLET>
+        where
+            OUTLET: #bounds,
+        {
+            outlet: OUTLET,
+        }
+
+        #[automatically_derived]
+        impl<OUTLET> plating_core::widgets::Widget<OUTLET> for #mock_name<OUTLET>
+        where
+            OUTLET: #bounds,
+        {
+            fn outlet(&self) -> &OUTLET {
+                &self.outlet
+            }
+        }
+
+        #(#containers)*
+
+        #[automatically_derived]
+        impl<OUTLET> #name<OUTLET> for #mock_name<OUTLET>
+        where
+            OUTLET: #bounds,
+        {
+            fn new(outlet: OUTLET) -> plating_core::PlatingResult<Self>
+            where
+                #children_of_self
+            {
+                let result = Self { outlet };
+                #(
+                    plating_core::utils::children::ChildOf::<Self, #outlet_types>::connect(
+                        <OUTLET as plating_core::utils::outlet::Outlet<#outlet_types>>::get(
+                            &result.outlet,
+                        ),
+                        &result,
+                    );
+                )*
+                Ok(result)
+            }
+        }
+
+        #[automatically_derived]
+        impl<PARENT, OUTLET>
+            plating_core::utils::children::ChildOf<PARENT, plating_core::utils::outlet::ChildrenOutlet>
+            for #mock_name<OUTLET>
+        where
+            PARENT: plating_core::mock::traits::MockContainer,
+            OUTLET: #bounds,
+            #children_of_self
+        {
+            fn setup(&mut self, _parent: &PARENT) {}
+
+            fn connect(&self, _parent: &PARENT) {}
+
+            fn disconnect(&self) {
+                #(
+                    plating_core::utils::children::ChildOf::<Self, #outlet_types>::disconnect(
+                        <OUTLET as plating_core::utils::outlet::Outlet<#outlet_types>>::get(
+                            &self.outlet,
+                        ),
+                    );
+                )*
+            }
+        }
+
+        #(#system_impls)*
+    })
+}